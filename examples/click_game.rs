@@ -165,7 +165,29 @@ impl eframe::App for ClickGameApp {
     }
 }
 
+/// Test harness: `cargo run --example click_game -- --find <name>` lists the
+/// running instances of a process LightScan can open, then exits
+fn find_processes(name: &str) {
+    match lightscan::scanner::Process::all_with_name(name) {
+        Ok(processes) if processes.is_empty() => println!("No process named {}", name),
+        Ok(processes) => {
+            for process in processes {
+                println!("{} (PID {})", process.name, process.pid);
+            }
+        }
+        Err(e) => eprintln!("Failed to list processes: {}", e),
+    }
+}
+
 fn main() -> eframe::Result<()> {
+    let args: Vec<String> = std::env::args().collect();
+    if let [_, flag, name] = args.as_slice() {
+        if flag == "--find" {
+            find_processes(name);
+            return Ok(());
+        }
+    }
+
     let options = eframe::NativeOptions {
         viewport: egui::ViewportBuilder::default()
             .with_title("Click Game")
@@ -0,0 +1,123 @@
+//! ロード済み DLL とシグネチャからゲームエンジンを自動判定する
+
+use super::error::{EngineError, Result};
+use super::native::NativeEngine;
+use super::r#trait::{EngineDetector, GameEngine};
+use super::unity_il2cpp::{UnityIL2CppEngine, IL2CPP_MODULE_NAMES};
+use super::unity_mono::{UnityMonoEngine, MONO_MODULE_NAMES};
+use super::unreal::scanner::{scan_pattern, Pattern};
+use super::unreal::signatures::UESignatures;
+use super::unreal::UnrealEngine;
+use crate::platform::module::{list_modules, resolve_remote_export, ModuleInfo};
+use crate::scanner::Process;
+
+/// 検出されたエンジンの種類
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DetectedEngine {
+    UnityMono,
+    UnityIL2Cpp,
+    Unreal,
+    Native,
+}
+
+impl DetectedEngine {
+    /// 組み込みバックエンドの一覧（バックエンド選択の表示順）
+    pub const ALL: [DetectedEngine; 4] = [
+        DetectedEngine::UnityMono,
+        DetectedEngine::UnityIL2Cpp,
+        DetectedEngine::Unreal,
+        DetectedEngine::Native,
+    ];
+
+    /// タブのバッジなどに表示する短い名前
+    pub fn label(&self) -> &'static str {
+        match self {
+            DetectedEngine::UnityMono => "Unity (Mono)",
+            DetectedEngine::UnityIL2Cpp => "Unity (IL2CPP)",
+            DetectedEngine::Unreal => "Unreal",
+            DetectedEngine::Native => "Native",
+        }
+    }
+}
+
+/// モジュール一覧から適切なバックエンドを選択する検出器
+///
+/// 判定順:
+/// 1. mono.dll / mono-2.0-bdwgc.dll → Unity Mono
+/// 2. GameAssembly.dll → Unity IL2CPP
+/// 3. メインモジュールが GUObjectArray をエクスポート、または GNames/GObjects シグネチャが一致 → Unreal
+/// 4. それ以外 → Native
+pub struct AutoEngineDetector;
+
+impl AutoEngineDetector {
+    /// エンジンの種類のみを判定する
+    pub fn detect_kind(process: &Process) -> Result<DetectedEngine> {
+        let modules = list_modules(process.pid)
+            .map_err(|e| EngineError::PlatformError(e.context("Failed to list modules")))?;
+
+        let is_loaded = |names: &[&str]| {
+            modules
+                .iter()
+                .any(|m| names.iter().any(|n| m.name.eq_ignore_ascii_case(n)))
+        };
+
+        if is_loaded(MONO_MODULE_NAMES) {
+            return Ok(DetectedEngine::UnityMono);
+        }
+        if is_loaded(IL2CPP_MODULE_NAMES) {
+            return Ok(DetectedEngine::UnityIL2Cpp);
+        }
+
+        // 先頭のモジュールがメインの実行ファイル
+        if let Some(main_module) = modules.first() {
+            if Self::is_unreal_module(process, main_module) {
+                return Ok(DetectedEngine::Unreal);
+            }
+        }
+
+        Ok(DetectedEngine::Native)
+    }
+
+    /// 判定済みの種類からバックエンドを生成する
+    ///
+    /// 判定だけを別スレッドで行い、生成は呼び出し元のプロセスハンドルで行う場合に使う
+    pub fn create(kind: DetectedEngine, process_handle: usize, process_id: u32) -> Box<dyn GameEngine> {
+        match kind {
+            DetectedEngine::UnityMono => Box::new(UnityMonoEngine::new(process_handle, process_id)),
+            DetectedEngine::UnityIL2Cpp => Box::new(UnityIL2CppEngine::new(process_handle, process_id)),
+            DetectedEngine::Unreal => Box::new(UnrealEngine::new(process_handle, process_id)),
+            DetectedEngine::Native => Box::new(NativeEngine::new(process_handle, process_id)),
+        }
+    }
+
+    /// UE の実行ファイルかどうか
+    ///
+    /// 汎用的な命令列に誤検出しないよう、シグネチャは GNames と GObjects の両方の一致を要求する
+    fn is_unreal_module(process: &Process, module: &ModuleInfo) -> bool {
+        let handle = process.handle();
+
+        // モジュラービルドや一部のゲームは GUObjectArray をエクスポートしている
+        if resolve_remote_export(handle, module, "GUObjectArray").is_ok() {
+            return true;
+        }
+
+        [UESignatures::GNAMES, UESignatures::GOBJECTS].iter().all(|signature| {
+            let pattern = Pattern::from_string(signature);
+            scan_pattern(handle, &pattern, module.base_address, module.size)
+                .is_ok_and(|matches| !matches.is_empty())
+        })
+    }
+}
+
+impl EngineDetector for AutoEngineDetector {
+    fn detect(process: &Process) -> Result<Box<dyn GameEngine>> {
+        let kind = Self::detect_kind(process)?;
+        tracing::info!("Detected engine: {:?}", kind);
+
+        Ok(Self::create(kind, process.handle_as_usize(), process.pid))
+    }
+
+    fn is_match(process: &Process) -> bool {
+        Self::detect_kind(process).is_ok_and(|kind| kind != DetectedEngine::Native)
+    }
+}
@@ -36,6 +36,10 @@ pub enum EngineError {
     #[error("Unsupported operation: {0}")]
     UnsupportedOperation(String),
 
+    /// 対象プロセスが応答しない場合など、操作が制限時間内に終わらなかった
+    #[error("Operation timed out after {}s — game may be frozen", .0.as_secs())]
+    Timeout(std::time::Duration),
+
     #[error("Invalid argument: {0}")]
     InvalidArgument(String),
 
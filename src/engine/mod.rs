@@ -13,8 +13,12 @@ pub mod unreal;
 pub mod unity_mono;
 pub mod unity_il2cpp;
 pub mod native;
+pub mod detector;
+pub mod plugin;
 
 // Re-exports
+pub use detector::{AutoEngineDetector, DetectedEngine};
 pub use error::{EngineError, Result};
+pub use plugin::{EnginePlugin, PluginRegistry};
 pub use r#trait::{EngineDetector, GameEngine};
 pub use types::*;
@@ -3,32 +3,131 @@
 use super::error::{EngineError, Result};
 use super::types::*;
 use super::GameEngine;
+use crate::platform::module::{find_module, get_main_module, list_exports, list_imports, ModuleInfo};
 use std::any::Any;
 use std::collections::HashMap;
+use std::sync::Mutex;
 
 /// Native バックエンド（リフレクション機能が限定的）
 pub struct NativeEngine {
     process_handle: usize,
+    process_id: u32,
     /// PE Export Table から取得したシンボル
     symbols: HashMap<String, usize>,
+    /// PE Import Table から取得した IAT エントリ（"dll_name::func_name" -> IAT アドレス）
+    imports: HashMap<String, usize>,
+    /// モジュールごとに解析済みの Export Table（キーは小文字のモジュール名）
+    export_tables: Mutex<HashMap<String, HashMap<String, usize>>>,
     initialized: bool,
 }
 
 impl NativeEngine {
-    pub fn new(process_handle: usize) -> Self {
+    pub fn new(process_handle: usize, process_id: u32) -> Self {
         Self {
             process_handle,
+            process_id,
             symbols: HashMap::new(),
+            imports: HashMap::new(),
+            export_tables: Mutex::new(HashMap::new()),
             initialized: false,
         }
     }
 
     /// PE Export Table を解析
     fn parse_export_table(&mut self) -> Result<()> {
-        // TODO: PE ヘッダーから Export Table を読み取り
-        // symbols に関数名 -> アドレスのマッピングを構築
+        let module = get_main_module(self.process_id)?;
+        tracing::info!("Parsing export table of {} at 0x{:X}", module.name, module.base_address);
+
+        self.symbols = self.module_exports(&module)?;
+
+        tracing::info!("Found {} exported symbols", self.symbols.len());
         Ok(())
     }
+
+    /// モジュールの Export Table を解析してキャッシュする
+    ///
+    /// 2 回目以降はリモートを読まずにキャッシュを返す
+    fn module_exports(&self, module: &ModuleInfo) -> Result<HashMap<String, usize>> {
+        let key = module.name.to_lowercase();
+        if let Some(table) = self.export_tables.lock().unwrap().get(&key) {
+            return Ok(table.clone());
+        }
+
+        // DOS/PE ヘッダー -> IMAGE_EXPORT_DIRECTORY -> 名前付きエクスポート
+        // resolve_remote_export と同様にモジュール範囲外を指すエントリは捨てる
+        let table: HashMap<String, usize> = list_exports(self.handle(), module.base_address)?
+            .into_iter()
+            .filter(|&(_, addr)| module.size == 0 || addr < module.base_address + module.size)
+            .collect();
+        self.export_tables.lock().unwrap().insert(key, table.clone());
+        Ok(table)
+    }
+
+    fn handle(&self) -> windows::Win32::Foundation::HANDLE {
+        unsafe { std::mem::transmute::<usize, windows::Win32::Foundation::HANDLE>(self.process_handle) }
+    }
+
+    /// PE Import Table を解析
+    fn parse_import_table(&mut self) -> Result<()> {
+        let module = get_main_module(self.process_id)?;
+
+        for import in list_imports(self.handle(), module.base_address)? {
+            let key = Self::import_key(&import.dll_name, &import.function_name);
+            self.imports.insert(key, import.iat_address);
+        }
+
+        tracing::info!("Found {} imported symbols", self.imports.len());
+        Ok(())
+    }
+
+    /// imports のキー（DLL 名は大文字小文字を区別しない）
+    fn import_key(dll: &str, name: &str) -> String {
+        format!("{}::{}", dll.to_lowercase(), name)
+    }
+
+    /// インポート関数の IAT エントリのアドレスを取得
+    pub fn get_import(&self, dll: &str, name: &str) -> Option<usize> {
+        self.imports.get(&Self::import_key(dll, name)).copied()
+    }
+
+    /// エクスポートとインポートをまとめて名前順に列挙
+    pub fn enumerate_all_symbols(&self) -> Vec<(String, usize)> {
+        let mut symbols: Vec<(String, usize)> = self
+            .symbols
+            .iter()
+            .chain(self.imports.iter())
+            .map(|(name, &addr)| (name.clone(), addr))
+            .collect();
+        symbols.sort_by(|a, b| a.0.cmp(&b.0));
+        symbols
+    }
+
+    /// "module.dll!ExportName" 形式の名前を他モジュールのエクスポートとして解決
+    ///
+    /// Export Table をリモートから直接読むので、リモートスレッドは不要。
+    /// 一度解析したモジュールはキャッシュから引く
+    fn resolve_qualified_export(&self, qualified: &str) -> Option<usize> {
+        let (module_name, export_name) = qualified.split_once('!')?;
+        if let Some(table) = self.export_tables.lock().unwrap().get(&module_name.to_lowercase()) {
+            return table.get(export_name).copied();
+        }
+
+        let module = find_module(self.process_id, &[module_name]).ok()?;
+        self.module_exports(&module)
+            .inspect_err(|e| tracing::debug!("Failed to resolve {}: {}", qualified, e))
+            .ok()?
+            .get(export_name)
+            .copied()
+    }
+
+    /// アドレスからシンボル名を逆引き（エクスポート → インポートの順）
+    pub fn lookup_symbol(&self, addr: usize) -> Option<&str> {
+        self.symbols
+            .iter()
+            .chain(self.imports.iter())
+            .find(|(_, &a)| a == addr)
+            .map(|(name, _)| name.as_str())
+    }
 }
 
 impl GameEngine for NativeEngine {
@@ -42,6 +141,7 @@ impl GameEngine for NativeEngine {
         }
 
         self.parse_export_table()?;
+        self.parse_import_table()?;
         self.initialized = true;
         Ok(())
     }
@@ -70,20 +170,25 @@ impl GameEngine for NativeEngine {
     }
 
     fn find_method(&self, _class: ClassHandle, name: &str) -> Result<MethodHandle> {
-        // シンボルテーブルから検索
+        // シンボルテーブル → インポートテーブル → 他モジュールのエクスポートの順で検索
         self.symbols
             .get(name)
-            .map(|&addr| MethodHandle(addr))
+            .copied()
+            .or_else(|| {
+                // "dll_name::func_name" は import_key と同じく DLL 名を小文字にして引く
+                let (dll, func) = name.split_once("::")?;
+                self.get_import(dll, func)
+            })
+            .or_else(|| self.resolve_qualified_export(name))
+            .map(MethodHandle)
             .ok_or_else(|| EngineError::MethodNotFound(name.to_string()))
     }
 
     fn get_method_info(&self, method: MethodHandle) -> Result<MethodInfo> {
         // シンボル名を逆引き
         let name = self
-            .symbols
-            .iter()
-            .find(|(_, &addr)| addr == method.0)
-            .map(|(n, _)| n.clone())
+            .lookup_symbol(method.0)
+            .map(|n| n.to_string())
             .unwrap_or_else(|| format!("func_0x{:X}", method.0));
 
         Ok(MethodInfo {
@@ -92,6 +197,7 @@ impl GameEngine for NativeEngine {
             params: Vec::new(), // Native では型情報不明
             return_type: None,
             is_static: true, // すべて static として扱う
+            flags_summary: String::new(),
         })
     }
 
@@ -106,6 +212,7 @@ impl GameEngine for NativeEngine {
                 params: Vec::new(),
                 return_type: None,
                 is_static: true,
+                flags_summary: String::new(),
             })
             .collect())
     }
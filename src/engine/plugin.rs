@@ -0,0 +1,51 @@
+//! 外部から登録するエンジンバックエンド (CryEngine, GameMaker など)
+
+use super::r#trait::GameEngine;
+use std::sync::{Mutex, MutexGuard};
+
+/// カスタムバックエンドを生成するプラグイン
+///
+/// 自動判定の対象にはならないので、GUI のバックエンド選択から明示的に選ぶ
+pub trait EnginePlugin: Send {
+    /// バックエンド選択に表示する名前
+    fn name(&self) -> &str;
+
+    /// プロセスハンドル (usize) と PID からバックエンドを生成
+    fn create(&self, process_handle: usize, pid: u32) -> Box<dyn GameEngine>;
+}
+
+/// 登録済みプラグインの一覧（プロセス全体で共有）
+pub struct PluginRegistry {
+    plugins: Vec<Box<dyn EnginePlugin>>,
+}
+
+static REGISTRY: Mutex<PluginRegistry> = Mutex::new(PluginRegistry { plugins: Vec::new() });
+
+impl PluginRegistry {
+    fn global() -> MutexGuard<'static, PluginRegistry> {
+        REGISTRY.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    /// プラグインを登録する（同じ名前のものは置き換える）
+    pub fn register(plugin: Box<dyn EnginePlugin>) {
+        let mut registry = Self::global();
+        let name = plugin.name().to_string();
+        registry.plugins.retain(|p| p.name() != name);
+        registry.plugins.push(plugin);
+        tracing::info!("Registered engine plugin: {}", name);
+    }
+
+    /// 登録順のプラグイン名
+    pub fn names() -> Vec<String> {
+        Self::global().plugins.iter().map(|p| p.name().to_string()).collect()
+    }
+
+    /// 名前で指定したプラグインからバックエンドを生成する（未登録なら None）
+    pub fn create(name: &str, process_handle: usize, pid: u32) -> Option<Box<dyn GameEngine>> {
+        Self::global()
+            .plugins
+            .iter()
+            .find(|p| p.name() == name)
+            .map(|p| p.create(process_handle, pid))
+    }
+}
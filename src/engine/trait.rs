@@ -2,6 +2,7 @@
 
 use super::error::Result;
 use super::types::*;
+use crate::scanner::Process;
 use std::any::Any;
 
 /// ゲームエンジンの共通インターフェース
@@ -28,6 +29,13 @@ pub trait GameEngine: Send + Sync {
     /// 初期化済みかどうか
     fn is_initialized(&self) -> bool;
 
+    /// 初期化時に見つけたポインタがまだ有効か確認する
+    ///
+    /// ゲームの更新やレベルの再読み込みでアドレスが変わった場合にエラーを返す
+    fn health_check(&self) -> Result<()> {
+        Ok(())
+    }
+
     // ====== クラス操作 ======
 
     /// 名前からクラスを検索
@@ -145,9 +153,12 @@ pub trait GameEngine: Send + Sync {
 
 /// エンジン検出用のトレイト
 pub trait EngineDetector {
-    /// プロセスからエンジンを検出して初期化
-    fn detect() -> Result<Box<dyn GameEngine>>;
+    /// プロセスからエンジンを検出してバックエンドを生成
+    ///
+    /// 初期化（initialize）は呼び出し側で行う。ゲームのロード完了前に
+    /// GNames 等を検索すると失敗するため
+    fn detect(process: &Process) -> Result<Box<dyn GameEngine>>;
 
     /// 特定のエンジンかどうかを判定
-    fn is_match() -> bool;
+    fn is_match(process: &Process) -> bool;
 }
@@ -46,6 +46,16 @@ pub enum Value {
     Array(Vec<Value>),
     /// 生バイト（エンジン固有の複雑な構造体）
     Struct(Vec<u8>),
+    /// 単精度 3次元ベクトル（UE4 / UE5.0 の FVector、Unity の Vector3）
+    Vector3(f32, f32, f32),
+    /// 倍精度 3次元ベクトル（UE5 Large World Coordinates の FVector）
+    Vector3D(f64, f64, f64),
+    /// 単精度クォータニオン (X, Y, Z, W)
+    Quaternion(f32, f32, f32, f32),
+    /// 倍精度クォータニオン（UE5 Large World Coordinates の FQuat）
+    QuaternionD(f64, f64, f64, f64),
+    /// 8bit RGBA カラー（メモリ上の並びはエンジン依存。UE の FColor は BGRA）
+    Color(u8, u8, u8, u8),
 }
 
 impl fmt::Display for Value {
@@ -67,6 +77,11 @@ impl fmt::Display for Value {
             Value::Object(h) => write!(f, "Object@0x{:X}", h.0),
             Value::Array(v) => write!(f, "[{} items]", v.len()),
             Value::Struct(v) => write!(f, "Struct[{} bytes]", v.len()),
+            Value::Vector3(x, y, z) => write!(f, "({:.3}, {:.3}, {:.3})", x, y, z),
+            Value::Vector3D(x, y, z) => write!(f, "({:.3}, {:.3}, {:.3})", x, y, z),
+            Value::Quaternion(x, y, z, w) => write!(f, "({:.3}, {:.3}, {:.3}, {:.3})", x, y, z, w),
+            Value::QuaternionD(x, y, z, w) => write!(f, "({:.3}, {:.3}, {:.3}, {:.3})", x, y, z, w),
+            Value::Color(r, g, b, a) => write!(f, "RGBA({}, {}, {}, {})", r, g, b, a),
         }
     }
 }
@@ -92,6 +107,8 @@ pub struct MethodInfo {
     pub params: Vec<ParamInfo>,
     pub return_type: Option<TypeInfo>,
     pub is_static: bool,
+    /// エンジン固有のフラグ表記 (例: "[Native][BlueprintCallable][Static]")、不明なら空
+    pub flags_summary: String,
 }
 
 /// フィールド情報
@@ -132,6 +149,8 @@ pub enum TypeKind {
     Struct(ClassHandle),
     Array(Box<TypeInfo>),
     Pointer(Box<TypeInfo>),
+    /// 連想配列（キー型, 値型）
+    Map(Box<TypeInfo>, Box<TypeInfo>),
     Unknown,
 }
 
@@ -143,6 +162,7 @@ impl PartialEq for TypeKind {
             (TypeKind::Struct(a), TypeKind::Struct(b)) => a == b,
             (TypeKind::Array(a), TypeKind::Array(b)) => **a == **b,
             (TypeKind::Pointer(a), TypeKind::Pointer(b)) => **a == **b,
+            (TypeKind::Map(ak, av), TypeKind::Map(bk, bv)) => **ak == **bk && **av == **bv,
             (TypeKind::Unknown, TypeKind::Unknown) => true,
             _ => false,
         }
@@ -1,26 +1,96 @@
-/// Unity IL2CPP backend (stub)
+/// Unity IL2CPP backend
 
 use super::error::{EngineError, Result};
 use super::types::*;
 use super::GameEngine;
+use crate::platform::module::{find_module, list_sections, resolve_remote_export, ModuleInfo};
+use crate::platform::windows::{call_remote_function, read_process_memory};
 use std::any::Any;
+use windows::Win32::Foundation::HANDLE as WinHandle;
+
+/// IL2CPP ランタイムの DLL 名
+pub(super) const IL2CPP_MODULE_NAMES: &[&str] = &["GameAssembly.dll"];
+
+/// global-metadata.dat のマジックナンバー
+const METADATA_SANITY: u32 = 0xFAB11BAF;
 
 pub struct UnityIL2CppEngine {
     process_handle: usize,
-    il2cpp_module: usize,
-    global_metadata: usize,
+    process_id: u32,
+    il2cpp_module: ModuleInfo,
+    domain: usize,
+    metadata_base: usize,
     initialized: bool,
 }
 
 impl UnityIL2CppEngine {
-    pub fn new(process_handle: usize) -> Self {
+    pub fn new(process_handle: usize, process_id: u32) -> Self {
         Self {
             process_handle,
-            il2cpp_module: 0,
-            global_metadata: 0,
+            process_id,
+            il2cpp_module: ModuleInfo::default(),
+            domain: 0,
+            metadata_base: 0,
             initialized: false,
         }
     }
+
+    fn handle(&self) -> WinHandle {
+        unsafe { std::mem::transmute::<usize, WinHandle>(self.process_handle) }
+    }
+
+    /// il2cpp_domain_get を呼び出して Il2CppDomain* を取得
+    fn find_domain(&self) -> Result<usize> {
+        let func = resolve_remote_export(self.handle(), &self.il2cpp_module, "il2cpp_domain_get").map_err(|e| {
+            EngineError::InitializationFailed(format!("Failed to resolve il2cpp_domain_get: {}", e))
+        })?;
+
+        call_remote_function(self.handle(), func, &[]).map_err(|e| {
+            EngineError::InitializationFailed(format!("Failed to call il2cpp_domain_get: {}", e))
+        })
+    }
+
+    /// ロード済みの global-metadata.dat のベースアドレスを検索
+    ///
+    /// il2cpp はメタデータをメモリにマップし、そのポインタ（s_GlobalMetadata）を
+    /// GameAssembly.dll の .data セクションに保持している。
+    /// .data 内のポインタを走査し、指す先がメタデータのヘッダー
+    /// （sanity 0xFAB11BAF + version）になっているものを探す
+    fn find_metadata_base(&self) -> Result<usize> {
+        let handle = self.handle();
+
+        let sections = list_sections(handle, self.il2cpp_module.base_address).map_err(|e| {
+            EngineError::InitializationFailed(format!("Failed to read GameAssembly.dll sections: {}", e))
+        })?;
+        let data_section = sections.iter().find(|s| s.name == ".data").ok_or_else(|| {
+            EngineError::InitializationFailed("GameAssembly.dll has no .data section".into())
+        })?;
+
+        let data = read_process_memory(handle, data_section.address, data_section.size).map_err(|e| {
+            EngineError::InitializationFailed(format!("Failed to read .data section: {}", e))
+        })?;
+
+        for chunk in data.chunks_exact(8) {
+            let ptr = usize::from_le_bytes(chunk.try_into().unwrap());
+            if !(0x10000..0x7FFF_FFFF_FFFF).contains(&ptr) || ptr % 8 != 0 {
+                continue;
+            }
+
+            let Ok(header) = read_process_memory(handle, ptr, 8) else {
+                continue;
+            };
+            let sanity = u32::from_le_bytes(header[0..4].try_into().unwrap());
+            let version = i32::from_le_bytes(header[4..8].try_into().unwrap());
+            if sanity == METADATA_SANITY && (16..=40).contains(&version) {
+                tracing::info!("global-metadata.dat v{} at 0x{:X}", version, ptr);
+                return Ok(ptr);
+            }
+        }
+
+        Err(EngineError::InitializationFailed(
+            "global-metadata.dat header not found in GameAssembly.dll .data section".into(),
+        ))
+    }
 }
 
 impl GameEngine for UnityIL2CppEngine {
@@ -29,13 +99,37 @@ impl GameEngine for UnityIL2CppEngine {
     }
 
     fn initialize(&mut self) -> Result<()> {
-        Err(EngineError::InitializationFailed(
-            "Unity IL2CPP backend not implemented".into(),
-        ))
+        if self.is_initialized() {
+            return Ok(());
+        }
+
+        tracing::info!("Initializing Unity IL2CPP engine...");
+
+        // 1. GameAssembly.dll を検索
+        let module = find_module(self.process_id, IL2CPP_MODULE_NAMES).map_err(|e| {
+            EngineError::InitializationFailed(format!("IL2CPP runtime module not found: {}", e))
+        })?;
+        tracing::info!("IL2CPP module: {} at 0x{:X}", module.name, module.base_address);
+        self.il2cpp_module = module;
+
+        // 2. ドメインを取得
+        self.domain = self.find_domain()?;
+        if self.domain == 0 {
+            return Err(EngineError::InitializationFailed(
+                "il2cpp_domain_get returned null".into(),
+            ));
+        }
+        tracing::info!("Domain: 0x{:X}", self.domain);
+
+        // 3. メタデータを検索
+        self.metadata_base = self.find_metadata_base()?;
+
+        self.initialized = true;
+        Ok(())
     }
 
     fn is_initialized(&self) -> bool {
-        self.initialized
+        self.initialized && self.il2cpp_module.base_address != 0 && self.domain != 0 && self.metadata_base != 0
     }
 
     fn find_class(&self, name: &str) -> Result<ClassHandle> {
@@ -1,26 +1,471 @@
-/// Unity Mono backend (stub)
+/// Unity Mono backend
 
 use super::error::{EngineError, Result};
 use super::types::*;
 use super::GameEngine;
+use crate::platform::module::{find_module, resolve_remote_export, ModuleInfo};
+use crate::platform::windows::{
+    call_remote_function, call_remote_functions_timeout, read_process_memory, write_process_memory,
+};
 use std::any::Any;
+use std::time::Duration;
+use windows::Win32::Foundation::HANDLE as WinHandle;
+use windows::Win32::System::Memory::{
+    VirtualAllocEx, VirtualFreeEx, MEM_COMMIT, MEM_RELEASE, MEM_RESERVE, PAGE_EXECUTE_READWRITE,
+};
+
+/// Mono ランタイムの DLL 名
+pub(super) const MONO_MODULE_NAMES: &[&str] = &["mono.dll", "mono-2.0-bdwgc.dll"];
+
+/// mono_assembly_foreach のコールバックで収集するアセンブリの最大数
+const MAX_ASSEMBLIES: usize = 1024;
+
+/// Mono API 呼び出し 1 回あたりの待ち時間の上限
+///
+/// Mono 内部でデッドロックしても LightScan ごと固まらないようにする
+const MONO_CALL_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// 1 イメージあたりに列挙する TypeDef の最大数
+const MAX_TYPEDEFS: usize = 0x10000;
+
+/// 1 クラスあたりに列挙するフィールドの最大数
+const MAX_FIELDS: usize = 1024;
+
+/// MONO_TABLE_TYPEDEF
+const MONO_TABLE_TYPEDEF: usize = 2;
+
+/// TypeDef テーブルのメタデータトークン
+const MONO_TOKEN_TYPE_DEF: u32 = 0x02000000;
+
+/// MonoClassField のレイアウト
+/// { MonoType* type; const char* name; MonoClass* parent; int offset; }
+const MONO_FIELD_TYPE_OFFSET: usize = 0x00;
+const MONO_FIELD_NAME_OFFSET: usize = 0x08;
+const MONO_FIELD_OFFSET_OFFSET: usize = 0x18;
+
+/// MonoType::type (MonoTypeEnum) のオフセット
+const MONO_TYPE_ENUM_OFFSET: usize = 0x0A;
 
 pub struct UnityMonoEngine {
     process_handle: usize,
-    mono_module: usize,
+    process_id: u32,
+    mono_module: ModuleInfo,
     root_domain: usize,
+    /// mono_thread_attach のアドレス
+    thread_attach: usize,
+    assemblies: Vec<usize>,
     initialized: bool,
 }
 
 impl UnityMonoEngine {
-    pub fn new(process_handle: usize) -> Self {
+    pub fn new(process_handle: usize, process_id: u32) -> Self {
         Self {
             process_handle,
-            mono_module: 0,
+            process_id,
+            mono_module: ModuleInfo::default(),
             root_domain: 0,
+            thread_attach: 0,
+            assemblies: Vec::new(),
             initialized: false,
         }
     }
+
+    fn handle(&self) -> WinHandle {
+        unsafe { std::mem::transmute::<usize, WinHandle>(self.process_handle) }
+    }
+
+    /// Mono のエクスポート関数のアドレスを取得
+    fn resolve_export(&self, name: &str) -> Result<usize> {
+        resolve_remote_export(self.handle(), &self.mono_module, name).map_err(|e| {
+            EngineError::InitializationFailed(format!("Failed to resolve {}: {}", name, e))
+        })
+    }
+
+    /// mono_get_root_domain を呼び出して MonoDomain* を取得
+    fn find_root_domain(&self) -> Result<usize> {
+        let func = self.resolve_export("mono_get_root_domain")?;
+        call_remote_function(self.handle(), func, &[]).map_err(|e| {
+            EngineError::InitializationFailed(format!("Failed to call mono_get_root_domain: {}", e))
+        })
+    }
+
+    /// リモートスレッドを mono_thread_attach(root_domain) で登録してから関数を呼び出す
+    ///
+    /// リモートスレッドは呼び出しごとに新しく作られる。Mono に登録されていないスレッドから
+    /// Mono API を呼ぶと GC などでクラッシュするため、同じスレッドで先に attach する。
+    /// MONO_CALL_TIMEOUT 以内に終わらなければ EngineError::Timeout を返す
+    fn call_attached(&self, function: usize, args: &[usize]) -> Result<usize> {
+        call_remote_functions_timeout(
+            self.handle(),
+            &[(self.thread_attach, &[self.root_domain]), (function, args)],
+            MONO_CALL_TIMEOUT,
+        )?
+        .ok_or(EngineError::Timeout(MONO_CALL_TIMEOUT))
+    }
+
+    /// VirtualAllocEx で確保した作業領域を解放する
+    ///
+    /// タイムアウトした場合はリモートスレッドがまだ使っている可能性があるので解放しない
+    fn free_remote_buffer<T>(&self, buffer: *mut std::ffi::c_void, size: usize, result: &Result<T>) {
+        if matches!(result, Err(EngineError::Timeout(_))) {
+            tracing::warn!("Leaking {} bytes in the target after a timed-out Mono call", size);
+            return;
+        }
+        unsafe {
+            let _ = VirtualFreeEx(self.handle(), buffer, 0, MEM_RELEASE);
+        }
+    }
+
+    /// mono_assembly_foreach でロード済みアセンブリを列挙
+    ///
+    /// ターゲットプロセス内に [count: u64][entries: u64 * MAX_ASSEMBLIES][callback] の
+    /// 共有領域を確保し、コールバックが MonoAssembly* を追記していく
+    fn collect_assemblies(&self) -> Result<Vec<usize>> {
+        let foreach = self.resolve_export("mono_assembly_foreach")?;
+        let handle = self.handle();
+
+        let entries_size = 8 + MAX_ASSEMBLIES * 8;
+        let callback_code = Self::generate_collect_callback();
+        let total_size = entries_size + callback_code.len();
+
+        let buffer = unsafe {
+            VirtualAllocEx(handle, None, total_size, MEM_COMMIT | MEM_RESERVE, PAGE_EXECUTE_READWRITE)
+        };
+        if buffer.is_null() {
+            return Err(EngineError::InitializationFailed(
+                "Failed to allocate assembly list buffer in target process".into(),
+            ));
+        }
+        let buffer_addr = buffer as usize;
+        let callback_addr = buffer_addr + entries_size;
+
+        let result = (|| -> Result<Vec<usize>> {
+            write_process_memory(handle, buffer_addr, &0u64.to_le_bytes())?;
+            write_process_memory(handle, callback_addr, &callback_code)?;
+
+            // mono_assembly_foreach(GFunc func, gpointer user_data)
+            self.call_attached(foreach, &[callback_addr, buffer_addr])?;
+
+            let count_bytes = read_process_memory(handle, buffer_addr, 8)?;
+            let count = (u64::from_le_bytes(count_bytes[..8].try_into().unwrap()) as usize)
+                .min(MAX_ASSEMBLIES);
+            if count == 0 {
+                return Ok(Vec::new());
+            }
+
+            let data = read_process_memory(handle, buffer_addr + 8, count * 8)?;
+            Ok(data
+                .chunks_exact(8)
+                .map(|c| usize::from_le_bytes(c.try_into().unwrap()))
+                .filter(|&addr| addr != 0)
+                .collect())
+        })();
+
+        self.free_remote_buffer(buffer, total_size, &result);
+
+        result.map_err(|e| match e {
+            EngineError::Timeout(_) => e,
+            e => EngineError::InitializationFailed(format!("Failed to enumerate assemblies: {}", e)),
+        })
+    }
+
+    /// mono_assembly_foreach 用のコールバックを生成
+    ///
+    /// void callback(MonoAssembly* assembly /* rcx */, u64* list /* rdx */)
+    fn generate_collect_callback() -> Vec<u8> {
+        let mut code = Vec::new();
+
+        // mov rax, [rdx]
+        code.extend_from_slice(&[0x48, 0x8B, 0x02]);
+
+        // cmp rax, MAX_ASSEMBLIES
+        code.extend_from_slice(&[0x48, 0x3D]);
+        code.extend_from_slice(&(MAX_ASSEMBLIES as u32).to_le_bytes());
+
+        // jae done
+        code.extend_from_slice(&[0x73, 0x0B]);
+
+        // mov [rdx + rax*8 + 8], rcx
+        code.extend_from_slice(&[0x48, 0x89, 0x4C, 0xC2, 0x08]);
+
+        // inc rax
+        code.extend_from_slice(&[0x48, 0xFF, 0xC0]);
+
+        // mov [rdx], rax
+        code.extend_from_slice(&[0x48, 0x89, 0x02]);
+
+        // done: ret
+        code.push(0xC3);
+
+        code
+    }
+
+    /// ロード済みアセンブリ（MonoAssembly*）の一覧
+    pub fn assemblies(&self) -> &[usize] {
+        &self.assemblies
+    }
+
+    /// ターゲットプロセス内でルーチンを実行し、出力領域を読み戻す
+    ///
+    /// 領域のレイアウトは [out: out_size][data][code]。
+    /// `args` には (out のアドレス, data のアドレス) が渡される
+    fn run_remote_routine(
+        &self,
+        code: &[u8],
+        data: &[u8],
+        out_size: usize,
+        args: impl FnOnce(usize, usize) -> Vec<usize>,
+    ) -> Result<(usize, Vec<u8>)> {
+        let handle = self.handle();
+        let total_size = out_size + data.len() + code.len();
+
+        // VirtualAllocEx で確保した領域はゼロ初期化されている
+        let buffer = unsafe {
+            VirtualAllocEx(handle, None, total_size, MEM_COMMIT | MEM_RESERVE, PAGE_EXECUTE_READWRITE)
+        };
+        if buffer.is_null() {
+            return Err(EngineError::MemoryError(
+                "Failed to allocate routine buffer in target process".into(),
+            ));
+        }
+        let out_addr = buffer as usize;
+        let data_addr = out_addr + out_size;
+        let code_addr = data_addr + data.len();
+
+        let result = (|| -> Result<(usize, Vec<u8>)> {
+            if !data.is_empty() {
+                write_process_memory(handle, data_addr, data)?;
+            }
+            write_process_memory(handle, code_addr, code)?;
+
+            let ret = self.call_attached(code_addr, &args(out_addr, data_addr))?;
+            let out = read_process_memory(handle, out_addr, out_size)?;
+            Ok((ret, out))
+        })();
+
+        self.free_remote_buffer(buffer, total_size, &result);
+
+        result.map_err(|e| match e {
+            EngineError::PlatformError(e) => EngineError::InvocationFailed(e.to_string()),
+            e => e,
+        })
+    }
+
+    /// Mono API を名前で解決して呼び出す
+    fn call_mono(&self, name: &str, args: &[usize]) -> Result<usize> {
+        let func = resolve_remote_export(self.handle(), &self.mono_module, name)
+            .map_err(|e| EngineError::MethodNotFound(format!("{}: {}", name, e)))?;
+        self.call_attached(func, args).map_err(|e| match e {
+            EngineError::Timeout(_) => e,
+            e => EngineError::InvocationFailed(format!("{}: {}", name, e)),
+        })
+    }
+
+    /// イメージ内の全 TypeDef を (MonoClass*, name, namespace) として列挙
+    fn enumerate_image_classes(&self, image: usize) -> Result<Vec<(usize, usize, usize)>> {
+        let rows = self.call_mono("mono_image_get_table_rows", &[image, MONO_TABLE_TYPEDEF])?;
+        let rows = (rows & 0xFFFF_FFFF).min(MAX_TYPEDEFS);
+        if rows == 0 {
+            return Ok(Vec::new());
+        }
+
+        // 関数テーブル: [mono_class_get, mono_class_get_name, mono_class_get_namespace]
+        let handle = self.handle();
+        let mut table = Vec::with_capacity(24);
+        for name in ["mono_class_get", "mono_class_get_name", "mono_class_get_namespace"] {
+            let func = resolve_remote_export(handle, &self.mono_module, name)
+                .map_err(|e| EngineError::MethodNotFound(format!("{}: {}", name, e)))?;
+            table.extend_from_slice(&func.to_le_bytes());
+        }
+
+        let code = Self::generate_class_loop();
+        let (_, out) = self.run_remote_routine(&code, &table, rows * 24, |out, data| {
+            vec![image, rows, out, data]
+        })?;
+
+        Ok(out
+            .chunks_exact(24)
+            .map(|c| {
+                (
+                    usize::from_le_bytes(c[0..8].try_into().unwrap()),
+                    usize::from_le_bytes(c[8..16].try_into().unwrap()),
+                    usize::from_le_bytes(c[16..24].try_into().unwrap()),
+                )
+            })
+            .filter(|&(klass, _, _)| klass != 0)
+            .collect())
+    }
+
+    /// TypeDef を列挙するルーチンを生成
+    ///
+    /// usize routine(MonoImage* image /* rcx */, usize rows /* rdx */,
+    ///               usize* out /* r8 */, void** funcs /* r9 */)
+    ///
+    /// out[i] = { klass, mono_class_get_name(klass), mono_class_get_namespace(klass) }
+    fn generate_class_loop() -> Vec<u8> {
+        let mut code = Vec::new();
+
+        // push rbx; push rsi; push rdi; push r12; push r13; sub rsp, 0x20
+        code.extend_from_slice(&[0x53, 0x56, 0x57, 0x41, 0x54, 0x41, 0x55, 0x48, 0x83, 0xEC, 0x20]);
+        // mov r12, rcx; mov rsi, rdx; mov rdi, r8; mov r13, r9
+        code.extend_from_slice(&[0x49, 0x89, 0xCC, 0x48, 0x89, 0xD6, 0x4C, 0x89, 0xC7, 0x4D, 0x89, 0xCD]);
+        // mov ebx, 1
+        code.extend_from_slice(&[0xBB, 0x01, 0x00, 0x00, 0x00]);
+
+        // loop: cmp rbx, rsi; ja done
+        let loop_start = code.len();
+        code.extend_from_slice(&[0x48, 0x39, 0xF3, 0x77, 0x00]);
+        let ja_patch = code.len() - 1;
+
+        // mov rcx, r12; mov edx, ebx; or edx, MONO_TOKEN_TYPE_DEF
+        code.extend_from_slice(&[0x4C, 0x89, 0xE1, 0x89, 0xDA, 0x81, 0xCA]);
+        code.extend_from_slice(&MONO_TOKEN_TYPE_DEF.to_le_bytes());
+        // call [r13] (mono_class_get); mov [rdi], rax
+        code.extend_from_slice(&[0x41, 0xFF, 0x55, 0x00, 0x48, 0x89, 0x07]);
+        // test rax, rax; jz next
+        code.extend_from_slice(&[0x48, 0x85, 0xC0, 0x74, 0x00]);
+        let jz_patch = code.len() - 1;
+
+        // mov rcx, rax; call [r13 + 8] (mono_class_get_name); mov [rdi + 8], rax
+        code.extend_from_slice(&[0x48, 0x89, 0xC1, 0x41, 0xFF, 0x55, 0x08, 0x48, 0x89, 0x47, 0x08]);
+        // mov rcx, [rdi]; call [r13 + 16] (mono_class_get_namespace); mov [rdi + 16], rax
+        code.extend_from_slice(&[0x48, 0x8B, 0x0F, 0x41, 0xFF, 0x55, 0x10, 0x48, 0x89, 0x47, 0x10]);
+
+        // next: add rdi, 24; inc rbx; jmp loop
+        let next = code.len();
+        code.extend_from_slice(&[0x48, 0x83, 0xC7, 0x18, 0x48, 0xFF, 0xC3, 0xEB, 0x00]);
+        let jmp_patch = code.len() - 1;
+
+        // done: mov rax, rsi; add rsp, 0x20; pop r13; pop r12; pop rdi; pop rsi; pop rbx; ret
+        let done = code.len();
+        code.extend_from_slice(&[
+            0x48, 0x89, 0xF0, 0x48, 0x83, 0xC4, 0x20, 0x41, 0x5D, 0x41, 0x5C, 0x5F, 0x5E, 0x5B, 0xC3,
+        ]);
+
+        code[ja_patch] = (done - (ja_patch + 1)) as u8;
+        code[jz_patch] = (next - (jz_patch + 1)) as u8;
+        code[jmp_patch] = (loop_start as isize - (jmp_patch + 1) as isize) as i8 as u8;
+
+        code
+    }
+
+    /// mono_class_get_fields のイテレータを回してフィールドを列挙するルーチンを生成
+    ///
+    /// usize routine(MonoClass* klass /* rcx */, MonoClassField** out /* rdx */,
+    ///               usize max /* r8 */, void* mono_class_get_fields /* r9 */)
+    fn generate_field_loop() -> Vec<u8> {
+        let mut code = Vec::new();
+
+        // push rbx; push rsi; push rdi; push r12; push r13; sub rsp, 0x30
+        code.extend_from_slice(&[0x53, 0x56, 0x57, 0x41, 0x54, 0x41, 0x55, 0x48, 0x83, 0xEC, 0x30]);
+        // mov r12, rcx; mov rdi, rdx; mov rsi, r8; mov r13, r9
+        code.extend_from_slice(&[0x49, 0x89, 0xCC, 0x48, 0x89, 0xD7, 0x4C, 0x89, 0xC6, 0x4D, 0x89, 0xCD]);
+        // xor ebx, ebx; mov qword [rsp + 0x20], 0 (iter)
+        code.extend_from_slice(&[0x31, 0xDB, 0x48, 0xC7, 0x44, 0x24, 0x20, 0x00, 0x00, 0x00, 0x00]);
+
+        // loop: cmp rbx, rsi; jae done
+        let loop_start = code.len();
+        code.extend_from_slice(&[0x48, 0x39, 0xF3, 0x73, 0x00]);
+        let jae_patch = code.len() - 1;
+
+        // mov rcx, r12; lea rdx, [rsp + 0x20]; call r13
+        code.extend_from_slice(&[0x4C, 0x89, 0xE1, 0x48, 0x8D, 0x54, 0x24, 0x20, 0x41, 0xFF, 0xD5]);
+        // test rax, rax; jz done
+        code.extend_from_slice(&[0x48, 0x85, 0xC0, 0x74, 0x00]);
+        let jz_patch = code.len() - 1;
+
+        // mov [rdi + rbx*8], rax; inc rbx; jmp loop
+        code.extend_from_slice(&[0x48, 0x89, 0x04, 0xDF, 0x48, 0xFF, 0xC3, 0xEB, 0x00]);
+        let jmp_patch = code.len() - 1;
+
+        // done: mov rax, rbx; add rsp, 0x30; pop r13; pop r12; pop rdi; pop rsi; pop rbx; ret
+        let done = code.len();
+        code.extend_from_slice(&[
+            0x48, 0x89, 0xD8, 0x48, 0x83, 0xC4, 0x30, 0x41, 0x5D, 0x41, 0x5C, 0x5F, 0x5E, 0x5B, 0xC3,
+        ]);
+
+        code[jae_patch] = (done - (jae_patch + 1)) as u8;
+        code[jz_patch] = (done - (jz_patch + 1)) as u8;
+        code[jmp_patch] = (loop_start as isize - (jmp_patch + 1) as isize) as i8 as u8;
+
+        code
+    }
+
+    /// NULL 終端の C 文字列を読み取る
+    fn read_c_string(&self, addr: usize) -> Option<String> {
+        if addr == 0 {
+            return None;
+        }
+
+        // ページ境界をまたがないよう小さなチャンクで読む
+        let mut bytes = Vec::new();
+        while bytes.len() < 512 {
+            let chunk = read_process_memory(self.handle(), addr + bytes.len(), 32).ok()?;
+            if let Some(pos) = chunk.iter().position(|&b| b == 0) {
+                bytes.extend_from_slice(&chunk[..pos]);
+                return Some(String::from_utf8_lossy(&bytes).into_owned());
+            }
+            bytes.extend_from_slice(&chunk);
+        }
+        Some(String::from_utf8_lossy(&bytes).into_owned())
+    }
+
+    /// "Namespace.Name" 形式のクラス名を組み立てる
+    fn format_class_name(&self, name_ptr: usize, namespace_ptr: usize) -> String {
+        let name = self.read_c_string(name_ptr).unwrap_or_else(|| "<unknown>".to_string());
+        match self.read_c_string(namespace_ptr) {
+            Some(ns) if !ns.is_empty() => format!("{}.{}", ns, name),
+            _ => name,
+        }
+    }
+
+    /// MonoType から型情報を作成
+    fn read_type_info(&self, mono_type: usize) -> TypeInfo {
+        let type_enum = read_process_memory(self.handle(), mono_type + MONO_TYPE_ENUM_OFFSET, 1)
+            .map(|b| b[0])
+            .unwrap_or(0);
+
+        let primitive = |name: &str, prim: PrimitiveType| TypeInfo {
+            name: name.to_string(),
+            size: prim.size(),
+            kind: TypeKind::Primitive(prim),
+        };
+
+        // MonoTypeEnum
+        match type_enum {
+            0x02 => primitive("bool", PrimitiveType::Bool),
+            0x03 => primitive("char", PrimitiveType::U16),
+            0x04 => primitive("sbyte", PrimitiveType::I8),
+            0x05 => primitive("byte", PrimitiveType::U8),
+            0x06 => primitive("short", PrimitiveType::I16),
+            0x07 => primitive("ushort", PrimitiveType::U16),
+            0x08 => primitive("int", PrimitiveType::I32),
+            0x09 => primitive("uint", PrimitiveType::U32),
+            0x0A => primitive("long", PrimitiveType::I64),
+            0x0B => primitive("ulong", PrimitiveType::U64),
+            0x0C => primitive("float", PrimitiveType::F32),
+            0x0D => primitive("double", PrimitiveType::F64),
+            0x0E | 0x12 | 0x14 | 0x15 | 0x1C | 0x1D => TypeInfo {
+                name: match type_enum {
+                    0x0E => "string",
+                    0x14 | 0x1D => "array",
+                    _ => "object",
+                }
+                .to_string(),
+                size: 8,
+                kind: TypeKind::Pointer(Box::new(TypeInfo {
+                    name: "object".to_string(),
+                    size: 0,
+                    kind: TypeKind::Unknown,
+                })),
+            },
+            _ => TypeInfo {
+                name: format!("MonoType(0x{:02X})", type_enum),
+                size: 0,
+                kind: TypeKind::Unknown,
+            },
+        }
+    }
 }
 
 impl GameEngine for UnityMonoEngine {
@@ -29,9 +474,37 @@ impl GameEngine for UnityMonoEngine {
     }
 
     fn initialize(&mut self) -> Result<()> {
-        Err(EngineError::InitializationFailed(
-            "Unity Mono backend not implemented".into(),
-        ))
+        if self.initialized {
+            return Ok(());
+        }
+
+        tracing::info!("Initializing Unity Mono engine...");
+
+        // 1. Mono モジュールを検索
+        let module = find_module(self.process_id, MONO_MODULE_NAMES).map_err(|e| {
+            EngineError::InitializationFailed(format!("Mono runtime module not found: {}", e))
+        })?;
+        tracing::info!("Mono module: {} at 0x{:X}", module.name, module.base_address);
+        self.mono_module = module;
+
+        // 2. ルートドメインを取得
+        self.root_domain = self.find_root_domain()?;
+        if self.root_domain == 0 {
+            return Err(EngineError::InitializationFailed(
+                "mono_get_root_domain returned null".into(),
+            ));
+        }
+        tracing::info!("Root domain: 0x{:X}", self.root_domain);
+
+        // 3. 以降のリモート呼び出しでスレッドを登録するための mono_thread_attach
+        self.thread_attach = self.resolve_export("mono_thread_attach")?;
+
+        // 4. アセンブリを列挙
+        self.assemblies = self.collect_assemblies()?;
+        tracing::info!("Found {} assemblies", self.assemblies.len());
+
+        self.initialized = true;
+        Ok(())
     }
 
     fn is_initialized(&self) -> bool {
@@ -39,15 +512,61 @@ impl GameEngine for UnityMonoEngine {
     }
 
     fn find_class(&self, name: &str) -> Result<ClassHandle> {
-        Err(EngineError::ClassNotFound(name.to_string()))
+        // "Namespace.Name" と "Name" のどちらでも一致を許可
+        self.enumerate_classes()?
+            .into_iter()
+            .find(|c| c.name == name || c.name.rsplit('.').next() == Some(name))
+            .map(|c| c.handle)
+            .ok_or_else(|| EngineError::ClassNotFound(name.to_string()))
     }
 
-    fn get_class_info(&self, _class: ClassHandle) -> Result<ClassInfo> {
-        Err(EngineError::UnsupportedOperation("Not implemented".into()))
+    fn get_class_info(&self, class: ClassHandle) -> Result<ClassInfo> {
+        if !self.initialized {
+            return Err(EngineError::NotInitialized);
+        }
+
+        let name_ptr = self.call_mono("mono_class_get_name", &[class.0])?;
+        let namespace_ptr = self.call_mono("mono_class_get_namespace", &[class.0])?;
+
+        Ok(ClassInfo {
+            name: self.format_class_name(name_ptr, namespace_ptr),
+            handle: class,
+            parent: None,
+            size: 0,
+        })
     }
 
     fn enumerate_classes(&self) -> Result<Vec<ClassInfo>> {
-        Ok(Vec::new())
+        if !self.initialized {
+            return Err(EngineError::NotInitialized);
+        }
+
+        let mut classes = Vec::new();
+        for &assembly in &self.assemblies {
+            let image = match self.call_mono("mono_assembly_get_image", &[assembly]) {
+                Ok(image) if image != 0 => image,
+                _ => continue,
+            };
+
+            match self.enumerate_image_classes(image) {
+                Ok(entries) => {
+                    for (klass, name_ptr, namespace_ptr) in entries {
+                        classes.push(ClassInfo {
+                            name: self.format_class_name(name_ptr, namespace_ptr),
+                            handle: ClassHandle(klass),
+                            parent: None,
+                            size: 0,
+                        });
+                    }
+                }
+                Err(e) => {
+                    tracing::warn!("Failed to enumerate classes of image 0x{:X}: {}", image, e);
+                }
+            }
+        }
+
+        tracing::info!("enumerate_classes: found {} classes", classes.len());
+        Ok(classes)
     }
 
     fn find_method(&self, _class: ClassHandle, name: &str) -> Result<MethodHandle> {
@@ -62,16 +581,58 @@ impl GameEngine for UnityMonoEngine {
         Ok(Vec::new())
     }
 
-    fn find_field(&self, _class: ClassHandle, name: &str) -> Result<FieldHandle> {
-        Err(EngineError::FieldNotFound(name.to_string()))
+    fn find_field(&self, class: ClassHandle, name: &str) -> Result<FieldHandle> {
+        self.enumerate_fields(class)?
+            .into_iter()
+            .find(|f| f.name == name)
+            .map(|f| f.handle)
+            .ok_or_else(|| EngineError::FieldNotFound(name.to_string()))
     }
 
-    fn get_field_info(&self, _field: FieldHandle) -> Result<FieldInfo> {
-        Err(EngineError::UnsupportedOperation("Not implemented".into()))
+    fn get_field_info(&self, field: FieldHandle) -> Result<FieldInfo> {
+        // MonoClassField を直接読み取る
+        let data = read_process_memory(self.handle(), field.0, 0x20)?;
+        let read_ptr = |offset: usize| usize::from_le_bytes(data[offset..offset + 8].try_into().unwrap());
+
+        let mono_type = read_ptr(MONO_FIELD_TYPE_OFFSET);
+        let name = self
+            .read_c_string(read_ptr(MONO_FIELD_NAME_OFFSET))
+            .unwrap_or_else(|| format!("field_0x{:X}", field.0));
+        let offset = i32::from_le_bytes(
+            data[MONO_FIELD_OFFSET_OFFSET..MONO_FIELD_OFFSET_OFFSET + 4].try_into().unwrap(),
+        );
+
+        Ok(FieldInfo {
+            name,
+            handle: field,
+            offset: offset.max(0) as usize,
+            type_info: self.read_type_info(mono_type),
+        })
     }
 
-    fn enumerate_fields(&self, _class: ClassHandle) -> Result<Vec<FieldInfo>> {
-        Ok(Vec::new())
+    fn enumerate_fields(&self, class: ClassHandle) -> Result<Vec<FieldInfo>> {
+        if !self.initialized {
+            return Err(EngineError::NotInitialized);
+        }
+
+        let get_fields = resolve_remote_export(self.handle(), &self.mono_module, "mono_class_get_fields")
+            .map_err(|e| EngineError::MethodNotFound(format!("mono_class_get_fields: {}", e)))?;
+
+        let code = Self::generate_field_loop();
+        let (count, out) = self.run_remote_routine(&code, &[], MAX_FIELDS * 8, |out, _| {
+            vec![class.0, out, MAX_FIELDS, get_fields]
+        })?;
+
+        let fields: Vec<FieldInfo> = out
+            .chunks_exact(8)
+            .take(count.min(MAX_FIELDS))
+            .map(|c| usize::from_le_bytes(c.try_into().unwrap()))
+            .filter(|&addr| addr != 0)
+            .filter_map(|addr| self.get_field_info(FieldHandle(addr)).ok())
+            .collect();
+
+        tracing::info!("enumerate_fields: found {} fields", fields.len());
+        Ok(fields)
     }
 
     fn get_instances(&self, _class: ClassHandle) -> Result<Vec<InstanceHandle>> {
@@ -1,12 +1,45 @@
 /// Unreal Engine backend implementation details
 
 use super::scanner::{scan_pattern, Pattern};
-use super::signatures::VersionSignatures;
+use super::signatures::{UESignatures, VersionSignatures};
+use super::offsets::UEOffsets;
 use super::structures::{FNamePool, FUObjectArray, UObject};
-use super::{EngineError, Result, UnrealEngine};
+use super::{EngineError, Result, UEVersion, UnrealEngine};
 use crate::platform::windows::{read_process_memory, HANDLE};
+use std::time::{Duration, Instant};
 use windows::Win32::Foundation::HANDLE as WinHandle;
 
+/// GNames 候補の採点で読むエントリ数
+const GNAMES_SCORE_ENTRIES: usize = 5;
+
+/// GObjects を辿る処理の制限時間（壊れた配列や応答しないプロセスで終わらなくならないように）
+const OBJECT_TRAVERSAL_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// `started` から OBJECT_TRAVERSAL_TIMEOUT を過ぎていれば Timeout を返す
+pub(super) fn check_traversal_timeout(started: Instant) -> Result<()> {
+    let elapsed = started.elapsed();
+    if elapsed >= OBJECT_TRAVERSAL_TIMEOUT {
+        return Err(EngineError::Timeout(elapsed));
+    }
+    Ok(())
+}
+
+/// UE が起動時に最初に登録する名前 (UnrealNames.inl の先頭付近)
+const GNAMES_BOOTSTRAP_NAMES: [&str; 12] = [
+    "None",
+    "ByteProperty",
+    "IntProperty",
+    "BoolProperty",
+    "FloatProperty",
+    "ObjectProperty",
+    "NameProperty",
+    "DelegateProperty",
+    "DoubleProperty",
+    "ArrayProperty",
+    "StructProperty",
+    "VectorProperty",
+];
+
 impl UnrealEngine {
     /// 値がASCIIテキストのように見えるかチェック（誤検出回避用）
     fn looks_like_ascii(value: usize) -> bool {
@@ -128,7 +161,7 @@ impl UnrealEngine {
     /// 検索戦略:
     /// 1. 複数のパターンで候補を収集
     /// 2. 各候補について、index 0 が "None" になるかチェック
-    /// 3. "None" が読めた候補を採用
+    /// 3. "None" が読めた候補を score_gnames_candidate で採点し、最高得点の候補を採用
     pub(super) fn find_gnames_impl(&self) -> Result<usize> {
         let handle = unsafe { std::mem::transmute::<usize, WinHandle>(self.process_handle) };
 
@@ -152,16 +185,7 @@ impl UnrealEngine {
 
                     for result in results.iter().take(50) {
                         // パターンに応じてオフセット位置を調整
-                        let (offset_pos, instruction_end) = match *pattern_str {
-                            // 48 8D 0D (lea rcx, [rip+offset])
-                            s if s.starts_with("48 8D 0D") => (3, 7),
-                            // 48 8B 1D (mov rbx, [rip+offset])
-                            s if s.starts_with("48 8B 1D") => (3, 7),
-                            // 長いパターン (ALT2)
-                            s if s.len() > 50 => (pattern.len() - 7, pattern.len() - 3),
-                            // デフォルト: 48 8B 05 (mov rax, [rip+offset])
-                            _ => (3, 7),
-                        };
+                        let (offset_pos, instruction_end) = gnames_operand_layout(pattern_str, pattern.len());
 
                         // RIP相対アドレスを解決
                         let inst_data = match read_process_memory(handle, result.address, pattern.len() + 8) {
@@ -169,18 +193,11 @@ impl UnrealEngine {
                             Err(_) => continue,
                         };
 
-                        if inst_data.len() < offset_pos + 4 {
+                        let Some(gnames_ptr) =
+                            rip_relative_target(result.address, &inst_data, offset_pos, instruction_end)
+                        else {
                             continue;
-                        }
-
-                        let rel_offset = i32::from_le_bytes([
-                            inst_data[offset_pos],
-                            inst_data[offset_pos + 1],
-                            inst_data[offset_pos + 2],
-                            inst_data[offset_pos + 3],
-                        ]);
-
-                        let gnames_ptr = (result.address as i64 + instruction_end as i64 + rel_offset as i64) as usize;
+                        };
 
                         if gnames_ptr > module_base && gnames_ptr < module_base + module_size + 0x10000000 {
                             if !all_candidates.iter().any(|(addr, _)| *addr == gnames_ptr) {
@@ -209,7 +226,9 @@ impl UnrealEngine {
             }
         }
 
-        // すべての候補を検証 - "None" が読めるものを探す
+        // すべての候補を検証 - "None" が読めるものを採点する
+        // (返す ptr_addr, Blocks アドレス, スコア, パターン)
+        let mut scored: Vec<(usize, usize, u32, &str)> = Vec::new();
         for (idx, (ptr_addr, pattern)) in all_candidates.iter().enumerate() {
             // 候補周辺のオフセットも試す（ポインタの間接参照など）
             for addr_offset in [0i64, 8, 16, -8, -16] {
@@ -246,12 +265,17 @@ impl UnrealEngine {
                         // "None" が index 0 で読めるかテスト
                         if let Some(name) = self.try_read_fname_at_index_0(handle, blocks_addr) {
                             if name == "None" {
-                                tracing::info!("Found valid GNames at 0x{:X} (blocks at 0x{:X}, pattern={}, idx={})",
-                                    test_addr, blocks_addr, pattern, idx);
-                                // blocks_addr を直接返す（Blocks 配列のアドレス）
-                                // ただし mod.rs では gnames_ptr を返すので、ここでは test_addr を返す
-                                // get_fname_impl で blocks_offset=16 を使う前提
-                                return Ok(try_ptr_addr);
+                                let score = self.score_gnames_candidate(handle, blocks_addr);
+                                tracing::debug!("GNames candidate 0x{:X} (blocks at 0x{:X}, pattern={}, idx={}): score {}",
+                                    test_addr, blocks_addr, pattern, idx, score);
+
+                                // mod.rs では gnames_ptr として扱うので、Blocks ではなく try_ptr_addr を返す
+                                // (get_fname_impl で blocks_offset=16 を使う前提)
+                                match scored.iter_mut().find(|c| c.0 == try_ptr_addr) {
+                                    Some(existing) if existing.2 < score => *existing = (try_ptr_addr, blocks_addr, score, pattern),
+                                    Some(_) => {}
+                                    None => scored.push((try_ptr_addr, blocks_addr, score, pattern)),
+                                }
                             } else if !name.is_empty() {
                                 tracing::debug!("Candidate 0x{:X} (offset {}) has first entry '{}' (not 'None')",
                                     blocks_addr, blocks_offset, name);
@@ -262,6 +286,17 @@ impl UnrealEngine {
             }
         }
 
+        // 同点なら先に見つかった候補を優先 (安定ソート)
+        scored.sort_by_key(|c| std::cmp::Reverse(c.2));
+        for (rank, (ptr_addr, blocks_addr, score, pattern)) in scored.iter().take(3).enumerate() {
+            tracing::info!("GNames candidate #{}: 0x{:X} (blocks at 0x{:X}, pattern={}) score {}/{}",
+                rank + 1, ptr_addr, blocks_addr, pattern, score, GNAMES_SCORE_ENTRIES);
+        }
+        if let Some(&(ptr_addr, blocks_addr, score, _)) = scored.first() {
+            tracing::info!("Found valid GNames at 0x{:X} (blocks at 0x{:X}, score {})", ptr_addr, blocks_addr, score);
+            return Ok(ptr_addr);
+        }
+
         // 見つからなかった場合は元の検証方法にフォールバック
         tracing::warn!("'None' entry not found via pattern, falling back to original validation");
         for (idx, (ptr_addr, pattern)) in all_candidates.iter().enumerate() {
@@ -327,6 +362,52 @@ impl UnrealEngine {
         ))
     }
 
+    /// GNames 候補の信頼度 (0〜GNAMES_SCORE_ENTRIES)
+    ///
+    /// Blocks[0] の先頭から順にエントリ (index 0, 1, 2, ...) を読み、
+    /// UE が起動時に登録する名前と一致した数を返す
+    fn score_gnames_candidate(&self, handle: WinHandle, blocks_addr: usize) -> u32 {
+        let Ok(block0_data) = read_process_memory(handle, blocks_addr, 8) else {
+            return 0;
+        };
+        let block0 = usize::from_le_bytes(block0_data[..8].try_into().unwrap());
+        if !self.is_valid_pointer(block0) {
+            return 0;
+        }
+
+        // 起動時の名前はどれも短いので、1 エントリ 64 文字までで十分
+        let Ok(data) = read_process_memory(handle, block0, GNAMES_SCORE_ENTRIES * (2 + 64)) else {
+            return 0;
+        };
+
+        let mut score = 0;
+        let mut offset = 0;
+        for _ in 0..GNAMES_SCORE_ENTRIES {
+            let Some(header) = data.get(offset..offset + 2) else {
+                break;
+            };
+            let header = u16::from_le_bytes([header[0], header[1]]);
+            let is_wide = (header & 1) != 0;
+            let len = (header >> 6) as usize;
+            let byte_len = if is_wide { len * 2 } else { len };
+
+            let Some(name) = data.get(offset + 2..offset + 2 + byte_len) else {
+                break;
+            };
+            if len == 0 {
+                break;
+            }
+            if !is_wide && std::str::from_utf8(name).is_ok_and(|name| GNAMES_BOOTSTRAP_NAMES.contains(&name)) {
+                score += 1;
+            }
+
+            // エントリは alignof(FNameEntry) 境界に並ぶ
+            offset = (offset + 2 + byte_len).next_multiple_of(FNamePool::STRIDE);
+        }
+
+        score
+    }
+
     /// index 0 の FName を読んでみる (検証用)
     fn try_read_fname_at_index_0(&self, handle: WinHandle, blocks_addr: usize) -> Option<String> {
         // Blocks[0] を読む
@@ -897,7 +978,7 @@ impl UnrealEngine {
     }
 
     /// ポインタが有効なアドレス範囲かチェック
-    fn is_valid_pointer(&self, ptr: usize) -> bool {
+    pub(super) fn is_valid_pointer(&self, ptr: usize) -> bool {
         ptr > 0x10000 && ptr < 0x7FFFFFFFFFFF
     }
 
@@ -932,7 +1013,13 @@ impl UnrealEngine {
     }
 
     /// チャンク配列から全オブジェクトを読み取る
-    fn read_objects_from_chunk_array(&self, handle: WinHandle, chunk_array_ptr: usize) -> Result<Vec<usize>> {
+    fn read_objects_from_chunk_array(
+        &self,
+        handle: WinHandle,
+        chunk_array_ptr: usize,
+        accept: &dyn Fn(&super::structures::FUObjectItem) -> bool,
+        started: Instant,
+    ) -> Result<Vec<usize>> {
         use super::structures::FUObjectItem;
 
         let mut objects = Vec::new();
@@ -976,10 +1063,11 @@ impl UnrealEngine {
             let mut chunk_objects = 0;
             let mut consecutive_empty = 0;
             for within_chunk in 0..ELEMENTS_PER_CHUNK {
+                check_traversal_timeout(started)?;
                 let item_addr = chunk_ptr + (within_chunk * FUObjectItem::SIZE_UE5);
                 match FUObjectItem::read(handle, item_addr) {
                     Ok(item) => {
-                        if item.is_valid() && self.is_valid_fuobject_item(&item) {
+                        if accept(&item) && self.is_valid_fuobject_item(&item) {
                             objects.push(item.object);
                             chunk_objects += 1;
                             consecutive_empty = 0;
@@ -1049,6 +1137,38 @@ impl UnrealEngine {
         None
     }
 
+    /// メインモジュールに埋め込まれたブランチ名から UE バージョンを検出
+    ///
+    /// 見つからない・未対応のバージョンなら Unknown（新しいレイアウト扱い）
+    pub(super) fn detect_version_impl(&self) -> UEVersion {
+        let handle = unsafe { std::mem::transmute::<usize, WinHandle>(self.process_handle) };
+        let pattern = Pattern::from_string(UESignatures::RELEASE_BRANCH);
+
+        let results = match scan_pattern(handle, &pattern, self.module_base, self.module_size) {
+            Ok(results) => results,
+            Err(e) => {
+                tracing::warn!("Version string scan failed: {}", e);
+                return UEVersion::Unknown;
+            }
+        };
+
+        for result in results.iter().take(16) {
+            // "+Release-" の後ろの "5.3" 部分まで読む
+            let Ok(data) = read_process_memory(handle, result.address, pattern.len() + 16) else {
+                continue;
+            };
+            let branch = decode_utf16_prefix(&data);
+            let version = UEVersion::from_branch_name(&branch);
+            if version != UEVersion::Unknown {
+                tracing::info!("Detected {:?} from branch name \"{}\"", version, branch);
+                return version;
+            }
+        }
+
+        tracing::warn!("UE version not detected ({} branch name candidates)", results.len());
+        UEVersion::Unknown
+    }
+
     /// ProcessEvent のアドレスを検索
     pub(super) fn find_process_event_impl(&self) -> Result<usize> {
         let handle = unsafe { std::mem::transmute::<usize, WinHandle>(self.process_handle) };
@@ -1265,21 +1385,75 @@ impl UnrealEngine {
         }
     }
 
-    /// UObject の名前を取得
+    /// FName の番号サフィックス込みで名前を取得
+    ///
+    /// 同名オブジェクトは "Actor", "Actor_0", "Actor_1" ... と区別される。
+    /// FName::Number は 0 ならサフィックスなし、それ以外は (Number - 1) がサフィックス
+    pub(super) fn get_fname_full(&self, index: u32, number: u32) -> Result<String> {
+        let base_name = self.get_fname_impl(index)?;
+        if number == 0 {
+            Ok(base_name)
+        } else {
+            Ok(format!("{}_{}", base_name, number - 1))
+        }
+    }
+
+    /// UObject の名前を取得（番号サフィックス込み）
     pub(super) fn get_object_name_impl(&self, obj_addr: usize) -> Result<String> {
         let handle = unsafe { std::mem::transmute::<usize, WinHandle>(self.process_handle) };
 
         let obj = UObject::read(handle, obj_addr)?;
-        self.get_fname_impl(obj.name.comparison_index)
+        self.get_fname_full(obj.name.comparison_index, obj.name.number)
     }
 
     /// GObjects から全オブジェクトを取得
+    ///
+    /// まず FUObjectItem::is_valid_strict で誤検出を減らす。vtable はロード済みの全モジュールで
+    /// 判定するのでモジュラービルドでも通るが、それでも何も取れなければ is_valid で読み直す
     pub(super) fn get_all_objects_impl(&self) -> Result<Vec<usize>> {
-        use super::structures::{FChunkedFixedUObjectArray, FUObjectItem};
-        use crate::platform::windows::read_process_memory;
+        use super::structures::{FUObjectItem, ModuleRanges};
+        use crate::platform::module::{list_modules, ModuleInfo};
+        use crate::platform::windows::query_memory_regions;
 
         let handle = unsafe { std::mem::transmute::<usize, WinHandle>(self.process_handle) };
 
+        let modules = list_modules(self.process_id).unwrap_or_else(|_| {
+            vec![ModuleInfo { name: String::new(), base_address: self.module_base, size: self.module_size }]
+        });
+        let modules = ModuleRanges::read(handle, &modules);
+
+        // ヒープはコミット済みのプライベート領域（アドレス順）
+        let heap: Vec<(usize, usize)> = query_memory_regions(handle)
+            .map(|regions| {
+                regions
+                    .iter()
+                    .filter(|r| r.is_private())
+                    .map(|r| (r.base_address, r.base_address + r.size))
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        let started = Instant::now();
+        let strict = |item: &FUObjectItem| item.is_valid_strict(handle, &modules, &heap);
+        match self.collect_objects(handle, &strict, started) {
+            Ok(objects) if !objects.is_empty() => return Ok(objects),
+            Err(e @ EngineError::Timeout(_)) => return Err(e),
+            _ => tracing::warn!("No objects passed strict FUObjectItem validation, retrying with is_valid"),
+        }
+
+        self.collect_objects(handle, &FUObjectItem::is_valid, started)
+    }
+
+    /// GObjects のレイアウトを順に試し、accept を満たすオブジェクトを集める
+    fn collect_objects(
+        &self,
+        handle: WinHandle,
+        accept: &dyn Fn(&super::structures::FUObjectItem) -> bool,
+        started: Instant,
+    ) -> Result<Vec<usize>> {
+        use super::structures::{FChunkedFixedUObjectArray, FUObjectItem};
+        use crate::platform::windows::read_process_memory;
+
         tracing::info!("get_all_objects_impl: gobjects = 0x{:X}", self.gobjects);
 
         // デバッグ: gobjects周辺のメモリダンプ
@@ -1323,7 +1497,7 @@ impl UnrealEngine {
                     if item.object != 0 && item.object > 0x10000 {
                         // gobjects はチャンク配列を直接指している
                         tracing::info!("gobjects is a direct chunk array pointer, reading objects...");
-                        return self.read_objects_from_chunk_array(handle, self.gobjects);
+                        return self.read_objects_from_chunk_array(handle, self.gobjects, accept, started);
                     }
                 }
             }
@@ -1340,7 +1514,7 @@ impl UnrealEngine {
                     && uobject_array.obj_objects.num_chunks <= uobject_array.obj_objects.max_chunks
                 {
                     tracing::info!("Reading {} objects from FUObjectArray", uobject_array.obj_objects.num_elements);
-                    return Ok(uobject_array.get_all_objects(handle));
+                    return Ok(uobject_array.get_all_objects_with(handle, accept));
                 }
                 tracing::warn!("FUObjectArray has invalid fields (ObjFirstGCIndex={}, NumElements={}, NumChunks={}), trying alternatives",
                     uobject_array.obj_first_gc_index,
@@ -1380,11 +1554,12 @@ impl UnrealEngine {
                 let mut objects = Vec::new();
                 let mut failed_count = 0;
                 for i in 0..chunked_array.num_elements {
+                    check_traversal_timeout(started)?;
                     match chunked_array.get_object_item_address(handle, i) {
                         Ok(item_addr) => {
                             match FUObjectItem::read(handle, item_addr) {
                                 Ok(item) => {
-                                    if item.is_valid() {
+                                    if accept(&item) {
                                         objects.push(item.object);
                                     }
                                 }
@@ -1425,6 +1600,7 @@ impl UnrealEngine {
                 const ELEMENTS_PER_CHUNK: i32 = 64 * 1024;
 
                 for i in 0..num_elements {
+                    check_traversal_timeout(started)?;
                     let chunk_index = i / ELEMENTS_PER_CHUNK;
                     let within_chunk = i % ELEMENTS_PER_CHUNK;
 
@@ -1435,7 +1611,7 @@ impl UnrealEngine {
                         if chunk_ptr != 0 {
                             let item_addr = chunk_ptr + (within_chunk as usize * FUObjectItem::SIZE_UE5);
                             if let Ok(item) = FUObjectItem::read(handle, item_addr) {
-                                if item.is_valid() {
+                                if accept(&item) {
                                     objects.push(item.object);
                                 }
                             }
@@ -1477,10 +1653,11 @@ impl UnrealEngine {
 
                         // このチャンク内のオブジェクトを読む
                         for within_chunk in 0..ELEMENTS_PER_CHUNK {
+                            check_traversal_timeout(started)?;
                             let item_addr = chunk_ptr + (within_chunk * FUObjectItem::SIZE_UE5);
                             match FUObjectItem::read(handle, item_addr) {
                                 Ok(item) => {
-                                    if item.is_valid() {
+                                    if accept(&item) {
                                         objects.push(item.object);
                                     } else if item.object == 0 && within_chunk > 0 {
                                         // 空のスロットが続いたら次のチャンクへ
@@ -1533,4 +1710,326 @@ impl UnrealEngine {
 
         Err(EngineError::ClassNotFound(name.to_string()))
     }
+
+    /// UGameEngine シングルトン (GEngine) を GObjects から検索
+    ///
+    /// クラス名が "GameEngine" のオブジェクトのうち、CDO (Default__GameEngine) 以外を返す
+    pub(super) fn find_game_engine_impl(&self) -> Result<usize> {
+        let all_objects = self.get_all_objects_impl()?;
+        let handle = unsafe { std::mem::transmute::<usize, WinHandle>(self.process_handle) };
+
+        for obj_addr in all_objects {
+            let Ok(obj) = UObject::read(handle, obj_addr) else {
+                continue;
+            };
+            if obj.class == 0 {
+                continue;
+            }
+
+            let Ok(class_name) = self.get_object_name_impl(obj.class) else {
+                continue;
+            };
+            if class_name != "GameEngine" {
+                continue;
+            }
+
+            if let Ok(obj_name) = self.get_object_name_impl(obj_addr) {
+                if obj_name.starts_with("Default__") {
+                    continue;
+                }
+            }
+
+            tracing::info!("Found GameEngine instance at 0x{:X}", obj_addr);
+            return Ok(obj_addr);
+        }
+
+        Err(EngineError::InstanceNotFound)
+    }
+
+    /// ゲームの UWorld を GObjects から検索
+    ///
+    /// クラス名が "World" で、最外の Outer (パッケージ) が "/Game/" 配下のものを返す
+    pub(super) fn find_uworld_impl(&self) -> Result<usize> {
+        let all_objects = self.get_all_objects_impl()?;
+        let handle = unsafe { std::mem::transmute::<usize, WinHandle>(self.process_handle) };
+
+        for obj_addr in all_objects {
+            let Ok(obj) = UObject::read(handle, obj_addr) else {
+                continue;
+            };
+            if obj.class == 0 || obj.outer == 0 {
+                continue;
+            }
+
+            if !matches!(self.get_object_name_impl(obj.class), Ok(name) if name == "World") {
+                continue;
+            }
+
+            let Some(package) = self.outermost_object(handle, obj_addr) else {
+                continue;
+            };
+            if !matches!(self.get_object_name_impl(package), Ok(name) if name.starts_with("/Game/")) {
+                continue;
+            }
+
+            tracing::info!("Found UWorld at 0x{:X}", obj_addr);
+            return Ok(obj_addr);
+        }
+
+        Err(EngineError::InstanceNotFound)
+    }
+
+    /// UWorld::GameState を読み取る
+    pub(super) fn get_game_state_impl(&self, world_addr: usize) -> Result<usize> {
+        let handle = unsafe { std::mem::transmute::<usize, WinHandle>(self.process_handle) };
+
+        // リフレクションでオフセットを取得し、失敗したらバージョン別の既定値を使う
+        let world = UObject::read(handle, world_addr)?;
+        let offset = self
+            .find_field_impl(world.class, "GameState")
+            .and_then(|field| self.get_field_info_impl(field))
+            .map(|info| info.offset)
+            .unwrap_or_else(|_| UEOffsets::for_version(self.version).uworld_game_state);
+
+        let data = read_process_memory(handle, world_addr + offset, 8)?;
+        let game_state = usize::from_le_bytes(data[..8].try_into().unwrap());
+
+        if !self.is_valid_pointer(game_state) {
+            return Err(EngineError::InstanceNotFound);
+        }
+
+        tracing::info!("Found GameState at 0x{:X} (UWorld+0x{:X})", game_state, offset);
+        Ok(game_state)
+    }
+
+    /// Outer チェーンをたどって最外のオブジェクト（パッケージ）を返す
+    fn outermost_object(&self, handle: WinHandle, obj_addr: usize) -> Option<usize> {
+        let mut current = obj_addr;
+
+        // 循環参照対策として深さを制限
+        for _ in 0..32 {
+            let obj = UObject::read(handle, current).ok()?;
+            if obj.outer == 0 {
+                return Some(current);
+            }
+            if !self.is_valid_pointer(obj.outer) {
+                return None;
+            }
+            current = obj.outer;
+        }
+
+        None
+    }
+
+    /// パッケージ (Outer を持たない最外オブジェクト) のパスを返す (例: /Game/Maps/Lobby)
+    ///
+    /// UPackage はロングパッケージ名を自身の FName として持つので、名前をそのまま使う
+    pub(super) fn get_package_path_impl(&self, pkg_addr: usize) -> Result<String> {
+        let handle = unsafe { std::mem::transmute::<usize, WinHandle>(self.process_handle) };
+
+        let package = UObject::read(handle, pkg_addr)?;
+        if package.outer != 0 {
+            return Err(EngineError::InvalidArgument(format!(
+                "0x{:X} is not a package (it has an Outer)",
+                pkg_addr
+            )));
+        }
+
+        self.get_object_name_impl(pkg_addr)
+    }
+
+    /// Outer チェーンから "/Game/Maps/Lobby.Lobby:PersistentLevel.Actor_1" 形式のパスを組み立てる
+    ///
+    /// UE の GetPathName と同じく、パッケージ直下のオブジェクトとその子の間だけ ':' で区切る
+    pub(super) fn get_full_object_path_impl(&self, obj_addr: usize) -> Result<String> {
+        let handle = unsafe { std::mem::transmute::<usize, WinHandle>(self.process_handle) };
+
+        // 自身から最外のパッケージまで (循環参照対策として深さを制限)
+        let mut chain = vec![obj_addr];
+        loop {
+            let current = *chain.last().unwrap();
+            let obj = UObject::read(handle, current)?;
+            if obj.outer == 0 {
+                break;
+            }
+            if chain.len() >= 32 || !self.is_valid_pointer(obj.outer) {
+                return Err(EngineError::MemoryError(format!(
+                    "Broken Outer chain at 0x{:X}",
+                    current
+                )));
+            }
+            chain.push(obj.outer);
+        }
+        chain.reverse();
+
+        let mut path = self.get_package_path_impl(chain[0])?;
+        for (depth, &addr) in chain.iter().enumerate().skip(1) {
+            path.push(if depth == 2 { ':' } else { '.' });
+            path.push_str(&self.get_object_name_impl(addr)?);
+        }
+
+        Ok(path)
+    }
+
+    /// FNamePool の CurrentBlock / CurrentByteCursor からブロック数と使用量を求める
+    ///
+    /// エントリ数は確保済みブロックを先頭から辿って数える
+    /// (ヘッダの Len が 0 になった位置がブロックの終端)
+    pub(super) fn gnames_stats_impl(&self) -> Result<super::FNamePoolStats> {
+        let handle = unsafe { std::mem::transmute::<usize, WinHandle>(self.process_handle) };
+
+        let header = read_process_memory(handle, self.gnames, FNamePool::BLOCKS_OFFSET)?;
+        let read_u32 = |offset: usize| u32::from_le_bytes(header[offset..offset + 4].try_into().unwrap());
+        let current_block = read_u32(FNamePool::CURRENT_BLOCK_OFFSET);
+        let current_cursor = read_u32(FNamePool::CURRENT_BYTE_CURSOR_OFFSET) as usize;
+
+        if current_block >= FNamePool::MAX_BLOCKS || current_cursor > FNamePool::BLOCK_SIZE_BYTES {
+            return Err(EngineError::InitializationFailed(format!(
+                "GNames at 0x{:X} does not look like an FNamePool (CurrentBlock={}, CurrentByteCursor={})",
+                self.gnames, current_block, current_cursor
+            )));
+        }
+
+        let blocks_addr = self.gnames + FNamePool::BLOCKS_OFFSET;
+        let block_count = current_block as usize + 1;
+        let block_ptrs = read_process_memory(handle, blocks_addr, block_count * 8)?;
+
+        let mut total_blocks = 0u32;
+        let mut used_entries = 0u32;
+        for (index, ptr) in block_ptrs.chunks_exact(8).enumerate() {
+            let block = usize::from_le_bytes(ptr.try_into().unwrap());
+            if block == 0 {
+                break;
+            }
+            total_blocks += 1;
+
+            let used_bytes = if index == current_block as usize {
+                current_cursor
+            } else {
+                FNamePool::BLOCK_SIZE_BYTES
+            };
+            let Ok(data) = read_process_memory(handle, block, used_bytes) else {
+                continue;
+            };
+
+            // FNameEntryHeader: bit 0 = bIsWide, bits 6-15 = Len
+            let mut offset = 0;
+            while offset + 2 <= data.len() {
+                let entry_header = u16::from_le_bytes([data[offset], data[offset + 1]]);
+                let len = (entry_header >> 6) as usize;
+                if len == 0 {
+                    break;
+                }
+                let char_size = if entry_header & 1 != 0 { 2 } else { 1 };
+                used_entries += 1;
+                offset += (2 + len * char_size).next_multiple_of(FNamePool::STRIDE);
+            }
+        }
+
+        let used_bytes = if total_blocks == 0 {
+            0
+        } else {
+            current_block as usize * FNamePool::BLOCK_SIZE_BYTES + current_cursor
+        };
+
+        Ok(super::FNamePoolStats {
+            total_blocks,
+            used_entries,
+            estimated_memory_kb: (used_bytes / 1024) as u32,
+        })
+    }
+}
+
+/// GNames パターンの (rel32 の位置, 命令の終端) を返す
+fn gnames_operand_layout(pattern_str: &str, pattern_len: usize) -> (usize, usize) {
+    match pattern_str {
+        // 48 8D 0D (lea rcx, [rip+offset])
+        s if s.starts_with("48 8D 0D") => (3, 7),
+        // 48 8B 1D (mov rbx, [rip+offset])
+        s if s.starts_with("48 8B 1D") => (3, 7),
+        // 48 8D 05 (lea rax, [rip+offset]) - 長いが先頭の命令を使う (UE5.5)
+        s if s.starts_with("48 8D 05") => (3, 7),
+        // 長いパターン (ALT2)
+        s if s.len() > 50 => (pattern_len - 7, pattern_len - 3),
+        // デフォルト: 48 8B 05 (mov rax, [rip+offset])
+        _ => (3, 7),
+    }
+}
+
+/// UTF-16LE のバイト列を NULL 文字まで文字列にする
+fn decode_utf16_prefix(data: &[u8]) -> String {
+    let units: Vec<u16> = data
+        .chunks_exact(2)
+        .map(|c| u16::from_le_bytes([c[0], c[1]]))
+        .take_while(|&c| c != 0)
+        .collect();
+    String::from_utf16_lossy(&units)
+}
+
+/// 命令列 `data` (先頭が `address`) の rel32 から RIP 相対の参照先を計算する
+fn rip_relative_target(address: usize, data: &[u8], offset_pos: usize, instruction_end: usize) -> Option<usize> {
+    let rel_offset = i32::from_le_bytes(data.get(offset_pos..offset_pos + 4)?.try_into().ok()?);
+    Some((address as i64 + instruction_end as i64 + rel_offset as i64) as usize)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// UE5.5 の NamePoolData 初期化部分と同じ命令列
+    ///
+    /// lea rax, [rip+0x0123_4560]; jmp +7; lea rcx, [rip+0x0123_4557]; call; mov byte ptr [rip+...], 1
+    const UE5_5_GNAMES_FIXTURE: [u8; 32] = [
+        0xCC, 0xCC, 0xCC, 0xCC, 0xCC, // padding
+        0x48, 0x8D, 0x05, 0x60, 0x45, 0x23, 0x01, // lea rax, [NamePoolData]
+        0xEB, 0x07, // jmp
+        0x48, 0x8D, 0x0D, 0x57, 0x45, 0x23, 0x01, // lea rcx, [NamePoolData]
+        0xE8, 0x10, 0x20, 0x30, 0x00, // call FNamePool::FNamePool
+        0xC6, 0x05, 0x44, 0x33, 0x22, 0x01, // mov byte ptr [bNamePoolInitialized], ...
+    ];
+
+    #[test]
+    fn test_gnames_ue5_5_pattern_resolves_name_pool() {
+        let module_base = 0x7FF6_1000_0000usize;
+        let pattern = Pattern::from_string(UESignatures::GNAMES_UE5_5);
+
+        let matches = pattern.simd_search(&UE5_5_GNAMES_FIXTURE);
+        assert_eq!(matches, vec![5]);
+
+        let address = module_base + matches[0];
+        let (offset_pos, instruction_end) = gnames_operand_layout(UESignatures::GNAMES_UE5_5, pattern.len());
+        assert_eq!((offset_pos, instruction_end), (3, 7));
+
+        let name_pool = rip_relative_target(address, &UE5_5_GNAMES_FIXTURE[matches[0]..], offset_pos, instruction_end);
+        assert_eq!(name_pool, Some(module_base + 5 + 7 + 0x0123_4560));
+
+        // 2 つ目の lea rcx も同じ NamePoolData を指す
+        let second = rip_relative_target(address + 9, &UE5_5_GNAMES_FIXTURE[matches[0] + 9..], 3, 7);
+        assert_eq!(second, name_pool);
+    }
+
+    #[test]
+    fn test_ue_version_from_branch_name() {
+        assert_eq!(UEVersion::from_branch_name("++UE4+Release-4.27"), UEVersion::UE4_27);
+        assert_eq!(UEVersion::from_branch_name("++UE4+Release-4.22"), UEVersion::UE4_22);
+        assert_eq!(UEVersion::from_branch_name("++UE5+Release-5.3"), UEVersion::UE5_3);
+        assert_eq!(UEVersion::from_branch_name("++UE5+Release-5.5-Hotfix"), UEVersion::UE5_5);
+        assert_eq!(UEVersion::from_branch_name("++UE4+Release-4.19"), UEVersion::Unknown);
+        assert_eq!(UEVersion::from_branch_name("++UE5+Main"), UEVersion::Unknown);
+    }
+
+    #[test]
+    fn test_release_branch_pattern_finds_version_string() {
+        let mut data = vec![0xCCu8; 8];
+        data.extend("++UE5+Release-5.1".encode_utf16().flat_map(|c| c.to_le_bytes()));
+        data.extend_from_slice(&[0, 0]);
+
+        let pattern = Pattern::from_string(UESignatures::RELEASE_BRANCH);
+        let matches = pattern.simd_search(&data);
+        assert_eq!(matches, vec![8 + 5 * 2]);
+
+        let branch = decode_utf16_prefix(&data[matches[0]..]);
+        assert_eq!(branch, "+Release-5.1");
+        assert_eq!(UEVersion::from_branch_name(&branch), UEVersion::UE5_1);
+    }
 }
@@ -1,21 +1,116 @@
 /// Method enumeration and invocation
 
-use super::structures::{FField, UObject, UStruct};
+use super::offsets::UEOffsets;
+use super::structures::{
+    FChunkedFixedUObjectArray, FField, FMapProperty, FMulticastDelegate, FName, FProperty, FScriptDelegate, FString, FStructProperty,
+    FText, FUObjectArray, FUObjectItem, TMapElementType, TMapHeader, TMapPair, UFunction, UObject, UStruct,
+};
+use super::implementation::check_traversal_timeout;
 use super::{EngineError, Result, UnrealEngine};
 use crate::engine::types::*;
-use crate::platform::windows::{read_process_memory, write_process_memory};
+use crate::platform::thread::{list_threads, SuspendedThreads};
+use crate::platform::windows::{read_process_memory, write_process_memory, RemoteAlloc, RemoteThread};
 use windows::Win32::Foundation::HANDLE as WinHandle;
-use windows::Win32::System::Threading::{
-    CreateRemoteThread, WaitForSingleObject, INFINITE,
-};
-use windows::Win32::System::Memory::{VirtualAllocEx, VirtualFreeEx, MEM_COMMIT, MEM_RELEASE, MEM_RESERVE, PAGE_EXECUTE_READWRITE};
+use windows::Win32::System::Memory::{PAGE_EXECUTE_READWRITE, PAGE_READWRITE};
+
+/// LWC (UE5.1+) の FVector サイズ (double x3)
+const LWC_VECTOR_SIZE: usize = 24;
+
+/// LWC (UE5.1+) の FQuat サイズ (double x4)
+const LWC_QUAT_SIZE: usize = 32;
+
+/// ゲームスレッド停止中に ProcessEvent の完了を待つ最大時間
+const PROCESS_EVENT_TIMEOUT: std::time::Duration = std::time::Duration::from_secs(5);
+
+/// スレッド再開後にさらに完了を待つ時間（ゲームスレッドのロック待ちなど）
+const PROCESS_EVENT_GRACE: std::time::Duration = std::time::Duration::from_secs(5);
+
+/// UStruct のレイアウト判定に使うクラス（どのゲームにもあり、Children が空でないもの）
+const USTRUCT_SAMPLE_CLASSES: [&str; 6] = ["Actor", "Pawn", "Controller", "PlayerController", "World", "GameInstance"];
+
+/// レイアウトをキャッシュするのに必要な、判定が一致したサンプル数
+const USTRUCT_MIN_AGREEING_SAMPLES: usize = 3;
 
 impl UnrealEngine {
+    /// UStruct を読み取る（SuperStruct オフセットはバージョンごとにキャッシュ）
+    pub(super) fn read_ustruct(&self, handle: WinHandle, address: usize) -> anyhow::Result<UStruct> {
+        let cached = self.ustruct_offsets.lock().unwrap_or_else(|e| e.into_inner()).get(&self.version).copied();
+        let agreed = match cached {
+            Some(agreed) => agreed,
+            None => match self.sample_ustruct_base_offset(handle, address) {
+                Ok(agreed) => {
+                    match agreed {
+                        Some(offset) => tracing::info!(
+                            "UStruct layout: SuperStruct at +{} (FStructBaseChain {})",
+                            offset,
+                            if offset == UStruct::BASE_OFFSET_WITH_CHAIN { "present" } else { "absent" }
+                        ),
+                        None => tracing::warn!("UStruct layout samples disagree, detecting per struct"),
+                    }
+                    self.ustruct_offsets
+                        .lock()
+                        .unwrap_or_else(|e| e.into_inner())
+                        .insert(self.version, agreed);
+                    agreed
+                }
+                // GObjects が読めない間はキャッシュせず、この UStruct だけで判定する
+                Err(_) => None,
+            },
+        };
+
+        let offset = match agreed {
+            Some(offset) => offset,
+            None => UStruct::detect_base_offset(handle, address)
+                .ok_or_else(|| anyhow::anyhow!("Failed to detect UStruct layout at 0x{:X}", address))?,
+        };
+        UStruct::read(handle, address, offset)
+    }
+
+    /// `address` と既知のクラスで FStructBaseChain の有無を判定し、一致した SuperStruct オフセットを返す
+    ///
+    /// 判定できたサンプルが USTRUCT_MIN_AGREEING_SAMPLES 未満か、判定が食い違う場合は None
+    fn sample_ustruct_base_offset(&self, handle: WinHandle, address: usize) -> Result<Option<usize>> {
+        let mut samples = vec![address];
+        for obj_addr in self.get_all_objects_impl()? {
+            if samples.len() > USTRUCT_SAMPLE_CLASSES.len() {
+                break;
+            }
+            if samples.contains(&obj_addr) {
+                continue;
+            }
+            let Ok(name) = self.get_object_name_impl(obj_addr) else {
+                continue;
+            };
+            if !USTRUCT_SAMPLE_CLASSES.contains(&name.as_str()) {
+                continue;
+            }
+
+            // UClass かどうかを確認（Class->Class == Class なら UClass）
+            let is_class = UObject::read(handle, obj_addr)
+                .and_then(|obj| Ok((obj.class, UObject::read(handle, obj.class)?.class)))
+                .is_ok_and(|(class, metaclass)| class != 0 && class == metaclass);
+            if is_class {
+                samples.push(obj_addr);
+            }
+        }
+
+        let votes: Vec<usize> = samples
+            .iter()
+            .filter_map(|&sample| UStruct::detect_base_offset(handle, sample))
+            .collect();
+        let Some(&first) = votes.first() else {
+            return Ok(None);
+        };
+        let agreed = votes.len() >= USTRUCT_MIN_AGREEING_SAMPLES && votes.iter().all(|&vote| vote == first);
+        tracing::info!("UStruct layout samples: {:?}", votes);
+        Ok(agreed.then_some(first))
+    }
+
     /// UClass から情報を取得
     pub(super) fn get_class_info_impl(&self, class_addr: usize) -> Result<ClassInfo> {
         let name = self.get_object_name_impl(class_addr)?;
         let handle = unsafe { std::mem::transmute::<usize, WinHandle>(self.process_handle) };
-        let ustruct = UStruct::read(handle, class_addr)
+        let ustruct = self.read_ustruct(handle, class_addr)
             .map_err(|e| EngineError::InitializationFailed(format!("UStruct read failed: {}", e)))?;
 
         Ok(ClassInfo {
@@ -112,7 +207,7 @@ impl UnrealEngine {
     pub(super) fn find_method_impl(&self, class_addr: usize, method_name: &str) -> Result<usize> {
         let handle = unsafe { std::mem::transmute::<usize, WinHandle>(self.process_handle) };
 
-        let ustruct = UStruct::read(handle, class_addr)?;
+        let ustruct = self.read_ustruct(handle, class_addr)?;
         let mut current_field = ustruct.children;
 
         // Children リンクリストを辿る
@@ -138,18 +233,194 @@ impl UnrealEngine {
     /// UFunction から情報を取得
     pub(super) fn get_method_info_impl(&self, method_addr: usize) -> Result<MethodInfo> {
         let name = self.get_object_name_impl(method_addr)?;
+        let handle = unsafe { std::mem::transmute::<usize, WinHandle>(self.process_handle) };
 
-        // TODO: パラメータ情報を読み取る
+        let (params, return_type) = self.read_function_params(handle, method_addr);
+        let flags = self.read_function_flags(handle, method_addr).unwrap_or(0);
 
         Ok(MethodInfo {
             name,
             handle: MethodHandle(method_addr),
-            params: Vec::new(),
-            return_type: None,
-            is_static: false,
+            params,
+            return_type,
+            is_static: flags & UFunction::FUNC_STATIC != 0,
+            flags_summary: UFunction::flags_summary(flags),
+        })
+    }
+
+    /// UFunction の ChildProperties (FProperty チェーン) からパラメータと戻り値を読み取る
+    ///
+    /// - CPF_Parm (0x80) が立っているものがパラメータ
+    /// - さらに CPF_ReturnParm (0x400) が立っているものが戻り値
+    fn read_function_params(
+        &self,
+        handle: WinHandle,
+        function_addr: usize,
+    ) -> (Vec<ParamInfo>, Option<TypeInfo>) {
+        let mut params = Vec::new();
+        let mut return_type = None;
+
+        let Ok(ustruct) = self.read_ustruct(handle, function_addr) else {
+            return (params, return_type);
+        };
+
+        let mut current_field = ustruct.child_properties;
+        let mut count = 0;
+        while current_field != 0 && count < 64 {
+            count += 1;
+
+            if !self.is_valid_pointer(current_field) {
+                break;
+            }
+
+            let Ok(field) = FField::read(handle, current_field) else {
+                break;
+            };
+
+            if let Ok(property) = FProperty::read(handle, current_field) {
+                if property.is_param() {
+                    let type_name = self
+                        .read_ffield_class_name(handle, field.class_private)
+                        .unwrap_or_else(|_| "unknown".to_string());
+                    let type_info = self.resolve_property_type(
+                        handle,
+                        current_field,
+                        &type_name,
+                        property.element_size.max(0) as usize,
+                    );
+
+                    if property.is_return_param() {
+                        return_type = Some(type_info);
+                    } else {
+                        let name = self
+                            .get_fname_impl(field.name.comparison_index)
+                            .unwrap_or_else(|_| format!("param_{}", params.len()));
+                        params.push(ParamInfo { name, type_info });
+                    }
+                }
+            }
+
+            current_field = field.next;
+        }
+
+        (params, return_type)
+    }
+
+    /// FFieldClass::Name のオフセット
+    const FFIELD_CLASS_NAME_OFFSET: usize = 0x08;
+
+    /// FField::ClassPrivate (FFieldClass*) からプロパティ型名 ("FloatProperty" など) を取得
+    ///
+    /// Name は通常 +0x08 にあるが、ビルドによっては先頭にあるので
+    /// "Property" で終わる名前が得られなければ +0x00 も試す
+    pub(super) fn read_ffield_class_name(&self, handle: WinHandle, class_private_addr: usize) -> Result<String> {
+        if !self.is_valid_pointer(class_private_addr) {
+            return Err(EngineError::MemoryError(format!(
+                "Invalid FFieldClass pointer 0x{:X}",
+                class_private_addr
+            )));
+        }
+
+        let mut fallback = None;
+        for offset in [Self::FFIELD_CLASS_NAME_OFFSET, 0] {
+            let Ok(name) = FName::read(handle, class_private_addr + offset) else {
+                continue;
+            };
+            match self.get_fname_impl(name.comparison_index) {
+                Ok(type_name) if type_name.ends_with("Property") => return Ok(type_name),
+                Ok(type_name) if !type_name.is_empty() && fallback.is_none() => fallback = Some(type_name),
+                _ => {}
+            }
+        }
+
+        fallback.ok_or_else(|| {
+            EngineError::MemoryError(format!("Failed to read FFieldClass name at 0x{:X}", class_private_addr))
+        })
+    }
+
+    /// プロパティの TypeInfo を構築（StructProperty は UScriptStruct 名で FVector などにする）
+    fn resolve_property_type(
+        &self,
+        handle: WinHandle,
+        property_addr: usize,
+        type_name: &str,
+        element_size: usize,
+    ) -> TypeInfo {
+        if type_name == "StructProperty" {
+            if let Some(type_info) = self.struct_property_type(handle, property_addr, element_size) {
+                return type_info;
+            }
+        }
+        Self::property_type_info(type_name, element_size)
+    }
+
+    /// StructProperty の Struct (UScriptStruct*) から型情報を作る ("Vector" → "FVector")
+    ///
+    /// Struct の位置は候補のうち、クラスが ScriptStruct の UObject を指すものを採用する
+    fn struct_property_type(&self, handle: WinHandle, property_addr: usize, element_size: usize) -> Option<TypeInfo> {
+        FStructProperty::STRUCT_CANDIDATES.iter().find_map(|&offset| {
+            let data = read_process_memory(handle, property_addr + offset, 8).ok()?;
+            let script_struct = usize::from_le_bytes(data[..8].try_into().unwrap());
+            if !self.is_valid_pointer(script_struct) {
+                return None;
+            }
+            let object = UObject::read(handle, script_struct).ok()?;
+            let class = UObject::read(handle, object.class).ok()?;
+            if self.get_fname_impl(class.name.comparison_index).ok()? != "ScriptStruct" {
+                return None;
+            }
+            let name = self.get_fname_impl(object.name.comparison_index).ok()?;
+            Some(TypeInfo {
+                name: format!("F{}", name),
+                size: element_size,
+                kind: TypeKind::Struct(ClassHandle(script_struct)),
+            })
         })
     }
 
+    /// FProperty の型名 ("IntProperty" など) から TypeInfo を構築
+    fn property_type_info(type_name: &str, element_size: usize) -> TypeInfo {
+        let primitive = match type_name {
+            "BoolProperty" => Some(PrimitiveType::Bool),
+            "Int8Property" => Some(PrimitiveType::I8),
+            "Int16Property" => Some(PrimitiveType::I16),
+            "IntProperty" => Some(PrimitiveType::I32),
+            "Int64Property" => Some(PrimitiveType::I64),
+            "ByteProperty" => Some(PrimitiveType::U8),
+            "UInt16Property" => Some(PrimitiveType::U16),
+            "UInt32Property" => Some(PrimitiveType::U32),
+            "UInt64Property" => Some(PrimitiveType::U64),
+            "FloatProperty" => Some(PrimitiveType::F32),
+            "DoubleProperty" => Some(PrimitiveType::F64),
+            _ => None,
+        };
+
+        let kind = match primitive {
+            Some(prim) => TypeKind::Primitive(prim),
+            None => match type_name {
+                // UObject* (TObjectPtr) はポインタとして扱う
+                "ObjectProperty" | "ClassProperty" => TypeKind::Pointer(Box::new(TypeInfo {
+                    name: "UObject".into(),
+                    size: 0,
+                    kind: TypeKind::Unknown,
+                })),
+                _ => TypeKind::Unknown,
+            },
+        };
+
+        let size = match &kind {
+            TypeKind::Primitive(prim) => prim.size(),
+            TypeKind::Pointer(_) => 8,
+            _ => element_size,
+        };
+
+        TypeInfo {
+            name: type_name.to_string(),
+            size,
+            kind,
+        }
+    }
+
     /// UClass のすべてのメソッドを列挙
     /// UE5.5: Children は TObjectPtr<UField> で、UFunction (UObject派生) のリンクリスト
     pub(super) fn enumerate_methods_impl(&self, class_addr: usize) -> Result<Vec<MethodInfo>> {
@@ -169,7 +440,7 @@ impl UnrealEngine {
             }
         }
 
-        let ustruct = UStruct::read(handle, class_addr)?;
+        let ustruct = self.read_ustruct(handle, class_addr)?;
         let mut current_field = ustruct.children;
         let mut methods = Vec::new();
 
@@ -200,6 +471,36 @@ impl UnrealEngine {
         Ok(methods)
     }
 
+    /// UFunction::FunctionFlags を読み取る
+    pub(super) fn read_function_flags(&self, handle: WinHandle, function_addr: usize) -> Result<u32> {
+        let offsets = UEOffsets::for_version(self.version);
+        let data = read_process_memory(handle, function_addr + offsets.ufunction_flags, 4)?;
+        Ok(u32::from_le_bytes(data[..4].try_into().unwrap()))
+    }
+
+    /// 指定した FunctionFlags のいずれかを持つメソッドのみを列挙
+    pub(super) fn enumerate_methods_with_flags_impl(
+        &self,
+        class_addr: usize,
+        flags: u32,
+    ) -> Result<Vec<MethodInfo>> {
+        let handle = unsafe { std::mem::transmute::<usize, WinHandle>(self.process_handle) };
+
+        let methods: Vec<MethodInfo> = self
+            .enumerate_methods_impl(class_addr)?
+            .into_iter()
+            .filter(|m| {
+                self.read_function_flags(handle, m.handle.0)
+                    .map(|f| (f & flags) != 0)
+                    .unwrap_or(false)
+            })
+            .collect();
+
+        tracing::info!("enumerate_methods_with_flags_impl: {} methods with flags 0x{:08X}",
+            methods.len(), flags);
+        Ok(methods)
+    }
+
     /// ProcessEvent を呼び出してメソッドを実行
     pub(super) fn invoke_method_impl(
         &self,
@@ -208,93 +509,186 @@ impl UnrealEngine {
         _args: &[Value],
     ) -> Result<Value> {
         let handle = unsafe { std::mem::transmute::<usize, WinHandle>(self.process_handle) };
+        let offsets = UEOffsets::for_version(self.version);
 
-        // パラメータ構造体を確保
-        let params_size = 0x100; // 仮のサイズ
-        let params_addr = unsafe {
-            VirtualAllocEx(
-                handle,
-                None,
-                params_size,
-                MEM_COMMIT | MEM_RESERVE,
-                PAGE_EXECUTE_READWRITE,
-            )
+        // ParmsSize / ReturnValueOffset を読み取る
+        let data = read_process_memory(handle, method_addr + offsets.ufunction_params_size, 2)?;
+        let params_size = u16::from_le_bytes([data[0], data[1]]) as usize;
+        let data = read_process_memory(handle, method_addr + offsets.ufunction_return_value_offset, 2)?;
+        let return_value_offset = u16::from_le_bytes([data[0], data[1]]) as usize;
+
+        // TODO: args を params に書き込む
+        let params = vec![0u8; params_size];
+        let result = self.call_process_event_impl(instance_addr, method_addr, &params, Vec::new())?;
+
+        // 戻り値なしの場合は ReturnValueOffset が 0xFFFF になる
+        if return_value_offset == 0xFFFF || return_value_offset >= result.len() {
+            return Ok(Value::Null);
+        }
+
+        let (_, return_type) = self.read_function_params(handle, method_addr);
+        let Some(return_type) = return_type else {
+            return Ok(Value::Null);
         };
 
-        if params_addr.is_null() {
-            return Err(EngineError::InvocationFailed(
-                "Failed to allocate memory".into(),
-            ));
+        let end = (return_value_offset + return_type.size.max(1)).min(result.len());
+        Ok(Self::decode_value(&result[return_value_offset..end], &return_type))
+    }
+
+    /// バイト列を型情報に従って Value に変換する
+    ///
+    /// 未知の型やサイズ不足の場合は生バイトのまま Value::Struct で返す
+    fn decode_value(data: &[u8], type_info: &TypeInfo) -> Value {
+        match &type_info.kind {
+            TypeKind::Primitive(prim) if data.len() >= prim.size() => match prim {
+                PrimitiveType::Bool => Value::Bool(data[0] != 0),
+                PrimitiveType::I8 => Value::I8(data[0] as i8),
+                PrimitiveType::U8 => Value::U8(data[0]),
+                PrimitiveType::I16 => Value::I16(i16::from_le_bytes([data[0], data[1]])),
+                PrimitiveType::U16 => Value::U16(u16::from_le_bytes([data[0], data[1]])),
+                PrimitiveType::I32 => Value::I32(i32::from_le_bytes(data[..4].try_into().unwrap())),
+                PrimitiveType::U32 => Value::U32(u32::from_le_bytes(data[..4].try_into().unwrap())),
+                PrimitiveType::I64 => Value::I64(i64::from_le_bytes(data[..8].try_into().unwrap())),
+                PrimitiveType::U64 => Value::U64(u64::from_le_bytes(data[..8].try_into().unwrap())),
+                PrimitiveType::F32 => Value::F32(f32::from_le_bytes(data[..4].try_into().unwrap())),
+                PrimitiveType::F64 => Value::F64(f64::from_le_bytes(data[..8].try_into().unwrap())),
+            },
+            TypeKind::Pointer(_) if data.len() >= 8 => {
+                let ptr = usize::from_le_bytes(data[..8].try_into().unwrap());
+                if ptr == 0 {
+                    Value::Null
+                } else {
+                    Value::Object(InstanceHandle(ptr))
+                }
+            }
+            _ => Value::Struct(data.to_vec()),
         }
+    }
 
-        // TODO: args を params に書き込む
+    /// パラメータ構造体を書き込んで ProcessEvent を呼び出す
+    ///
+    /// 呼び出し完了後のパラメータ構造体（out パラメータ・戻り値を含む）を読み戻して返す。
+    /// `extra_allocs` はパラメータから参照されるリモートバッファ（FString の中身など）で、
+    /// シェルコード・パラメータと同じく呼び出し完了まで保持される
+    pub(super) fn call_process_event_impl(
+        &self,
+        instance_addr: usize,
+        method_addr: usize,
+        params: &[u8],
+        extra_allocs: Vec<RemoteAlloc>,
+    ) -> Result<Vec<u8>> {
+        let handle = unsafe { std::mem::transmute::<usize, WinHandle>(self.process_handle) };
+
+        // パラメータ構造体を確保（ガードの Drop で解放される）
+        let params_size = params.len().max(0x100); // 最低でも仮のサイズを確保
+        let params_alloc = RemoteAlloc::new(handle, params_size, PAGE_READWRITE)
+            .map_err(|e| EngineError::InvocationFailed(format!("Failed to allocate params: {}", e)))?;
+
+        if !params.is_empty() {
+            write_process_memory(handle, params_alloc.address(), params)?;
+        }
 
         // シェルコードを生成して ProcessEvent を呼び出す
         // ProcessEvent(UObject* Context, UFunction* Function, void* Params)
         let shellcode = self.generate_process_event_shellcode(
             instance_addr,
             method_addr,
-            params_addr as usize,
+            params_alloc.address(),
         )?;
 
-        let shellcode_addr = unsafe {
-            VirtualAllocEx(
-                handle,
-                None,
-                shellcode.len(),
-                MEM_COMMIT | MEM_RESERVE,
-                PAGE_EXECUTE_READWRITE,
-            )
+        let shellcode_alloc = RemoteAlloc::with_data(handle, &shellcode, PAGE_EXECUTE_READWRITE)
+            .map_err(|e| EngineError::InvocationFailed(format!("Failed to allocate shellcode: {}", e)))?;
+
+        // ゲームスレッドとの競合を避けるため、呼び出し中は既存スレッドを停止する
+        // （リモートスレッド作成前に列挙するので、リモートスレッド自身は含まれない）
+        let game_threads: Vec<u32> = list_threads(self.process_id)
+            .map(|threads| threads.iter().map(|t| t.tid).collect())
+            .unwrap_or_default();
+
+        // リモートスレッドを作成して実行
+        let thread = unsafe { RemoteThread::spawn(handle, shellcode_alloc.address()) }
+            .map_err(|e| EngineError::InvocationFailed(e.to_string()))?;
+
+        // 呼び出した UFunction がゲームスレッドを待つとデッドロックするため、
+        // 待ち時間に上限を設け、超えたらスレッドを再開してから判断する
+        let finished = {
+            let _suspended = SuspendedThreads::new(&game_threads);
+            thread.wait_timeout(PROCESS_EVENT_TIMEOUT)
         };
+        if !finished && !thread.wait_timeout(PROCESS_EVENT_GRACE) {
+            // リモートスレッドがまだシェルコードとパラメータを使っているので、解放せずに残す
+            tracing::warn!(
+                "ProcessEvent call did not finish within {:?}; leaking {} bytes in the target",
+                PROCESS_EVENT_TIMEOUT + PROCESS_EVENT_GRACE,
+                shellcode_alloc.size()
+                    + params_alloc.size()
+                    + extra_allocs.iter().map(RemoteAlloc::size).sum::<usize>()
+            );
+            std::mem::forget(shellcode_alloc);
+            std::mem::forget(params_alloc);
+            extra_allocs.into_iter().for_each(std::mem::forget);
+            return Err(EngineError::Timeout(PROCESS_EVENT_TIMEOUT + PROCESS_EVENT_GRACE));
+        }
 
-        if shellcode_addr.is_null() {
-            unsafe {
-                VirtualFreeEx(handle, params_addr, 0, MEM_RELEASE);
-            }
-            return Err(EngineError::InvocationFailed(
-                "Failed to allocate shellcode".into(),
-            ));
+        // 呼び出し後のパラメータ構造体を読み戻す
+        Ok(read_process_memory(handle, params_alloc.address(), params_size)?)
+    }
+
+    /// UKismetSystemLibrary::ExecuteConsoleCommand でコンソールコマンドを実行
+    ///
+    /// パラメータ構造体のレイアウト:
+    /// - WorldContextObject (UObject*, 8 bytes) at 0
+    /// - Command (FString, 16 bytes) at 8
+    /// - SpecificPlayer (APlayerController*, 8 bytes) at 24
+    ///
+    /// 出力用の FString パラメータを持つ場合 (ParmsSize > 32) のみ、その内容を返す
+    pub(super) fn execute_console_command_impl(&self, command: &str) -> Result<Option<String>> {
+        if !self.initialized {
+            return Err(EngineError::NotInitialized);
         }
 
-        // シェルコードを書き込み
-        write_process_memory(handle, shellcode_addr as usize, &shellcode)?;
+        let handle = unsafe { std::mem::transmute::<usize, WinHandle>(self.process_handle) };
 
-        // リモートスレッドを作成して実行
-        let thread = unsafe {
-            CreateRemoteThread(
-                handle,
-                None,
-                0,
-                Some(std::mem::transmute(shellcode_addr)),
-                None,
-                0,
-                None,
-            )
+        let engine_addr = self.find_game_engine_impl()?;
+        let kismet_class = self.find_class_by_name_impl("KismetSystemLibrary")?;
+        let function_addr = self.find_method_impl(kismet_class, "ExecuteConsoleCommand")?;
+
+        tracing::info!("ExecuteConsoleCommand: engine=0x{:X}, function=0x{:X}, command='{}'",
+            engine_addr, function_addr, command);
+
+        // コマンド文字列 (UTF-16LE, null 終端付き) をリモートに書き込む
+        let mut wide: Vec<u16> = command.encode_utf16().collect();
+        wide.push(0);
+        let string_bytes: Vec<u8> = wide.iter().flat_map(|c| c.to_le_bytes()).collect();
+
+        let string_alloc = RemoteAlloc::with_data(handle, &string_bytes, PAGE_READWRITE)
+            .map_err(|e| EngineError::InvocationFailed(format!("Failed to allocate command string: {}", e)))?;
+
+        let command_fstring = FString {
+            data: string_alloc.address(),
+            num: wide.len() as i32,
+            max: wide.len() as i32,
         };
 
-        if let Ok(thread_handle) = thread {
-            unsafe {
-                WaitForSingleObject(thread_handle, INFINITE);
-            }
+        let mut params = Vec::with_capacity(32);
+        params.extend_from_slice(&engine_addr.to_le_bytes());
+        params.extend_from_slice(&command_fstring.to_bytes());
+        params.extend_from_slice(&0usize.to_le_bytes());
 
-            // TODO: 戻り値を読み取る
+        // タイムアウト時はパラメータと一緒に文字列バッファもリークさせる
+        let out_params = self.call_process_event_impl(engine_addr, function_addr, &params, vec![string_alloc])?;
 
-            // クリーンアップ
-            unsafe {
-                VirtualFreeEx(handle, params_addr, 0, MEM_RELEASE);
-                VirtualFreeEx(handle, shellcode_addr, 0, MEM_RELEASE);
-            }
+        // 出力パラメータ (FString) があれば読み取る
+        let parms_size = self.read_ustruct(handle, function_addr)
+            .map(|s| s.properties_size as usize)
+            .unwrap_or(0);
 
-            Ok(Value::Null)
-        } else {
-            unsafe {
-                VirtualFreeEx(handle, params_addr, 0, MEM_RELEASE);
-                VirtualFreeEx(handle, shellcode_addr, 0, MEM_RELEASE);
-            }
-            Err(EngineError::InvocationFailed(
-                "Failed to create remote thread".into(),
-            ))
+        if parms_size >= 32 + FString::SIZE && out_params.len() >= 32 + FString::SIZE {
+            let output = FString::from_bytes(&out_params[32..32 + FString::SIZE]);
+            return Ok(output.read_string(handle).ok());
         }
+
+        Ok(None)
     }
 
     /// ProcessEvent 呼び出し用のシェルコードを生成
@@ -351,6 +745,36 @@ impl UnrealEngine {
 
         let addr = instance_addr + field_offset;
 
+        // FVector / FQuat / FColor (StructProperty の UScriptStruct 名から決まる)
+        if let Some(size) = Self::math_struct_size(field_type, self.version.is_lw_coordinates()) {
+            let data = read_process_memory(handle, addr, size)?;
+            if let Some(value) = Self::decode_math_struct(&field_type.name, &data) {
+                return Ok(value);
+            }
+        }
+
+        // FText は表示文字列だけを読む（書き込みは未対応）
+        if field_type.name == "TextProperty" {
+            return Ok(Value::String(FText::read(handle, addr)?));
+        }
+
+        // デリゲートは (バインド先, 関数名) の組の配列として返す
+        let delegates = match field_type.name.as_str() {
+            "DelegateProperty" => Some(self.resolve_delegates(handle, vec![FScriptDelegate::read(handle, addr)?])),
+            "MulticastDelegateProperty" | "MulticastInlineDelegateProperty" => {
+                Some(self.read_delegate_impl(instance_addr, field_offset)?)
+            }
+            _ => None,
+        };
+        if let Some(delegates) = delegates {
+            return Ok(Value::Array(
+                delegates
+                    .into_iter()
+                    .map(|(object, function)| Value::Array(vec![Value::Object(object), Value::String(function)]))
+                    .collect(),
+            ));
+        }
+
         match &field_type.kind {
             TypeKind::Primitive(prim) => {
                 let data = read_process_memory(handle, addr, prim.size())?;
@@ -371,6 +795,23 @@ impl UnrealEngine {
                     _ => Ok(Value::Struct(data)),
                 }
             }
+            // TMap は (キー, 値) の組の配列として返す
+            TypeKind::Map(key_type, value_type) => {
+                let key = self.tmap_element_type(handle, key_type);
+                let value = self.tmap_element_type(handle, value_type);
+                let pairs = self.read_tmap_pairs(instance_addr, field_offset, key, value)?;
+                Ok(Value::Array(
+                    pairs
+                        .iter()
+                        .map(|(key, value)| {
+                            Value::Array(vec![
+                                Self::decode_value(key, key_type),
+                                Self::decode_value(value, value_type),
+                            ])
+                        })
+                        .collect(),
+                ))
+            }
             _ => {
                 let data = read_process_memory(handle, addr, field_type.size)?;
                 Ok(Value::Struct(data))
@@ -378,6 +819,168 @@ impl UnrealEngine {
         }
     }
 
+    /// TMap の生の (キー, 値) を読み取る
+    pub(super) fn read_tmap_pairs_impl(
+        &self,
+        instance_addr: usize,
+        offset: usize,
+        key: TMapElementType,
+        value: TMapElementType,
+    ) -> Result<Vec<TMapPair>> {
+        let handle = unsafe { std::mem::transmute::<usize, WinHandle>(self.process_handle) };
+
+        let header = TMapHeader::read(handle, instance_addr + offset)?;
+        Ok(header.iter_pairs(handle, key, value)?)
+    }
+
+    /// TMap のキー / 値の型からサイズとアラインメントを決める
+    ///
+    /// 構造体は UScriptStruct::MinAlignment、それ以外はプロパティの型で決め、
+    /// どちらも分からなければサイズから推定する
+    fn tmap_element_type(&self, handle: WinHandle, type_info: &TypeInfo) -> TMapElementType {
+        let align = match &type_info.kind {
+            TypeKind::Primitive(prim) => prim.size(),
+            TypeKind::Pointer(_) => 8,
+            TypeKind::Struct(ClassHandle(script_struct)) => self
+                .read_ustruct(handle, *script_struct)
+                .ok()
+                .and_then(|ustruct| usize::try_from(ustruct.min_alignment).ok())
+                .filter(|align| align.is_power_of_two() && *align <= 16)
+                .unwrap_or_else(|| Self::alignment_from_size(type_info.size)),
+            _ => match type_info.name.as_str() {
+                // FName と FWeakObjectPtr は int32 x2
+                "NameProperty" | "WeakObjectProperty" | "LazyObjectProperty" => 4,
+                "StrProperty" | "TextProperty" | "SoftObjectProperty" | "SoftClassProperty"
+                | "InterfaceProperty" | "ArrayProperty" | "MapProperty" | "SetProperty" => 8,
+                _ => Self::alignment_from_size(type_info.size),
+            },
+        };
+        TMapElementType::new(type_info.size, align)
+    }
+
+    /// 型が分からないときのアラインメント推定 (8/4/2/1 バイト)
+    fn alignment_from_size(size: usize) -> usize {
+        match size {
+            s if s.is_multiple_of(8) => 8,
+            s if s.is_multiple_of(4) => 4,
+            s if s.is_multiple_of(2) => 2,
+            _ => 1,
+        }
+    }
+
+    /// マルチキャストデリゲートのバインド先と関数名を読み取る
+    pub(super) fn read_delegate_impl(
+        &self,
+        instance_addr: usize,
+        offset: usize,
+    ) -> Result<Vec<(InstanceHandle, String)>> {
+        let handle = unsafe { std::mem::transmute::<usize, WinHandle>(self.process_handle) };
+
+        let delegate = FMulticastDelegate::read(handle, instance_addr + offset)?;
+        Ok(self.resolve_delegates(handle, delegate.delegates(handle)?))
+    }
+
+    /// FScriptDelegate の弱参照と関数名を解決する（解決できないものは除く）
+    fn resolve_delegates(&self, handle: WinHandle, delegates: Vec<FScriptDelegate>) -> Vec<(InstanceHandle, String)> {
+        delegates
+            .into_iter()
+            .filter(FScriptDelegate::is_bound)
+            .filter_map(|delegate| {
+                let object = self.object_from_index(handle, delegate.object_index)?;
+                let function = self.get_fname_impl(delegate.function_name.comparison_index).ok()?;
+                Some((InstanceHandle(object), function))
+            })
+            .collect()
+    }
+
+    /// GObjects のインデックスから UObject のアドレスを取得（FWeakObjectPtr の解決用）
+    ///
+    /// gobjects が FUObjectArray を指す場合とチャンク配列を直接指す場合の両方を試す
+    pub(super) fn object_from_index(&self, handle: WinHandle, index: i32) -> Option<usize> {
+        if index < 0 {
+            return None;
+        }
+
+        if let Ok(object) = FUObjectArray::read(handle, self.gobjects)
+            .and_then(|array| array.get_object_address(handle, index))
+        {
+            if self.is_valid_pointer(object) {
+                return Some(object);
+            }
+        }
+
+        let index = index as usize;
+        let chunk_index = index / FChunkedFixedUObjectArray::NUM_ELEMENTS_PER_CHUNK;
+        let within_chunk = index % FChunkedFixedUObjectArray::NUM_ELEMENTS_PER_CHUNK;
+
+        let data = read_process_memory(handle, self.gobjects + chunk_index * 8, 8).ok()?;
+        let chunk = usize::from_le_bytes(data[..8].try_into().unwrap());
+        if !self.is_valid_pointer(chunk) {
+            return None;
+        }
+
+        let item = FUObjectItem::read(handle, chunk + within_chunk * FUObjectItem::SIZE_UE5).ok()?;
+        (item.is_valid() && self.is_valid_pointer(item.object)).then_some(item.object)
+    }
+
+    /// FMapProperty の KeyProp / ValueProp から型情報を取得
+    ///
+    /// 両方のポインタが "～Property" クラスの FField を指す位置を KeyProp とみなす
+    fn map_property_types(&self, handle: WinHandle, field_addr: usize) -> Option<(TypeInfo, TypeInfo)> {
+        let prop_type = |prop_addr: usize| -> Option<TypeInfo> {
+            let field = FField::read(handle, prop_addr).ok()?;
+            let type_name = self.read_ffield_class_name(handle, field.class_private).ok()?;
+            if !type_name.ends_with("Property") {
+                return None;
+            }
+            let element_size = FProperty::read(handle, prop_addr).ok()?.element_size.max(0) as usize;
+            Some(self.resolve_property_type(handle, prop_addr, &type_name, element_size))
+        };
+
+        FMapProperty::KEY_PROP_CANDIDATES.iter().find_map(|&key_prop_offset| {
+            let data = read_process_memory(handle, field_addr + key_prop_offset, 16).ok()?;
+            let key_prop = usize::from_le_bytes(data[0..8].try_into().unwrap());
+            let value_prop = usize::from_le_bytes(data[8..16].try_into().unwrap());
+            if !self.is_valid_pointer(key_prop) || !self.is_valid_pointer(value_prop) {
+                return None;
+            }
+            Some((prop_type(key_prop)?, prop_type(value_prop)?))
+        })
+    }
+
+    /// FVector / FQuat / FColor の読み取りサイズ
+    ///
+    /// UE5.1+ (LWC) の FVector は double x3 (24 バイト)、UE4 / UE5.0 は float x3。
+    /// ElementSize が分かればそれに従い、不明 (0) ならバージョンで判断する
+    fn math_struct_size(type_info: &TypeInfo, lwc: bool) -> Option<usize> {
+        let (count, double_size) = match type_info.name.as_str() {
+            "FVector" => (3, LWC_VECTOR_SIZE),
+            "FQuat" => (4, LWC_QUAT_SIZE),
+            "FColor" => return Some(4),
+            _ => return None,
+        };
+        let double = match type_info.size {
+            0 => lwc,
+            size => size == double_size,
+        };
+        Some(count * if double { 8 } else { 4 })
+    }
+
+    /// math_struct_size で読んだバイト列を Value に変換する
+    fn decode_math_struct(type_name: &str, data: &[u8]) -> Option<Value> {
+        let f32_at = |i: usize| f32::from_le_bytes(data[i * 4..i * 4 + 4].try_into().unwrap());
+        let f64_at = |i: usize| f64::from_le_bytes(data[i * 8..i * 8 + 8].try_into().unwrap());
+        match (type_name, data.len()) {
+            ("FVector", 12) => Some(Value::Vector3(f32_at(0), f32_at(1), f32_at(2))),
+            ("FVector", LWC_VECTOR_SIZE) => Some(Value::Vector3D(f64_at(0), f64_at(1), f64_at(2))),
+            ("FQuat", 16) => Some(Value::Quaternion(f32_at(0), f32_at(1), f32_at(2), f32_at(3))),
+            ("FQuat", LWC_QUAT_SIZE) => Some(Value::QuaternionD(f64_at(0), f64_at(1), f64_at(2), f64_at(3))),
+            // FColor はメモリ上 B, G, R, A の順
+            ("FColor", 4) => Some(Value::Color(data[2], data[1], data[0], data[3])),
+            _ => None,
+        }
+    }
+
     /// フィールドを書き込む
     pub(super) fn write_field_impl(
         &self,
@@ -396,6 +999,12 @@ impl UnrealEngine {
             Value::F32(v) => v.to_le_bytes().to_vec(),
             Value::F64(v) => v.to_le_bytes().to_vec(),
             Value::Struct(v) => v.clone(),
+            // LWC の FVector として double x3 で書き込む
+            Value::Vector3D(x, y, z) => [x, y, z].iter().flat_map(|c| c.to_le_bytes()).collect(),
+            Value::Vector3(x, y, z) => [x, y, z].iter().flat_map(|c| c.to_le_bytes()).collect(),
+            Value::Quaternion(x, y, z, w) => [x, y, z, w].iter().flat_map(|c| c.to_le_bytes()).collect(),
+            Value::QuaternionD(x, y, z, w) => [x, y, z, w].iter().flat_map(|c| c.to_le_bytes()).collect(),
+            Value::Color(r, g, b, a) => vec![*b, *g, *r, *a],
             _ => {
                 return Err(EngineError::TypeMismatch {
                     expected: "primitive or struct".into(),
@@ -413,11 +1022,21 @@ impl UnrealEngine {
     // =========================================================================
 
     /// UClass から FProperty を検索
-    /// UE5 では ChildProperties (FField*) を使用
+    /// 4.25 以降は ChildProperties (FField*)、それ未満は Children (UField*) を使用
     pub(super) fn find_field_impl(&self, class_addr: usize, field_name: &str) -> Result<usize> {
         let handle = unsafe { std::mem::transmute::<usize, WinHandle>(self.process_handle) };
 
-        let ustruct = UStruct::read(handle, class_addr)?;
+        let ustruct = self.read_ustruct(handle, class_addr)?;
+
+        if !self.version.has_child_properties() {
+            return self
+                .enumerate_uproperties(handle, ustruct.children)
+                .into_iter()
+                .find(|field| field.name == field_name)
+                .map(|field| field.handle.0)
+                .ok_or_else(|| EngineError::FieldNotFound(field_name.to_string()));
+        }
+
         let mut current_field = ustruct.child_properties;
 
         // FField リンクリストを辿る
@@ -441,52 +1060,157 @@ impl UnrealEngine {
     pub(super) fn get_field_info_impl(&self, field_addr: usize) -> Result<FieldInfo> {
         let handle = unsafe { std::mem::transmute::<usize, WinHandle>(self.process_handle) };
 
+        // 4.25 未満のプロパティは FField ではなく UProperty (UObject 派生)
+        if !self.version.has_child_properties() {
+            let offsets = UEOffsets::for_version(self.version);
+            return self
+                .get_uproperty_info(handle, field_addr, &offsets)
+                .ok_or_else(|| EngineError::FieldNotFound(format!("0x{:X}", field_addr)));
+        }
+
         let field = FField::read(handle, field_addr)?;
         let name = self.get_fname_impl(field.name.comparison_index)?;
 
-        // FProperty の追加フィールドを読む
-        // FProperty は FField を継承し、以下のフィールドを追加:
-        // FField base: 36 bytes (ClassPrivate:8 + Owner:8 + Next:8 + NamePrivate:8 + FlagsPrivate:4)
-        // - ArrayDim (4 bytes) at +36
-        // - ElementSize (4 bytes) at +40
-        // - PropertyFlags (8 bytes) at +44
-        // - RepIndex (2 bytes) at +52
-        // - BlueprintReplicationCondition (1 byte + padding) at +54
-        // - Offset_Internal (4 bytes) at +56 (non-editor) or +60 (editor)
-        //
-        // ただし、FField の実サイズは 40 バイト (8バイトアライメント) の可能性あり
-        // その場合: Offset_Internal は +60 または +64
-
-        // 複数のオフセットを試す
-        let mut offset = 0usize;
-        for fprop_offset in [56usize, 60, 64, 68, 72, 44, 48, 52] {
-            if let Ok(data) = read_process_memory(handle, field_addr + fprop_offset, 4) {
-                let val = i32::from_le_bytes(data[..4].try_into().unwrap());
-                // 妥当な offset 値かチェック (0-65536 範囲)
-                if val >= 0 && val < 65536 {
-                    offset = val as usize;
-                    break;
-                }
-            }
-        }
+        // Offset_Internal の位置は所有クラスのプロパティ群で検証して決める
+        // (FFieldVariant の最下位ビットは UObject かどうかのタグ)
+        let owner = field.owner & !1;
+        let offset_pos = self.resolve_property_offset_pos(handle, owner);
+        let offset = read_process_memory(handle, field_addr + offset_pos, 4)
+            .map(|data| i32::from_le_bytes(data[..4].try_into().unwrap()).max(0) as usize)
+            .unwrap_or(0);
+
+        let element_size = FProperty::read(handle, field_addr)
+            .map(|p| p.element_size.max(0) as usize)
+            .unwrap_or(0);
+        let type_info = match self.read_ffield_class_name(handle, field.class_private) {
+            Ok(type_name) if type_name == "MapProperty" => match self.map_property_types(handle, field_addr) {
+                Some((key_type, value_type)) => TypeInfo {
+                    name: format!("TMap<{}, {}>", key_type.name, value_type.name),
+                    size: element_size,
+                    kind: TypeKind::Map(Box::new(key_type), Box::new(value_type)),
+                },
+                None => Self::property_type_info(&type_name, element_size),
+            },
+            Ok(type_name) => self.resolve_property_type(handle, field_addr, &type_name, element_size),
+            Err(_) => TypeInfo {
+                name: "unknown".into(),
+                size: element_size,
+                kind: TypeKind::Unknown,
+            },
+        };
 
         Ok(FieldInfo {
             name,
             handle: FieldHandle(field_addr),
             offset,
-            type_info: TypeInfo {
-                name: "unknown".into(),
-                size: 0,
-                kind: TypeKind::Unknown,
-            },
+            type_info,
         })
     }
 
+    /// クラスのプロパティ群から FProperty::Offset_Internal の位置を決める（クラスごとにキャッシュ）
+    ///
+    /// 候補位置ごとに各プロパティの (Offset, Offset + ElementSize * ArrayDim) を読み、
+    /// PropertiesSize に収まり他と重ならない区間の数をスコアとして最大のものを採用する。
+    /// owner が UStruct でない場合（構造体内のプロパティなど）は既定の候補を使う
+    fn resolve_property_offset_pos(&self, handle: WinHandle, owner: usize) -> usize {
+        let default = FProperty::OFFSET_INTERNAL_CANDIDATES[0];
+        if !self.is_valid_pointer(owner) {
+            return default;
+        }
+
+        let mut cache = self.property_offset_cache.lock().unwrap_or_else(|e| e.into_inner());
+        if let Some(&pos) = cache.get(&owner) {
+            return pos;
+        }
+
+        let Ok(ustruct) = self.read_ustruct(handle, owner) else {
+            return default;
+        };
+        let properties_size = ustruct.properties_size.max(0) as usize;
+
+        // プロパティのアドレスと占有サイズを集める
+        let mut properties = Vec::new();
+        let mut current = ustruct.child_properties;
+        while self.is_valid_pointer(current) && properties.len() < 1000 {
+            let Ok(field) = FField::read(handle, current) else {
+                break;
+            };
+            if let Ok(property) = FProperty::read(handle, current) {
+                properties.push((current, property.total_size()));
+            }
+            current = field.next;
+        }
+
+        let mut best = (default, 0usize);
+        for &pos in &FProperty::OFFSET_INTERNAL_CANDIDATES {
+            let intervals: Vec<(usize, usize)> = properties
+                .iter()
+                .filter_map(|&(address, size)| {
+                    let data = read_process_memory(handle, address + pos, 4).ok()?;
+                    let offset = i32::from_le_bytes(data[..4].try_into().unwrap());
+                    let offset = usize::try_from(offset).ok()?;
+                    (properties_size == 0 || offset + size <= properties_size).then_some((offset, size))
+                })
+                .collect();
+
+            let score = Self::non_overlapping_count(intervals);
+            if score > best.1 {
+                best = (pos, score);
+            }
+        }
+
+        tracing::debug!(
+            "Offset_Internal for class 0x{:X}: +{} ({}/{} properties consistent)",
+            owner,
+            best.0,
+            best.1,
+            properties.len()
+        );
+        cache.insert(owner, best.0);
+        best.0
+    }
+
+    /// 他の区間と重ならない (offset, size) 区間の数
+    ///
+    /// ビットフィールドの BoolProperty は同じバイトを共有するので、
+    /// offset と size が完全に一致する区間同士は重なりとみなさない
+    fn non_overlapping_count(mut intervals: Vec<(usize, usize)>) -> usize {
+        intervals.sort_unstable();
+
+        // 完全一致する区間をまとめ、(start, end, 個数) にする
+        let mut groups: Vec<(usize, usize, usize)> = Vec::new();
+        for (offset, size) in intervals {
+            match groups.last_mut() {
+                Some(last) if last.0 == offset && last.1 == offset + size => last.2 += 1,
+                _ => groups.push((offset, offset + size, 1)),
+            }
+        }
+
+        // start 順なので、前方との重なりは end の最大値、後方との重なりは次の start で判定できる
+        let mut max_end = 0;
+        let mut count = 0;
+        for (i, &(start, end, n)) in groups.iter().enumerate() {
+            let overlaps_prev = i > 0 && start < max_end;
+            let overlaps_next = groups.get(i + 1).is_some_and(|next| next.0 < end);
+            if !overlaps_prev && !overlaps_next {
+                count += n;
+            }
+            max_end = max_end.max(end);
+        }
+        count
+    }
+
     /// UClass の全プロパティを列挙
     pub(super) fn enumerate_fields_impl(&self, class_addr: usize) -> Result<Vec<FieldInfo>> {
         let handle = unsafe { std::mem::transmute::<usize, WinHandle>(self.process_handle) };
 
-        let ustruct = UStruct::read(handle, class_addr)?;
+        let ustruct = self.read_ustruct(handle, class_addr)?;
+
+        // 4.25 未満はプロパティが UStruct::Children 側にある
+        if !self.version.has_child_properties() {
+            return Ok(self.enumerate_uproperties(handle, ustruct.children));
+        }
+
         let mut current_field = ustruct.child_properties;
         let mut fields = Vec::new();
 
@@ -563,6 +1287,57 @@ impl UnrealEngine {
         Ok(fields)
     }
 
+    /// UStruct::Children（UField の連結リスト）から UProperty を列挙（4.25 未満）
+    ///
+    /// Children には UFunction なども混在するので、クラス名が "Property" で終わるものだけを拾う
+    fn enumerate_uproperties(&self, handle: WinHandle, children: usize) -> Vec<FieldInfo> {
+        let offsets = UEOffsets::for_version(self.version);
+        let mut current = children;
+        let mut fields = Vec::new();
+
+        let mut count = 0;
+        while self.is_valid_pointer(current) && count < 1000 {
+            count += 1;
+
+            if let Some(info) = self.get_uproperty_info(handle, current, &offsets) {
+                fields.push(info);
+            }
+
+            match read_process_memory(handle, current + offsets.ufield_next, 8) {
+                Ok(data) => current = usize::from_le_bytes(data[..8].try_into().unwrap()),
+                Err(_) => break,
+            }
+        }
+
+        tracing::info!("enumerate_uproperties: found {} properties", fields.len());
+        fields
+    }
+
+    /// UProperty（UObject 派生）から情報を取得。プロパティでなければ None
+    fn get_uproperty_info(&self, handle: WinHandle, property_addr: usize, offsets: &UEOffsets) -> Option<FieldInfo> {
+        let object = UObject::read(handle, property_addr).ok()?;
+        let class = UObject::read(handle, object.class).ok()?;
+        let type_name = self.get_fname_impl(class.name.comparison_index).ok()?;
+        if !type_name.ends_with("Property") {
+            return None;
+        }
+
+        let name = self.get_fname_impl(object.name.comparison_index).ok()?;
+        let read_i32 = |offset: usize| {
+            read_process_memory(handle, property_addr + offset, 4)
+                .map(|data| i32::from_le_bytes(data[..4].try_into().unwrap()).max(0) as usize)
+                .unwrap_or(0)
+        };
+
+        let element_size = read_i32(offsets.uproperty_element_size);
+        Some(FieldInfo {
+            name,
+            handle: FieldHandle(property_addr),
+            offset: read_i32(offsets.uproperty_offset_internal),
+            type_info: self.resolve_property_type(handle, property_addr, &type_name, element_size),
+        })
+    }
+
     // =========================================================================
     // インスタンス関連の実装
     // =========================================================================
@@ -574,7 +1349,9 @@ impl UnrealEngine {
 
         let mut instances = Vec::new();
 
+        let started = std::time::Instant::now();
         for obj_addr in &all_objects {
+            check_traversal_timeout(started)?;
             if let Ok(obj) = UObject::read(handle, *obj_addr) {
                 // このオブジェクトが指定されたクラスのインスタンスかどうかをチェック
                 // 直接一致、または派生クラスのインスタンスかを確認
@@ -603,7 +1380,7 @@ impl UnrealEngine {
         // 最大 20 レベルまで（無限ループ防止）
         let mut current = obj_class;
         for _ in 0..20 {
-            if let Ok(ustruct) = UStruct::read(handle, current) {
+            if let Ok(ustruct) = self.read_ustruct(handle, current) {
                 if ustruct.super_struct == 0 {
                     break;
                 }
@@ -619,3 +1396,66 @@ impl UnrealEngine {
         false
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn struct_type(name: &str, size: usize) -> TypeInfo {
+        TypeInfo {
+            name: name.into(),
+            size,
+            kind: TypeKind::Struct(ClassHandle(0)),
+        }
+    }
+
+    /// FVector などの型名は StructProperty の UScriptStruct 名 ("Vector") に F を付けたもの
+    fn read_math_struct(type_info: &TypeInfo, lwc: bool, data: &[u8]) -> Option<Value> {
+        let size = UnrealEngine::math_struct_size(type_info, lwc)?;
+        UnrealEngine::decode_math_struct(&type_info.name, &data[..size])
+    }
+
+    #[test]
+    fn test_decode_math_struct_vector() {
+        let floats: Vec<u8> = [1.0f32, 2.0, 3.0].iter().flat_map(|c| c.to_le_bytes()).collect();
+        assert_eq!(
+            read_math_struct(&struct_type("FVector", 12), true, &floats),
+            Some(Value::Vector3(1.0, 2.0, 3.0))
+        );
+
+        let doubles: Vec<u8> = [1.5f64, -2.0, 3.25].iter().flat_map(|c| c.to_le_bytes()).collect();
+        assert_eq!(
+            read_math_struct(&struct_type("FVector", 24), false, &doubles),
+            Some(Value::Vector3D(1.5, -2.0, 3.25))
+        );
+        // ElementSize が不明ならバージョンで判断する
+        assert_eq!(
+            read_math_struct(&struct_type("FVector", 0), true, &doubles),
+            Some(Value::Vector3D(1.5, -2.0, 3.25))
+        );
+    }
+
+    #[test]
+    fn test_decode_math_struct_quat_and_color() {
+        let floats: Vec<u8> = [0.0f32, 0.0, 0.0, 1.0].iter().flat_map(|c| c.to_le_bytes()).collect();
+        assert_eq!(
+            read_math_struct(&struct_type("FQuat", 16), false, &floats),
+            Some(Value::Quaternion(0.0, 0.0, 0.0, 1.0))
+        );
+
+        let doubles: Vec<u8> = [0.5f64, 0.5, 0.5, 0.5].iter().flat_map(|c| c.to_le_bytes()).collect();
+        assert_eq!(
+            read_math_struct(&struct_type("FQuat", 32), false, &doubles),
+            Some(Value::QuaternionD(0.5, 0.5, 0.5, 0.5))
+        );
+
+        // メモリ上は B, G, R, A
+        assert_eq!(
+            read_math_struct(&struct_type("FColor", 4), false, &[30, 20, 10, 255]),
+            Some(Value::Color(10, 20, 30, 255))
+        );
+
+        // プロパティクラス名 (StructProperty) のままでは構造体として扱わない
+        assert_eq!(UnrealEngine::math_struct_size(&struct_type("StructProperty", 12), false), None);
+    }
+}
@@ -3,8 +3,10 @@
 use super::error::{EngineError, Result};
 use super::types::*;
 use super::GameEngine;
+use crate::types::StringEncoding;
 use std::any::Any;
 use std::collections::HashMap;
+use std::sync::Mutex;
 
 pub mod implementation;
 pub mod methods;
@@ -13,8 +15,11 @@ pub mod scanner;
 pub mod signatures;
 pub mod structures;
 
+/// read_null_terminated_string で読む最大バイト数
+const MAX_NULL_TERMINATED_LEN: usize = 0x10000;
+
 /// Unreal Engine のバージョン
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
 pub enum UEVersion {
     UE4_20,
     UE4_21,
@@ -29,9 +34,78 @@ pub enum UEVersion {
     UE5_2,
     UE5_3,
     UE5_4,
+    UE5_5,
     Unknown,
 }
 
+impl UEVersion {
+    /// Large World Coordinates（FVector が double 3要素）を使うバージョンか
+    pub fn is_lw_coordinates(&self) -> bool {
+        matches!(
+            self,
+            UEVersion::UE5_1 | UEVersion::UE5_2 | UEVersion::UE5_3 | UEVersion::UE5_4 | UEVersion::UE5_5
+        )
+    }
+
+    /// プロパティが UStruct::ChildProperties（FField）にあるバージョンか（4.25 以降）
+    ///
+    /// 4.24 以前のプロパティは UObject 派生の UProperty で、UStruct::Children に UFunction と混在する。
+    /// バージョン不明の場合は新しいレイアウトとみなす
+    pub fn has_child_properties(&self) -> bool {
+        !matches!(
+            self,
+            UEVersion::UE4_20 | UEVersion::UE4_21 | UEVersion::UE4_22 | UEVersion::UE4_23 | UEVersion::UE4_24
+        )
+    }
+
+    /// メジャー・マイナーバージョンに対応する UEVersion（未対応のバージョンは Unknown）
+    pub fn from_major_minor(major: u32, minor: u32) -> Self {
+        match (major, minor) {
+            (4, 20) => UEVersion::UE4_20,
+            (4, 21) => UEVersion::UE4_21,
+            (4, 22) => UEVersion::UE4_22,
+            (4, 23) => UEVersion::UE4_23,
+            (4, 24) => UEVersion::UE4_24,
+            (4, 25) => UEVersion::UE4_25,
+            (4, 26) => UEVersion::UE4_26,
+            (4, 27) => UEVersion::UE4_27,
+            (5, 0) => UEVersion::UE5_0,
+            (5, 1) => UEVersion::UE5_1,
+            (5, 2) => UEVersion::UE5_2,
+            (5, 3) => UEVersion::UE5_3,
+            (5, 4) => UEVersion::UE5_4,
+            (5, 5) => UEVersion::UE5_5,
+            _ => UEVersion::Unknown,
+        }
+    }
+
+    /// ブランチ名（例: "++UE5+Release-5.3"）からバージョンを読み取る
+    pub fn from_branch_name(branch: &str) -> Self {
+        let Some((_, version)) = branch.split_once("+Release-") else {
+            return UEVersion::Unknown;
+        };
+        let mut parts = version.split('.').map(|part| {
+            let digits: String = part.chars().take_while(char::is_ascii_digit).collect();
+            digits.parse::<u32>().ok()
+        });
+        match (parts.next().flatten(), parts.next().flatten()) {
+            (Some(major), Some(minor)) => Self::from_major_minor(major, minor),
+            _ => UEVersion::Unknown,
+        }
+    }
+}
+
+/// FNamePool の使用状況（GNames が空に見えるときの診断用）
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct FNamePoolStats {
+    /// 確保済みブロック数 (CurrentBlock + 1)
+    pub total_blocks: u32,
+    /// 登録済み FNameEntry の数
+    pub used_entries: u32,
+    /// 使用中のバイト数から見積もったメモリ量
+    pub estimated_memory_kb: u32,
+}
+
 /// Unreal Engine バックエンド
 pub struct UnrealEngine {
     /// プロセスハンドル（usize として保持）
@@ -72,6 +146,14 @@ pub struct UnrealEngine {
 
     /// メソッドキャッシュ（MethodHandle -> 情報）
     method_cache: HashMap<MethodHandle, MethodInfo>,
+
+    /// UStruct の SuperStruct オフセット（UE バージョンごとに最初の読み取りで検出）
+    ///
+    /// None は既知クラスの判定が一致しなかったことを表し、以後は UStruct ごとに判定する
+    ustruct_offsets: Mutex<HashMap<UEVersion, Option<usize>>>,
+
+    /// FProperty::Offset_Internal の位置（クラスアドレス -> FProperty 先頭からのオフセット）
+    property_offset_cache: Mutex<HashMap<usize, usize>>,
 }
 
 impl UnrealEngine {
@@ -91,6 +173,8 @@ impl UnrealEngine {
             initialized: false,
             class_cache: HashMap::new(),
             method_cache: HashMap::new(),
+            ustruct_offsets: Mutex::new(HashMap::new()),
+            property_offset_cache: Mutex::new(HashMap::new()),
         }
     }
 
@@ -111,8 +195,7 @@ impl UnrealEngine {
 
     /// UE バージョンを検出
     fn detect_version(&self) -> UEVersion {
-        // TODO: バージョン検出ロジック
-        UEVersion::Unknown
+        self.detect_version_impl()
     }
 
     /// GNames から名前を取得
@@ -126,19 +209,103 @@ impl UnrealEngine {
     }
 
     /// UE 固有: Blueprint 関数の一覧を取得
-    pub fn enumerate_blueprint_functions(&self, _class: ClassHandle) -> Result<Vec<MethodInfo>> {
-        // TODO: FUNC_BlueprintCallable フラグを持つ UFunction を列挙
-        Err(EngineError::UnsupportedOperation(
-            "Blueprint enumeration not implemented".into(),
-        ))
+    pub fn enumerate_blueprint_functions(&self, class: ClassHandle) -> Result<Vec<MethodInfo>> {
+        self.enumerate_methods_with_flags_impl(class.0, structures::UFunction::FUNC_BLUEPRINT_CALLABLE)
+    }
+
+    /// UE 固有: ネイティブ (C++) 関数の一覧を取得
+    pub fn enumerate_native_functions(&self, class: ClassHandle) -> Result<Vec<MethodInfo>> {
+        self.enumerate_methods_with_flags_impl(class.0, structures::UFunction::FUNC_NATIVE)
+    }
+
+    /// UE 固有: FNamePool のブロック数・エントリ数を取得
+    ///
+    /// total_blocks が 0 ならゲームがまだ名前を読み込んでいない
+    pub fn gnames_stats(&self) -> Result<FNamePoolStats> {
+        if !self.initialized {
+            return Err(EngineError::NotInitialized);
+        }
+
+        self.gnames_stats_impl()
+    }
+
+    /// UE 固有: インスタンスの TMap フィールドから生の (キー, 値) を読み取る
+    ///
+    /// 削除済み (空き) 要素は含まない
+    pub fn read_tmap_pairs(
+        &self,
+        instance_addr: usize,
+        offset: usize,
+        key: structures::TMapElementType,
+        value: structures::TMapElementType,
+    ) -> Result<Vec<structures::TMapPair>> {
+        if !self.initialized {
+            return Err(EngineError::NotInitialized);
+        }
+
+        self.read_tmap_pairs_impl(instance_addr, offset, key, value)
+    }
+
+    /// UE 固有: インスタンスのマルチキャストデリゲートにバインドされた (オブジェクト, 関数名) を読み取る
+    pub fn read_delegate(&self, instance_addr: usize, field_offset: usize) -> Result<Vec<(InstanceHandle, String)>> {
+        if !self.initialized {
+            return Err(EngineError::NotInitialized);
+        }
+
+        self.read_delegate_impl(instance_addr, field_offset)
+    }
+
+    /// UE 固有: パッケージ (最外のオブジェクト) のパスを取得 (例: /Game/Maps/Lobby)
+    pub fn get_package_path(&self, pkg_addr: usize) -> Result<String> {
+        if !self.initialized {
+            return Err(EngineError::NotInitialized);
+        }
+
+        self.get_package_path_impl(pkg_addr)
+    }
+
+    /// UE 固有: パッケージから始まるオブジェクトのフルパスを取得
+    ///
+    /// 例: /Game/Maps/Lobby.Lobby:PersistentLevel.BP_Player_C_0
+    pub fn get_full_object_path(&self, obj_addr: usize) -> Result<String> {
+        if !self.initialized {
+            return Err(EngineError::NotInitialized);
+        }
+
+        self.get_full_object_path_impl(obj_addr)
+    }
+
+    /// UE 固有: ゲームの UWorld を検索
+    pub fn find_uworld(&self) -> Result<InstanceHandle> {
+        if !self.initialized {
+            return Err(EngineError::NotInitialized);
+        }
+
+        self.find_uworld_impl().map(InstanceHandle)
+    }
+
+    /// UE 固有: UWorld の GameState を取得
+    pub fn get_game_state(&self) -> Result<InstanceHandle> {
+        let world = self.find_uworld()?;
+        self.get_game_state_impl(world.0).map(InstanceHandle)
     }
 
     /// UE 固有: コンソールコマンド実行
-    pub fn execute_console_command(&self, _command: &str) -> Result<()> {
-        // TODO: UE コンソールコマンド実行
-        Err(EngineError::UnsupportedOperation(
-            "Console command not implemented".into(),
-        ))
+    ///
+    /// 出力を読み取れた場合はその文字列を返す
+    pub fn execute_console_command(&self, command: &str) -> Result<Option<String>> {
+        self.execute_console_command_impl(command)
+    }
+
+    /// UE 固有: null 終端文字列を読み取る（TCHAR 文字列なら Utf16）
+    ///
+    /// 終端が見つからない場合は MAX_NULL_TERMINATED_LEN バイトで打ち切る
+    pub fn read_null_terminated_string(&self, address: usize, encoding: StringEncoding) -> Result<String> {
+        use crate::platform::windows::read_until_null;
+
+        let handle = unsafe { std::mem::transmute::<usize, windows::Win32::Foundation::HANDLE>(self.process_handle) };
+        let raw = read_until_null(handle, address, MAX_NULL_TERMINATED_LEN, encoding.char_size())?;
+        Ok(encoding.decode(&raw))
     }
 
     /// GNamesの実際の値を更新
@@ -192,6 +359,41 @@ impl UnrealEngine {
         self.gobjects = self.gobjects_ptr;
         Ok(())
     }
+
+    /// GNames / GObjects / ProcessEvent を検索し直す
+    ///
+    /// ゲームのロード完了前に初期化して失敗した場合や、レベル再読み込みで
+    /// アドレスが変わった場合に使う。キャッシュはすべて破棄する
+    pub fn refresh(&mut self) -> Result<()> {
+        self.initialized = false;
+        self.gnames_ptr = 0;
+        self.gnames = 0;
+        self.gobjects_ptr = 0;
+        self.gobjects = 0;
+        self.process_event = 0;
+        self.class_cache.clear();
+        self.method_cache.clear();
+        self.ustruct_offsets.lock().unwrap_or_else(|e| e.into_inner()).clear();
+        self.property_offset_cache.lock().unwrap_or_else(|e| e.into_inner()).clear();
+
+        self.initialize()?;
+
+        // FName のインデックス 0 は常に "None"
+        match self.get_fname_impl(0) {
+            Ok(name) if name == "None" => Ok(()),
+            Ok(name) => {
+                self.initialized = false;
+                Err(EngineError::InitializationFailed(format!(
+                    "GNames validation failed: index 0 is \"{}\"",
+                    name
+                )))
+            }
+            Err(e) => {
+                self.initialized = false;
+                Err(EngineError::InitializationFailed(format!("GNames validation failed: {}", e)))
+            }
+        }
+    }
 }
 
 impl GameEngine for UnrealEngine {
@@ -208,6 +410,16 @@ impl GameEngine for UnrealEngine {
             return Ok(());
         }
 
+        // 32bit プロセスは未対応（オフセット・シェルコードが x64 前提）
+        let handle = unsafe {
+            std::mem::transmute::<usize, windows::Win32::Foundation::HANDLE>(self.process_handle)
+        };
+        if let Ok(false) = crate::platform::windows::is_process_64bit(handle) {
+            return Err(EngineError::UnsupportedOperation(
+                "32-bit (WOW64) processes are not supported by the Unreal Engine backend".into(),
+            ));
+        }
+
         // メインモジュールの情報を取得
         let module = crate::platform::module::get_main_module(self.process_id)
             .map_err(|e| EngineError::InitializationFailed(format!("Failed to get module info: {}", e)))?;
@@ -217,6 +429,9 @@ impl GameEngine for UnrealEngine {
 
         tracing::info!("Module: {} at 0x{:X} (size: 0x{:X})", module.name, self.module_base, self.module_size);
 
+        // オフセット表の選択に使うので、構造体の探索より先に検出する
+        self.version = self.detect_version();
+
         // GObjects を先に検索（ヒープアドレス推定に使用）
         self.gobjects_ptr = self.find_gobjects()?;
         self.refresh_gobjects()?;
@@ -227,7 +442,6 @@ impl GameEngine for UnrealEngine {
 
         // ProcessEvent を検索
         self.process_event = self.find_process_event()?;
-        self.version = self.detect_version();
 
         self.initialized = true;
         Ok(())
@@ -237,6 +451,37 @@ impl GameEngine for UnrealEngine {
         self.initialized
     }
 
+    /// GObjects[0]（常に存在する CoreUObject パッケージ）を読み直し、vtable が有効か確認する
+    fn health_check(&self) -> Result<()> {
+        use crate::platform::windows::read_process_memory;
+        use structures::UObject;
+
+        if !self.initialized {
+            return Err(EngineError::NotInitialized);
+        }
+
+        let handle = unsafe {
+            std::mem::transmute::<usize, windows::Win32::Foundation::HANDLE>(self.process_handle)
+        };
+        let object = self
+            .object_from_index(handle, 0)
+            .ok_or_else(|| EngineError::MemoryError("GObjects[0] is no longer readable".into()))?;
+        let uobject = UObject::read(handle, object)?;
+
+        // vtable の先頭エントリ（仮想デストラクタ）も有効なポインタのはず
+        let first_entry = read_process_memory(handle, uobject.vtable, 8)
+            .map(|data| usize::from_le_bytes(data[..8].try_into().unwrap()))
+            .unwrap_or(0);
+        if !self.is_valid_pointer(uobject.vtable) || !self.is_valid_pointer(first_entry) {
+            return Err(EngineError::MemoryError(format!(
+                "GObjects[0] at 0x{:X} has a stale vtable (0x{:X})",
+                object, uobject.vtable
+            )));
+        }
+
+        Ok(())
+    }
+
     fn find_class(&self, name: &str) -> Result<ClassHandle> {
         if !self.initialized {
             return Err(EngineError::NotInitialized);
@@ -284,11 +529,16 @@ impl GameEngine for UnrealEngine {
         self.get_instances_impl(class.0)
     }
 
-    fn get_instance_class(&self, _instance: InstanceHandle) -> Result<ClassHandle> {
-        // TODO: UObject->Class を読み取る
-        Err(EngineError::UnsupportedOperation(
-            "Instance class reading not implemented".into(),
-        ))
+    fn get_instance_class(&self, instance: InstanceHandle) -> Result<ClassHandle> {
+        let handle = unsafe {
+            std::mem::transmute::<usize, windows::Win32::Foundation::HANDLE>(self.process_handle)
+        };
+
+        let obj = structures::UObject::read(handle, instance.0)?;
+        if obj.class == 0 {
+            return Err(EngineError::InstanceNotFound);
+        }
+        Ok(ClassHandle(obj.class))
     }
 
     fn invoke(
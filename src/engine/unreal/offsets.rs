@@ -7,30 +7,88 @@ pub struct UEOffsets {
     pub uobject_class: usize,
     pub uobject_outer: usize,
     pub ufield_next: usize,
-    pub ustruct_children: usize,
-    pub ufunction_func: usize,
+    pub ufunction_flags: usize,
+    pub ufunction_params_size: usize,
+    pub ufunction_return_value_offset: usize,
+    /// UWorld::GameState（リフレクションで見つからない場合のフォールバック）
+    pub uworld_game_state: usize,
+    /// UProperty::ElementSize（4.25 未満、プロパティが UObject だった頃）
+    pub uproperty_element_size: usize,
+    /// UProperty::Offset_Internal（4.25 未満）
+    pub uproperty_offset_internal: usize,
 }
 
 impl UEOffsets {
     pub fn for_version(version: UEVersion) -> Self {
         match version {
+            // 4.20 - 4.24: UStruct が 0x88 バイトで、UFunction のメンバはその直後から並ぶ
+            UEVersion::UE4_20 | UEVersion::UE4_21 | UEVersion::UE4_22 => Self {
+                uobject_name: 0x18,
+                uobject_class: 0x10,
+                uobject_outer: 0x20,
+                ufield_next: 0x28,
+                ufunction_flags: 0x88,
+                ufunction_params_size: 0x8E,
+                ufunction_return_value_offset: 0x90,
+                uworld_game_state: 0x120,
+                uproperty_element_size: 0x34,
+                uproperty_offset_internal: 0x44,
+            },
+            UEVersion::UE4_23 | UEVersion::UE4_24 => Self {
+                uobject_name: 0x18,
+                uobject_class: 0x10,
+                uobject_outer: 0x20,
+                ufield_next: 0x28,
+                ufunction_flags: 0x88,
+                ufunction_params_size: 0x8E,
+                ufunction_return_value_offset: 0x90,
+                uworld_game_state: 0x128,
+                uproperty_element_size: 0x34,
+                uproperty_offset_internal: 0x44,
+            },
+            // 4.25 / 4.26: ChildProperties (FField) が追加され UStruct が 0xB0 バイトになる
+            UEVersion::UE4_25 | UEVersion::UE4_26 => Self {
+                uobject_name: 0x18,
+                uobject_class: 0x10,
+                uobject_outer: 0x20,
+                ufield_next: 0x28,
+                ufunction_flags: 0xB0,
+                ufunction_params_size: 0xB6,
+                ufunction_return_value_offset: 0xB8,
+                uworld_game_state: 0x120,
+                uproperty_element_size: 0x34,
+                uproperty_offset_internal: 0x44,
+            },
             UEVersion::UE4_27 => Self {
                 uobject_name: 0x18,
                 uobject_class: 0x10,
                 uobject_outer: 0x20,
                 ufield_next: 0x28,
-                ustruct_children: 0x50,
-                ufunction_func: 0xB0,
+                ufunction_flags: 0xB0,
+                ufunction_params_size: 0xB6,
+                ufunction_return_value_offset: 0xB8,
+                uworld_game_state: 0x120,
+                uproperty_element_size: 0x34,
+                uproperty_offset_internal: 0x44,
             },
-            UEVersion::UE5_3 => Self {
+            UEVersion::UE5_0
+            | UEVersion::UE5_1
+            | UEVersion::UE5_2
+            | UEVersion::UE5_3
+            | UEVersion::UE5_4
+            | UEVersion::UE5_5 => Self {
                 uobject_name: 0x18,
                 uobject_class: 0x10,
                 uobject_outer: 0x20,
                 ufield_next: 0x28,
-                ustruct_children: 0x50,
-                ufunction_func: 0xB8,
+                ufunction_flags: 0xB0,
+                ufunction_params_size: 0xB6,
+                ufunction_return_value_offset: 0xB8,
+                uworld_game_state: 0x158,
+                uproperty_element_size: 0x34,
+                uproperty_offset_internal: 0x44,
             },
-            _ => Self::default(),
+            UEVersion::Unknown => Self::default(),
         }
     }
 }
@@ -42,8 +100,12 @@ impl Default for UEOffsets {
             uobject_class: 0x10,
             uobject_outer: 0x20,
             ufield_next: 0x28,
-            ustruct_children: 0x50,
-            ufunction_func: 0xB0,
+            ufunction_flags: 0xB0,
+            ufunction_params_size: 0xB6,
+            ufunction_return_value_offset: 0xB8,
+            uworld_game_state: 0x130,
+            uproperty_element_size: 0x34,
+            uproperty_offset_internal: 0x44,
         }
     }
 }
@@ -1,6 +1,7 @@
 /// Pattern scanning utilities for UE structure detection
 
 use crate::platform::windows::{query_memory_regions, read_process_memory, HANDLE};
+use crate::scanner::simd::simd_aob_scan;
 use windows::Win32::System::Memory::MEM_COMMIT;
 
 /// パターンスキャン結果
@@ -35,24 +36,15 @@ impl Pattern {
         Self { bytes, mask }
     }
 
-    /// バイト配列とマッチするか
-    fn matches(&self, data: &[u8]) -> bool {
-        if data.len() < self.bytes.len() {
-            return false;
-        }
-
-        for i in 0..self.bytes.len() {
-            if self.mask[i] && data[i] != self.bytes[i] {
-                return false;
-            }
-        }
-
-        true
-    }
-
     pub fn len(&self) -> usize {
         self.bytes.len()
     }
+
+    /// SIMDでデータ全体を検索し、マッチしたオフセットを返す
+    pub fn simd_search(&self, data: &[u8]) -> Vec<usize> {
+        let mask: Vec<u8> = self.mask.iter().map(|&m| if m { 0xFF } else { 0x00 }).collect();
+        simd_aob_scan(data, &self.bytes, &mask)
+    }
 }
 
 /// メモリ領域内でパターンをスキャン
@@ -80,13 +72,11 @@ pub fn scan_pattern(
         // メモリを読み取り
         if let Ok(data) = read_process_memory(handle, region.base_address, region.size) {
             // パターン検索
-            for i in 0..data.len().saturating_sub(pattern.len()) {
-                if pattern.matches(&data[i..]) {
-                    results.push(ScanResult {
-                        address: region.base_address + i,
-                        offset: i,
-                    });
-                }
+            for i in pattern.simd_search(&data) {
+                results.push(ScanResult {
+                    address: region.base_address + i,
+                    offset: i,
+                });
             }
         }
     }
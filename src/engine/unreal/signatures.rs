@@ -28,6 +28,13 @@ impl UESignatures {
     /// GNames パターン8 (UE5 - 別のアプローチ)
     pub const GNAMES_UE5_2: &'static str = "48 8D 0D ?? ?? ?? ?? E8 ?? ?? ?? ?? 48 8B D8 48 85 C0 74";
 
+    /// GNames パターン9 (UE5.5 - NamePoolData の初期化)
+    ///
+    /// lea rax, [NamePoolData]; jmp; lea rcx, [NamePoolData]; call FNamePool::FNamePool; mov byte ptr [bInit], 1
+    /// 5.5 で FNamePool の Lock が FRWLock から std::shared_mutex になったため、
+    /// Blocks のオフセットは検証時の候補 (blocks_offset) で吸収する
+    pub const GNAMES_UE5_5: &'static str = "48 8D 05 ?? ?? ?? ?? EB ?? 48 8D 0D ?? ?? ?? ?? E8 ?? ?? ?? ?? C6 05";
+
     /// GUObjectArray (GObjects) パターン
     /// mov rcx, qword ptr [rip + offset]
     pub const GOBJECTS: &'static str = "48 8B 0D ?? ?? ?? ?? 48 8D 14 C1";
@@ -59,6 +66,11 @@ impl UESignatures {
     pub const PROCESS_EVENT_ALT: &'static str =
         "48 89 5C 24 ?? 48 89 74 24 ?? 55 57 41 56 48 8D 6C 24";
 
+    /// ビルドのブランチ名 "++UE5+Release-5.3" の "+Release-" 部分 (UTF-16LE)
+    ///
+    /// BuildSettings がリリースビルドにも埋め込むので、バージョン検出に使う
+    pub const RELEASE_BRANCH: &'static str = "2B 00 52 00 65 00 6C 00 65 00 61 00 73 00 65 00 2D 00";
+
     /// FName::ToString パターン（GNames 検証用）
     pub const FNAME_TOSTRING: &'static str =
         "48 89 5C 24 ?? 57 48 83 EC 30 83 79 04 00 48 8B DA";
@@ -79,6 +91,7 @@ impl VersionSignatures {
                 // UE5専用パターンを最初に
                 UESignatures::GNAMES_UE5_1,
                 UESignatures::GNAMES_UE5_2,
+                UESignatures::GNAMES_UE5_5,
                 // UE4/UE5共通パターン
                 UESignatures::GNAMES_ALT,
                 UESignatures::GNAMES_ALT5,
@@ -1,6 +1,10 @@
 /// Unreal Engine internal structures
 
-use crate::platform::windows::{read_process_memory, HANDLE};
+use crate::platform::module::ModuleInfo;
+use crate::platform::windows::{read_process_memory, read_until_null, HANDLE};
+use crate::types::StringEncoding;
+use std::cell::RefCell;
+use std::collections::HashMap;
 
 /// UObject 基底クラス
 #[repr(C)]
@@ -36,6 +40,191 @@ impl FName {
     }
 }
 
+/// FString - UE の可変長文字列 (TArray<TCHAR>)
+///
+/// Windows の TCHAR は UTF-16 なので、Data は null 終端付きの u16 配列を指す
+#[repr(C)]
+#[derive(Clone, Copy, Debug)]
+pub struct FString {
+    pub data: usize, // TCHAR*
+    pub num: i32,    // null 終端を含む文字数
+    pub max: i32,
+}
+
+impl FString {
+    pub const SIZE: usize = 16;
+
+    pub fn read(handle: HANDLE, address: usize) -> Result<Self, anyhow::Error> {
+        let data = read_process_memory(handle, address, Self::SIZE)?;
+        Ok(Self::from_bytes(&data))
+    }
+
+    /// パラメータ構造体などから切り出した 16 バイトを解釈
+    pub fn from_bytes(data: &[u8]) -> Self {
+        Self {
+            data: usize::from_le_bytes(data[0..8].try_into().unwrap()),
+            num: i32::from_le_bytes(data[8..12].try_into().unwrap()),
+            max: i32::from_le_bytes(data[12..16].try_into().unwrap()),
+        }
+    }
+
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(Self::SIZE);
+        bytes.extend_from_slice(&self.data.to_le_bytes());
+        bytes.extend_from_slice(&self.num.to_le_bytes());
+        bytes.extend_from_slice(&self.max.to_le_bytes());
+        bytes
+    }
+
+    /// Data が指す UTF-16 文字列を読み取る
+    pub fn read_string(&self, handle: HANDLE) -> Result<String, anyhow::Error> {
+        if self.data == 0 || self.num <= 0 {
+            return Ok(String::new());
+        }
+        if self.num > 0x10000 {
+            return Err(anyhow::anyhow!("FString length too large: {}", self.num));
+        }
+
+        // Num は null 終端を含むので、それを上限に終端まで読む
+        let raw = read_until_null(handle, self.data, self.num as usize * 2, 2)?;
+        Ok(StringEncoding::Utf16.decode(&raw))
+    }
+}
+
+/// FText - UE のローカライズ文字列
+///
+/// 本体は TSharedRef<ITextData> (TextData ポインタ + 参照カウンタ) + Flags で、
+/// 文字列は TextData 側に入っている。TextData 内の位置はバージョンにより異なる:
+/// - DisplayString (FString) - offset 0x28 (UE4.2x〜UE5)
+/// - Key (FString) - DisplayString が空の場合のフォールバック
+pub struct FText;
+
+impl FText {
+    pub const DISPLAY_STRING_OFFSET: usize = 0x28;
+    pub const KEY_OFFSET: usize = 0x18;
+
+    /// 表示文字列を読み取る（DisplayString が null なら Key を返す）
+    pub fn read(handle: HANDLE, address: usize) -> Result<String, anyhow::Error> {
+        let data = read_process_memory(handle, address, 8)?;
+        let text_data = usize::from_le_bytes(data[..8].try_into().unwrap());
+        if text_data == 0 {
+            return Ok(String::new());
+        }
+
+        let display = FString::read(handle, text_data + Self::DISPLAY_STRING_OFFSET)?;
+        if display.data != 0 {
+            return display.read_string(handle);
+        }
+
+        FString::read(handle, text_data + Self::KEY_OFFSET)?.read_string(handle)
+    }
+}
+
+/// TArray - UE の動的配列のヘッダー
+///
+/// レイアウト (16 bytes): Data (8 bytes) + Num (4 bytes) + Max (4 bytes)
+#[derive(Clone, Copy, Debug)]
+pub struct TArrayHeader {
+    pub data: usize,
+    pub num: i32,
+    pub max: i32,
+}
+
+impl TArrayHeader {
+    pub const SIZE: usize = 16;
+
+    pub fn read(handle: HANDLE, address: usize) -> Result<Self, anyhow::Error> {
+        let data = read_process_memory(handle, address, Self::SIZE)?;
+        Ok(Self {
+            data: usize::from_le_bytes(data[0..8].try_into().unwrap()),
+            num: i32::from_le_bytes(data[8..12].try_into().unwrap()),
+            max: i32::from_le_bytes(data[12..16].try_into().unwrap()),
+        })
+    }
+}
+
+/// FScriptDelegate - 単一のデリゲート (TScriptDelegate<FWeakObjectPtr>)
+///
+/// レイアウト (16 bytes):
+/// - Object (FWeakObjectPtr) - offset 0 - ObjectIndex (4 bytes) + ObjectSerialNumber (4 bytes)
+/// - FunctionName (FName) - offset 8
+///
+/// バインド先は弱参照なので、GObjects のインデックスから解決する必要がある
+#[derive(Clone, Copy, Debug)]
+pub struct FScriptDelegate {
+    pub object_index: i32,
+    pub object_serial_number: i32,
+    pub function_name: FName,
+}
+
+impl FScriptDelegate {
+    pub const SIZE: usize = 16;
+
+    pub fn read(handle: HANDLE, address: usize) -> Result<Self, anyhow::Error> {
+        let data = read_process_memory(handle, address, Self::SIZE)?;
+        Ok(Self::from_bytes(&data))
+    }
+
+    pub fn from_bytes(data: &[u8]) -> Self {
+        Self {
+            object_index: i32::from_le_bytes(data[0..4].try_into().unwrap()),
+            object_serial_number: i32::from_le_bytes(data[4..8].try_into().unwrap()),
+            function_name: FName {
+                comparison_index: u32::from_le_bytes(data[8..12].try_into().unwrap()),
+                number: u32::from_le_bytes(data[12..16].try_into().unwrap()),
+            },
+        }
+    }
+
+    /// バインド先の弱参照が有効 (ObjectIndex が INDEX_NONE でない) か
+    pub fn is_bound(&self) -> bool {
+        self.object_index >= 0 && self.object_serial_number != 0
+    }
+}
+
+/// FMulticastScriptDelegate - マルチキャストデリゲート
+///
+/// InvocationList (TArray<FScriptDelegate>) - offset 0
+/// (Sparse デリゲートは所有者側に格納されるため非対応)
+#[derive(Clone, Copy, Debug)]
+pub struct FMulticastDelegate {
+    pub invocation_list: TArrayHeader,
+}
+
+impl FMulticastDelegate {
+    /// 読み取るバインド数の上限（壊れたヘッダーで巨大な読み込みをしないように）
+    const MAX_BINDINGS: i32 = 0x1000;
+
+    pub fn read(handle: HANDLE, address: usize) -> Result<Self, anyhow::Error> {
+        let invocation_list = TArrayHeader::read(handle, address)?;
+        let num = invocation_list.num;
+        if !(0..=Self::MAX_BINDINGS).contains(&num) || num > invocation_list.max {
+            return Err(anyhow::anyhow!(
+                "Invalid delegate invocation list at 0x{:X} (num {}, max {})",
+                address,
+                num,
+                invocation_list.max
+            ));
+        }
+        Ok(Self { invocation_list })
+    }
+
+    /// InvocationList の FScriptDelegate を読み取る（未バインドの要素は除く）
+    pub fn delegates(&self, handle: HANDLE) -> Result<Vec<FScriptDelegate>, anyhow::Error> {
+        let count = self.invocation_list.num as usize;
+        if count == 0 || self.invocation_list.data == 0 {
+            return Ok(Vec::new());
+        }
+
+        let data = read_process_memory(handle, self.invocation_list.data, count * FScriptDelegate::SIZE)?;
+        Ok(data
+            .chunks_exact(FScriptDelegate::SIZE)
+            .map(FScriptDelegate::from_bytes)
+            .filter(FScriptDelegate::is_bound)
+            .collect())
+    }
+}
+
 /// FNameEntry - GNames の要素 (UE4.23+)
 #[repr(C)]
 pub struct FNameEntry {
@@ -98,6 +287,15 @@ impl FNameEntryAllocator {
     pub const BLOCK_OFFSET_BITS: u32 = 16;
     pub const BLOCK_OFFSETS: u32 = 1 << Self::BLOCK_OFFSET_BITS;  // 65536
     pub const STRIDE: usize = 2;  // alignof(FNameEntry)
+    /// 1ブロックのバイト数 (BLOCK_OFFSETS * STRIDE = 128KB)
+    pub const BLOCK_SIZE_BYTES: usize = Self::BLOCK_OFFSETS as usize * Self::STRIDE;
+    /// Blocks 配列の要素数
+    pub const MAX_BLOCKS: u32 = 8192;
+
+    /// FNamePool 先頭からの各メンバーのオフセット (Lock = SRWLOCK 8 bytes の後)
+    pub const CURRENT_BLOCK_OFFSET: usize = 8;
+    pub const CURRENT_BYTE_CURSOR_OFFSET: usize = 12;
+    pub const BLOCKS_OFFSET: usize = 16;
 
     /// FNameEntryId から Block と Offset を取得
     pub fn decode_id(id: u32) -> (u32, u32) {
@@ -177,6 +375,96 @@ impl FUObjectItem {
     pub fn is_valid(&self) -> bool {
         self.object != 0 && (self.flags & 1) == 0 // RF_NoFlags
     }
+
+    /// is_valid より厳しい検証（ゴミポインタの誤検出を減らす）
+    ///
+    /// - object がヒープ (`heap` はアドレス順のコミット済みプライベート領域) かモジュールイメージ内にある
+    /// - object 先頭の vtable がモジュール内を指し、その先頭エントリが実行可能セクション内にある
+    /// - flags の下位 8 ビットのうち立っているのは 4 ビット以下（0xFFFFFFFF 等を除外）
+    pub fn is_valid_strict(&self, handle: HANDLE, modules: &ModuleRanges, heap: &[(usize, usize)]) -> bool {
+        if !self.is_valid() || (self.flags as u32 & 0xFF).count_ones() > 4 {
+            return false;
+        }
+
+        if !in_ranges(heap, self.object) && !modules.in_image(self.object) {
+            return false;
+        }
+
+        read_ptr(handle, self.object).is_ok_and(|vtable| modules.is_vtable(handle, vtable))
+    }
+}
+
+/// ロード済みモジュールのイメージ範囲と実行可能セクション
+#[derive(Clone, Debug, Default)]
+pub struct ModuleRanges {
+    pub images: Vec<(usize, usize)>,
+    pub code: Vec<(usize, usize)>,
+    /// 検証済み vtable（同じクラスのオブジェクトは vtable を共有するので 1 回だけ読む）
+    vtables: RefCell<HashMap<usize, bool>>,
+}
+
+impl ModuleRanges {
+    /// 各モジュールの PE セクションヘッダから実行可能セクションを取得
+    /// （ヘッダが読めない場合はイメージ全体を実行可能とみなす）
+    pub fn read(handle: HANDLE, modules: &[ModuleInfo]) -> Self {
+        let mut images = Vec::with_capacity(modules.len());
+        let mut code = Vec::new();
+
+        for module in modules {
+            let image = (module.base_address, module.base_address + module.size);
+            images.push(image);
+
+            let sections = crate::platform::module::list_sections(handle, module.base_address).unwrap_or_default();
+            let before = code.len();
+            code.extend(sections.iter().filter(|s| s.executable).map(|s| (s.address, s.address + s.size)));
+            if code.len() == before {
+                code.push(image);
+            }
+        }
+
+        Self::new(images, code)
+    }
+
+    pub fn new(mut images: Vec<(usize, usize)>, mut code: Vec<(usize, usize)>) -> Self {
+        images.sort_unstable();
+        code.sort_unstable();
+        Self { images, code, vtables: RefCell::default() }
+    }
+
+    pub fn in_image(&self, address: usize) -> bool {
+        in_ranges(&self.images, address)
+    }
+
+    pub fn in_code(&self, address: usize) -> bool {
+        in_ranges(&self.code, address)
+    }
+
+    /// vtable がモジュール内にあり、先頭の仮想関数が実行可能セクションを指しているか
+    ///
+    /// vtable 自体は .rdata にあるので、実行可能かは先頭エントリで判定する
+    pub fn is_vtable(&self, handle: HANDLE, vtable: usize) -> bool {
+        if !self.in_image(vtable) {
+            return false;
+        }
+        if let Some(&valid) = self.vtables.borrow().get(&vtable) {
+            return valid;
+        }
+
+        let valid = read_ptr(handle, vtable).is_ok_and(|entry| self.in_code(entry));
+        self.vtables.borrow_mut().insert(vtable, valid);
+        valid
+    }
+}
+
+/// アドレス順に並んだ重ならない [start, end) 範囲のどれかに含まれるか
+fn in_ranges(ranges: &[(usize, usize)], address: usize) -> bool {
+    let index = ranges.partition_point(|&(start, _)| start <= address);
+    index > 0 && address < ranges[index - 1].1
+}
+
+fn read_ptr(handle: HANDLE, address: usize) -> Result<usize, anyhow::Error> {
+    let data = read_process_memory(handle, address, 8)?;
+    Ok(usize::from_le_bytes(data[..8].try_into().unwrap()))
 }
 
 /// FChunkedFixedUObjectArray - UE5のチャンク配列
@@ -273,10 +561,20 @@ impl FUObjectArray {
 
     /// インデックスから UObject のアドレスを取得
     pub fn get_object_address(&self, handle: HANDLE, index: i32) -> Result<usize, anyhow::Error> {
+        self.get_object_address_with(handle, index, &FUObjectItem::is_valid)
+    }
+
+    /// インデックスから UObject のアドレスを取得（検証関数を指定）
+    pub fn get_object_address_with(
+        &self,
+        handle: HANDLE,
+        index: i32,
+        accept: &dyn Fn(&FUObjectItem) -> bool,
+    ) -> Result<usize, anyhow::Error> {
         let item_addr = self.obj_objects.get_object_item_address(handle, index)?;
         let item = FUObjectItem::read(handle, item_addr)?;
 
-        if !item.is_valid() {
+        if !accept(&item) {
             return Err(anyhow::anyhow!("Invalid object at index {}", index));
         }
 
@@ -285,10 +583,15 @@ impl FUObjectArray {
 
     /// すべての有効な UObject のアドレスを取得
     pub fn get_all_objects(&self, handle: HANDLE) -> Vec<usize> {
+        self.get_all_objects_with(handle, &FUObjectItem::is_valid)
+    }
+
+    /// accept を満たす UObject のアドレスを取得
+    pub fn get_all_objects_with(&self, handle: HANDLE, accept: &dyn Fn(&FUObjectItem) -> bool) -> Vec<usize> {
         let mut objects = Vec::new();
 
         for i in 0..self.obj_objects.num_elements {
-            if let Ok(addr) = self.get_object_address(handle, i) {
+            if let Ok(addr) = self.get_object_address_with(handle, i, accept) {
                 objects.push(addr);
             }
         }
@@ -331,43 +634,10 @@ impl UStruct {
     /// - UField::Next: 8 bytes → total 48 bytes
     /// - FStructBaseChain (条件付き): StructBaseChainArray(8) + NumStructBasesInChainMinusOne(4) + padding(4) = 16 bytes → total 64 bytes
     /// - SuperStruct, Children, ChildProperties, PropertiesSize, MinAlignment
-    pub fn read(handle: HANDLE, address: usize) -> Result<Self, anyhow::Error> {
-        // 複数のオフセットを試す
-        // FStructBaseChain が有効な場合: 64
-        // FStructBaseChain が無効な場合: 48
-        for offset in [64usize, 48, 56, 72] {
-            if let Ok(data) = read_process_memory(handle, address + offset, 32) {
-                let super_struct = usize::from_le_bytes(data[0..8].try_into().unwrap());
-                let children = usize::from_le_bytes(data[8..16].try_into().unwrap());
-                let child_properties = usize::from_le_bytes(data[16..24].try_into().unwrap());
-                let properties_size = i32::from_le_bytes(data[24..28].try_into().unwrap());
-                let min_alignment = i32::from_le_bytes(data[28..32].try_into().unwrap());
-
-                // 妥当性チェック: properties_size と min_alignment が合理的な値か
-                // min_alignment は 0 の場合もある（デフォルト/未設定）
-                if properties_size >= 0 && properties_size < 0x100000
-                    && min_alignment >= 0 && min_alignment <= 16
-                {
-                    // super_struct がヒープポインタっぽいか、0か
-                    let super_valid = super_struct == 0
-                        || (super_struct > 0x10000 && super_struct < 0x7FFFFFFFFFFF);
-
-                    if super_valid {
-                        return Ok(Self {
-                            super_struct,
-                            children,
-                            child_properties,
-                            properties_size,
-                            min_alignment,
-                        });
-                    }
-                }
-            }
-        }
-
-        // フォールバック: オフセット 48 を使用
-        let offset = 48;
-        let data = read_process_memory(handle, address + offset, 32)?;
+    ///
+    /// `base_offset` は detect_base_offset で求めた SuperStruct の位置
+    pub fn read(handle: HANDLE, address: usize, base_offset: usize) -> Result<Self, anyhow::Error> {
+        let data = read_process_memory(handle, address + base_offset, 32)?;
         Ok(Self {
             super_struct: usize::from_le_bytes(data[0..8].try_into().unwrap()),
             children: usize::from_le_bytes(data[8..16].try_into().unwrap()),
@@ -376,6 +646,34 @@ impl UStruct {
             min_alignment: i32::from_le_bytes(data[28..32].try_into().unwrap()),
         })
     }
+
+    /// FStructBaseChain なしのときの SuperStruct オフセット
+    pub const BASE_OFFSET_NO_CHAIN: usize = 48;
+    /// FStructBaseChain ありのときの SuperStruct オフセット
+    pub const BASE_OFFSET_WITH_CHAIN: usize = 64;
+
+    /// FStructBaseChain の有無を調べて UStruct フィールドの開始オフセットを返す
+    ///
+    /// +48 が StructBaseChainArray（ヒープポインタ）で、+56 の
+    /// NumStructBasesInChainMinusOne が 0..64 の範囲なら FStructBaseChain ありとみなす。
+    /// チェーンなしの場合 +56 は Children ポインタなので下位 32bit が小さい値になることはまずない。
+    /// ただし Children が null の UStruct はチェーンありの深さ 0 と区別できないので判定しない。
+    /// 読み取りに失敗した場合と判定できない場合は None
+    pub fn detect_base_offset(handle: HANDLE, address: usize) -> Option<usize> {
+        let data = read_process_memory(handle, address + Self::BASE_OFFSET_NO_CHAIN, 16).ok()?;
+        let chain_array = usize::from_le_bytes(data[0..8].try_into().unwrap());
+        let depth = i32::from_le_bytes(data[8..12].try_into().unwrap());
+        if usize::from_le_bytes(data[8..16].try_into().unwrap()) == 0 {
+            return None;
+        }
+
+        let is_heap_pointer = chain_array > 0x10000 && chain_array < 0x7FFFFFFFFFFF && chain_array % 8 == 0;
+        if is_heap_pointer && (0..64).contains(&depth) {
+            Some(Self::BASE_OFFSET_WITH_CHAIN)
+        } else {
+            Some(Self::BASE_OFFSET_NO_CHAIN)
+        }
+    }
 }
 
 /// FField - UE5 の新しいプロパティ基底クラス (UObject を継承しない)
@@ -423,6 +721,58 @@ impl FField {
     }
 }
 
+/// FProperty - FField を継承したプロパティ
+///
+/// FField (48 bytes) の直後に以下が続く:
+/// - ArrayDim (4 bytes) - offset 48
+/// - ElementSize (4 bytes) - offset 52
+/// - PropertyFlags (8 bytes) - EPropertyFlags - offset 56
+/// - RepIndex (2 bytes) + BlueprintReplicationCondition (1 byte) + padding - offset 64
+/// - Offset_Internal (4 bytes) - offset 68
+#[repr(C)]
+#[derive(Debug)]
+pub struct FProperty {
+    pub array_dim: i32,
+    pub element_size: i32,
+    pub property_flags: u64,
+}
+
+impl FProperty {
+    pub const ARRAY_DIM_OFFSET: usize = 48;
+
+    /// Offset_Internal の候補位置（先頭ほど優先、同点なら先に並んだものを採用）
+    /// 通常は 68 だが、ビルド設定で FField / FProperty のサイズが変わる
+    pub const OFFSET_INTERNAL_CANDIDATES: [usize; 7] = [68, 64, 72, 76, 60, 56, 80];
+
+    /// EPropertyFlags
+    pub const CPF_PARM: u64 = 0x80;
+    pub const CPF_OUT_PARM: u64 = 0x100;
+    pub const CPF_RETURN_PARM: u64 = 0x400;
+
+    pub fn read(handle: HANDLE, address: usize) -> Result<Self, anyhow::Error> {
+        let data = read_process_memory(handle, address + Self::ARRAY_DIM_OFFSET, 16)?;
+        Ok(Self {
+            array_dim: i32::from_le_bytes(data[0..4].try_into().unwrap()),
+            element_size: i32::from_le_bytes(data[4..8].try_into().unwrap()),
+            property_flags: u64::from_le_bytes(data[8..16].try_into().unwrap()),
+        })
+    }
+
+    /// 関数のパラメータ (戻り値を含む) か
+    pub fn is_param(&self) -> bool {
+        (self.property_flags & Self::CPF_PARM) != 0
+    }
+
+    pub fn is_return_param(&self) -> bool {
+        (self.property_flags & Self::CPF_RETURN_PARM) != 0
+    }
+
+    /// プロパティが占めるバイト数 (ElementSize * ArrayDim)
+    pub fn total_size(&self) -> usize {
+        self.element_size.max(0) as usize * self.array_dim.max(1) as usize
+    }
+}
+
 /// UFunction - 関数情報
 #[repr(C)]
 pub struct UFunction {
@@ -437,12 +787,285 @@ pub struct UFunction {
 }
 
 impl UFunction {
+    /// EFunctionFlags
+    pub const FUNC_NET: u32 = 0x00000040;
+    pub const FUNC_EXEC: u32 = 0x00000200;
+    pub const FUNC_NATIVE: u32 = 0x00000400;
+    pub const FUNC_EVENT: u32 = 0x00000800;
+    pub const FUNC_STATIC: u32 = 0x00002000;
+    pub const FUNC_BLUEPRINT_CALLABLE: u32 = 0x04000000;
+    pub const FUNC_BLUEPRINT_EVENT: u32 = 0x08000000;
+    pub const FUNC_BLUEPRINT_PURE: u32 = 0x10000000;
+    pub const FUNC_CONST: u32 = 0x40000000;
+
+    /// 表示用のフラグ名 (flags_summary の順序)
+    const FLAG_NAMES: [(u32, &'static str); 9] = [
+        (Self::FUNC_NATIVE, "Native"),
+        (Self::FUNC_BLUEPRINT_CALLABLE, "BlueprintCallable"),
+        (Self::FUNC_BLUEPRINT_EVENT, "BlueprintEvent"),
+        (Self::FUNC_BLUEPRINT_PURE, "BlueprintPure"),
+        (Self::FUNC_STATIC, "Static"),
+        (Self::FUNC_EVENT, "Event"),
+        (Self::FUNC_NET, "Net"),
+        (Self::FUNC_EXEC, "Exec"),
+        (Self::FUNC_CONST, "Const"),
+    ];
+
+    /// FunctionFlags を "[Native][BlueprintCallable][Static]" 形式にする
+    pub fn flags_summary(function_flags: u32) -> String {
+        Self::FLAG_NAMES
+            .iter()
+            .filter(|(flag, _)| function_flags & flag != 0)
+            .map(|(_, name)| format!("[{}]", name))
+            .collect()
+    }
+
     /// UFunction の flag をチェック
     pub fn is_native(&self) -> bool {
-        (self.function_flags & 0x00000400) != 0 // FUNC_Native
+        (self.function_flags & Self::FUNC_NATIVE) != 0
     }
 
     pub fn is_blueprint_callable(&self) -> bool {
-        (self.function_flags & 0x00000001) != 0 // FUNC_BlueprintCallable
+        (self.function_flags & Self::FUNC_BLUEPRINT_CALLABLE) != 0
+    }
+}
+
+/// FMapProperty - TMap 型のプロパティ
+///
+/// FProperty の直後に KeyProp (FProperty*) と ValueProp (FProperty*) が並ぶ。
+/// FProperty のサイズはビルド設定で変わるため、KeyProp の位置は候補から検証して決める
+pub struct FMapProperty;
+
+impl FMapProperty {
+    /// KeyProp の位置の候補（ValueProp はその 8 バイト後）
+    pub const KEY_PROP_CANDIDATES: [usize; 3] = [0x70, 0x78, 0x80];
+}
+
+/// FStructProperty (UStructProperty) - 構造体型のプロパティ
+///
+/// FProperty (4.25 未満は UProperty) の直後に Struct (UScriptStruct*) がある。
+/// 位置は FMapProperty と同じくビルド設定で変わるので候補から検証して決める
+pub struct FStructProperty;
+
+impl FStructProperty {
+    /// Struct の位置の候補
+    pub const STRUCT_CANDIDATES: [usize; 3] = [0x70, 0x78, 0x80];
+}
+
+/// TMap の要素 (キーの生バイト, 値の生バイト)
+pub type TMapPair = (Vec<u8>, Vec<u8>);
+
+/// TMap のキー / 値の型のサイズとアラインメント
+///
+/// アラインメントはサイズから決まらない (FName は 8 バイトだが 4 バイト境界) ので、
+/// プロパティの型または UScriptStruct::MinAlignment から与える
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct TMapElementType {
+    pub size: usize,
+    pub align: usize,
+}
+
+impl TMapElementType {
+    pub fn new(size: usize, align: usize) -> Self {
+        Self { size, align: align.max(1) }
+    }
+}
+
+/// TMap<K, V> のヘッダー (= TSet<TPair<K, V>>)
+///
+/// レイアウト (80 bytes):
+/// - Elements.Data (8 bytes) - offset 0 - TSetElement の配列
+/// - Elements.Num (4 bytes) - offset 8 - 使用中インデックスの上限 (SetMaxIndex)
+/// - Elements.Max (4 bytes) - offset 12
+/// - AllocationFlags.InlineData (16 bytes) - offset 16 - 128 要素までの割り当てビット
+/// - AllocationFlags.SecondaryData (8 bytes) - offset 32 - それを超える場合のビット配列
+/// - AllocationFlags.NumBits / MaxBits (4 + 4 bytes) - offset 40
+/// - FirstFreeIndex / NumFreeIndices (4 + 4 bytes) - offset 48
+/// - Hash, HashSize - offset 56
+#[derive(Clone, Debug)]
+pub struct TMapHeader {
+    pub elements: usize,
+    pub set_max_index: i32,
+    /// 有効な要素数 (SetMaxIndex - NumFreeIndices)
+    pub set_num: i32,
+    inline_flags: [u8; 16],
+    secondary_flags: usize,
+}
+
+impl TMapHeader {
+    pub const SIZE: usize = 80;
+
+    /// 読み取る要素数の上限（壊れたヘッダーで巨大な読み込みをしないように）
+    const MAX_ELEMENTS: i32 = 0x10000;
+
+    /// InlineData に収まるビット数
+    const INLINE_BITS: usize = 128;
+
+    pub fn read(handle: HANDLE, address: usize) -> Result<Self, anyhow::Error> {
+        let data = read_process_memory(handle, address, 56)?;
+        let i32_at = |offset: usize| i32::from_le_bytes(data[offset..offset + 4].try_into().unwrap());
+
+        let set_max_index = i32_at(8);
+        let num_free_indices = i32_at(52);
+        if !(0..=Self::MAX_ELEMENTS).contains(&set_max_index) || !(0..=set_max_index).contains(&num_free_indices) {
+            return Err(anyhow::anyhow!(
+                "Invalid TMap header at 0x{:X} (num {}, free {})",
+                address,
+                set_max_index,
+                num_free_indices
+            ));
+        }
+
+        Ok(Self {
+            elements: usize::from_le_bytes(data[0..8].try_into().unwrap()),
+            set_max_index,
+            set_num: set_max_index - num_free_indices,
+            inline_flags: data[16..32].try_into().unwrap(),
+            secondary_flags: usize::from_le_bytes(data[32..40].try_into().unwrap()),
+        })
+    }
+
+    /// 要素配列を辿り、割り当てビットが立っている (削除済みでない) 要素の (キー, 値) を返す
+    pub fn iter_pairs(
+        &self,
+        handle: HANDLE,
+        key: TMapElementType,
+        value: TMapElementType,
+    ) -> Result<Vec<TMapPair>, anyhow::Error> {
+        let count = self.set_max_index as usize;
+        if count == 0 || self.elements == 0 {
+            return Ok(Vec::new());
+        }
+
+        let flags = if self.secondary_flags != 0 {
+            read_process_memory(handle, self.secondary_flags, count.div_ceil(32) * 4)?
+        } else if count <= Self::INLINE_BITS {
+            self.inline_flags.to_vec()
+        } else {
+            return Err(anyhow::anyhow!("TMap allocation flags missing for {} elements", count));
+        };
+
+        let (value_offset, stride) = Self::element_layout(key, value);
+        let data = read_process_memory(handle, self.elements, count * stride)?;
+
+        let pairs = (0..count)
+            .filter(|&index| flags[index / 8] & (1 << (index % 8)) != 0)
+            .map(|index| {
+                let element = &data[index * stride..];
+                (
+                    element[..key.size].to_vec(),
+                    element[value_offset..value_offset + value.size].to_vec(),
+                )
+            })
+            .collect();
+        Ok(pairs)
+    }
+
+    /// TSetElement<TPair<K, V>> の (値のオフセット, 要素サイズ)
+    ///
+    /// TPair の後に HashNextId (4 bytes) と HashIndex (4 bytes) が続き、
+    /// 削除済み要素の FreeListLink (8 bytes) と共用体になっている
+    fn element_layout(key: TMapElementType, value: TMapElementType) -> (usize, usize) {
+        let pair_align = key.align.max(value.align);
+
+        let value_offset = key.size.next_multiple_of(value.align);
+        let pair_size = (value_offset + value.size).next_multiple_of(pair_align);
+        let stride = (pair_size + 8).next_multiple_of(pair_align.max(4));
+        (value_offset, stride)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn fake_virtual_function() {}
+
+    static FAKE_VTABLE: [fn(); 1] = [fake_virtual_function];
+
+    #[test]
+    fn test_uobject_item_is_valid_strict() {
+        use windows::Win32::System::Threading::GetCurrentProcess;
+
+        let vtable = FAKE_VTABLE.as_ptr() as usize;
+        let function = fake_virtual_function as fn() as usize;
+        let modules = ModuleRanges::new(vec![(vtable, vtable + 8)], vec![(function, function + 1)]);
+
+        let object = Box::new(vtable);
+        let object_addr = &*object as *const usize as usize;
+        let heap = [(object_addr, object_addr + 8)];
+
+        let handle = unsafe { GetCurrentProcess() };
+        let item = |object: usize, flags: i32| FUObjectItem {
+            object,
+            flags,
+            cluster_root_index: 0,
+            serial_number: 0,
+            ref_count: 0,
+        };
+
+        assert!(item(object_addr, 0x10).is_valid_strict(handle, &modules, &heap));
+        // 下位 8 ビットが立ちすぎているフラグ
+        assert!(!item(object_addr, -2).is_valid_strict(handle, &modules, &heap));
+        // ヒープにもモジュールにもないオブジェクト
+        assert!(!item(object_addr, 0).is_valid_strict(handle, &modules, &heap[..0]));
+
+        // 先頭の仮想関数が実行可能セクション外を指す vtable
+        let no_code = ModuleRanges::new(vec![(vtable, vtable + 8)], Vec::new());
+        assert!(!item(object_addr, 0).is_valid_strict(handle, &no_code, &heap));
+    }
+
+    #[test]
+    fn test_tmap_element_layout() {
+        // TMap<FName, int32>: FName は 8 バイトだが 4 バイト境界
+        let name = TMapElementType::new(8, 4);
+        let int32 = TMapElementType::new(4, 4);
+        assert_eq!(TMapHeader::element_layout(name, int32), (8, 20));
+
+        // TMap<uint8, uint64>: 値の前にパディングが入る
+        let byte = TMapElementType::new(1, 1);
+        let uint64 = TMapElementType::new(8, 8);
+        assert_eq!(TMapHeader::element_layout(byte, uint64), (8, 24));
+
+        // TMap<FString, uint8>: TPair の末尾が 8 バイト境界まで埋まる
+        let string = TMapElementType::new(16, 8);
+        assert_eq!(TMapHeader::element_layout(string, byte), (16, 32));
+    }
+
+    #[test]
+    fn test_tmap_iter_pairs_skips_free_elements() {
+        use windows::Win32::System::Threading::GetCurrentProcess;
+
+        let name = TMapElementType::new(8, 4);
+        let int32 = TMapElementType::new(4, 4);
+        let (value_offset, stride) = TMapHeader::element_layout(name, int32);
+
+        let mut elements = vec![0u8; stride * 3];
+        for index in 0..3 {
+            let element = &mut elements[index * stride..];
+            element[..8].copy_from_slice(&(index as u64 + 1).to_le_bytes());
+            element[value_offset..value_offset + 4].copy_from_slice(&(index as i32 * 100).to_le_bytes());
+        }
+
+        // 要素 1 は削除済み
+        let mut inline_flags = [0u8; 16];
+        inline_flags[0] = 0b101;
+        let header = TMapHeader {
+            elements: elements.as_ptr() as usize,
+            set_max_index: 3,
+            set_num: 2,
+            inline_flags,
+            secondary_flags: 0,
+        };
+
+        let handle = unsafe { GetCurrentProcess() };
+        let pairs = header.iter_pairs(handle, name, int32).expect("Failed to read pairs");
+        assert_eq!(
+            pairs,
+            vec![
+                (1u64.to_le_bytes().to_vec(), 0i32.to_le_bytes().to_vec()),
+                (3u64.to_le_bytes().to_vec(), 200i32.to_le_bytes().to_vec()),
+            ]
+        );
     }
 }
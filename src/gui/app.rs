@@ -1,40 +1,273 @@
 use crate::gui::{
-    engine_view::EngineView, process_list::ProcessListView, results_view::ResultsView,
-    scan_view::ScanView,
+    cheat_table::{CheatEngineImporter, CheatTable, DEFAULT_CHEAT_TABLE_PATH},
+    engine_view::{BackendChoice, EngineView}, memory_view::MemoryView, module_list_view::ModuleListView,
+    pointer_scan_view::PointerScanView, process_list::ProcessListView,
+    region_map::RegionMapView, results_view::ResultsView, scan_view::ScanView, thread_list::ThreadListView,
 };
+use crate::engine::{AutoEngineDetector, DetectedEngine, PluginRegistry};
+use crate::platform::elevation::IntegrityLevel;
 use crate::platform::ProcessInfo;
-use crate::scanner::{Process, Scanner};
-use crate::types::{ScanOptions, ScanValue, ValueType};
+use crate::scanner::{
+    load_session, save_session, FreezeHandle, Process, ScanError, ScanSession, Scanner, WatchlistEntry,
+};
+use crate::types::{Endianness, ScanOptions, ScanProgress, ScanResults, ScanType, ScanValue};
 use eframe::egui;
+use std::path::Path;
+use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::{Arc, Mutex};
+use std::thread::JoinHandle;
+use std::time::{Duration, Instant};
+
+/// Default file name for saved sessions
+const DEFAULT_SESSION_PATH: &str = "lightscan_session.json";
+
+/// How often the selected process is checked for having exited (see try_reattach)
+const REATTACH_CHECK_INTERVAL: Duration = Duration::from_secs(2);
+
+/// Pending session or cheat table file dialog
+#[derive(PartialEq)]
+enum SessionDialog {
+    Save,
+    Load,
+    ExportCheatTable,
+    ImportCheatTable,
+    /// Cheat Engine `.CT` address list
+    ImportCheatEngineTable,
+}
+
+/// First scan running on a background thread
+struct ScanTask {
+    /// Kept results, the total number of matches, and the timeout if the scan hit it
+    thread: JoinHandle<anyhow::Result<(ScanResults, usize, Option<ScanError>)>>,
+    /// Region filter of the scan, handed to the session scanner for next scans
+    options: ScanOptions,
+    progress: Arc<Mutex<ScanProgress>>,
+    cancel: Arc<AtomicBool>,
+    /// Parameters of the scan, recorded in the history once it finishes
+    scan_type: ScanType,
+    value: Option<ScanValue>,
+}
+
+/// Formats a byte count as MB or GB for status messages
+fn format_bytes(bytes: u64) -> String {
+    const MB: f64 = 1024.0 * 1024.0;
+    let mb = bytes as f64 / MB;
+    if mb >= 1024.0 {
+        format!("{:.1} GB", mb / 1024.0)
+    } else {
+        format!("{:.1} MB", mb)
+    }
+}
+
+/// Clears the frozen values of all watchlist entries and returns how many were frozen
+///
+/// Frozen addresses and values belong to the process they were found in and
+/// must not be written into a different one.
+fn unfreeze_watchlist(watchlist: &mut [WatchlistEntry]) -> usize {
+    watchlist
+        .iter_mut()
+        .filter_map(|entry| entry.frozen_value.take())
+        .count()
+}
+
+/// A completed first or next scan, shown in the scan history panel
+struct ScanHistoryEntry {
+    scan_type: ScanType,
+    /// None for scans that compare against previous values
+    value: Option<ScanValue>,
+    result_count: usize,
+    timestamp: Instant,
+}
+
+impl ScanHistoryEntry {
+    fn new(scan_type: ScanType, value: Option<ScanValue>, result_count: usize) -> Self {
+        Self {
+            scan_type,
+            value,
+            result_count,
+            timestamp: Instant::now(),
+        }
+    }
+
+    /// One-line summary, e.g. "Scan 1: Exact Value 100 → 4523 results"
+    fn summary(&self, index: usize) -> String {
+        match &self.value {
+            Some(value) => format!(
+                "Scan {}: {} {} → {} results",
+                index + 1,
+                self.scan_type,
+                value,
+                self.result_count
+            ),
+            None => format!("Scan {}: {} → {} results", index + 1, self.scan_type, self.result_count),
+        }
+    }
+}
+
+/// Engine detection running on a background thread after a process is selected
+struct EngineDetectTask {
+    /// Session and PID the detection was started for; stale results are discarded
+    session_id: u64,
+    pid: u32,
+    thread: JoinHandle<crate::engine::Result<DetectedEngine>>,
+}
 
 /// Main application tabs
 #[derive(PartialEq)]
 enum AppTab {
     MemoryScan,
+    Memory,
+    PointerScan,
     Engine,
 }
 
-/// Main application state
-pub struct LightScanApp {
-    // Process management
-    process_list_view: ProcessListView,
+/// One scan session tab: a process with its own scanner, scan state and watchlist
+struct SessionTab {
+    /// Unique for the lifetime of the app, so tasks can find their tab after tabs are closed
+    id: u64,
     selected_process: Option<ProcessInfo>,
     scanner: Option<Arc<Mutex<Scanner>>>,
 
     // Scanning
     scan_view: ScanView,
     results_view: ResultsView,
+    scan_address_range: Option<(usize, usize)>,
+    scan_task: Option<ScanTask>,
+    /// When the running (or last) scan started, for the elapsed time in the status bar
+    scan_start_time: Option<Instant>,
+    /// First and next scans since the last reset, oldest first
+    scan_history: Vec<ScanHistoryEntry>,
+
+    // Watchlist
+    watchlist: Arc<Mutex<Vec<WatchlistEntry>>>,
+    /// `Scanner::freeze_value` locks for the frozen watchlist entries
+    frozen_addresses: Vec<FreezeHandle>,
+
+    /// Results loaded from a session before a process was selected
+    loaded_results: Option<ScanResults>,
+}
+
+impl SessionTab {
+    fn new(id: u64) -> Self {
+        Self {
+            id,
+            selected_process: None,
+            scanner: None,
+            scan_view: ScanView::default(),
+            results_view: ResultsView::default(),
+            scan_address_range: None,
+            scan_task: None,
+            scan_start_time: None,
+            scan_history: Vec::new(),
+            watchlist: Arc::new(Mutex::new(Vec::new())),
+            frozen_addresses: Vec::new(),
+            loaded_results: None,
+        }
+    }
+
+    /// Tab label: the process name, or a placeholder before one is selected
+    fn title(&self, index: usize) -> String {
+        match &self.selected_process {
+            Some(process) => format!("{} ({})", process.name, process.pid),
+            None => format!("Session {}", index + 1),
+        }
+    }
+
+    /// Starts and stops value locks so they match the frozen watchlist entries
+    fn sync_frozen_addresses(&mut self) {
+        let frozen: Vec<(usize, ScanValue, Endianness)> = match self.watchlist.lock() {
+            Ok(entries) => entries
+                .iter()
+                .filter_map(|e| Some((e.address, e.frozen_value.clone()?, e.endianness)))
+                .collect(),
+            Err(_) => return,
+        };
+
+        // Unfrozen, removed or re-frozen at another value or byte order
+        self.frozen_addresses.retain(|lock| {
+            frozen.iter().any(|(address, value, endianness)| {
+                *address == lock.address() && value == lock.value() && *endianness == lock.endianness()
+            })
+        });
+
+        let missing: Vec<(usize, ScanValue, Endianness)> = frozen
+            .into_iter()
+            .filter(|(address, _, _)| !self.frozen_addresses.iter().any(|lock| lock.address() == *address))
+            .collect();
+        if missing.is_empty() {
+            return;
+        }
+
+        // A running scan holds the scanner; retry on a later frame
+        let Some(Ok(scanner)) = self.scanner.as_ref().map(|s| s.try_lock()) else {
+            return;
+        };
+        for (address, value, endianness) in missing {
+            match scanner.freeze_value_endian(address, value, endianness) {
+                Ok(lock) => self.frozen_addresses.push(lock),
+                Err(e) => tracing::warn!("Failed to freeze 0x{:X}: {}", address, e),
+            }
+        }
+    }
+
+    /// Cancels a running scan and discards its results
+    fn cancel_scan_task(&mut self) {
+        if let Some(task) = self.scan_task.take() {
+            task.cancel.store(true, Ordering::Relaxed);
+            let _ = task.thread.join();
+        }
+    }
+}
+
+impl Drop for SessionTab {
+    fn drop(&mut self) {
+        self.cancel_scan_task();
+        self.frozen_addresses.clear();
+    }
+}
+
+/// Main application state
+pub struct LightScanApp {
+    // Process management
+    process_list_view: ProcessListView,
+    thread_list_view: ThreadListView,
+    module_list_view: ModuleListView,
+    region_map_view: RegionMapView,
+
+    // Scan sessions (always at least one)
+    sessions: Vec<SessionTab>,
+    active_session: usize,
+    next_session_id: u64,
+
+    engine_detect_task: Option<EngineDetectTask>,
+    /// Engine detected for the selected process, shown as a badge on the Engine tab
+    detected_engine: Option<DetectedEngine>,
+    /// Session whose process handle the engine backend uses
+    engine_session: Option<u64>,
+
+    // Memory viewer
+    memory_view: MemoryView,
+
+    // Pointer scanner
+    pointer_scan_view: PointerScanView,
 
     // Engine abstraction
     engine_view: EngineView,
 
+    // Session save/load
+    session_dialog: Option<SessionDialog>,
+    session_path: String,
+    cheat_table_path: String,
+
     // UI state
     current_tab: AppTab,
     show_process_list: bool,
     error_message: Option<String>,
     status_message: String,
     is_elevated: bool,
+    /// Integrity level of LightScan itself
+    integrity_level: Option<IntegrityLevel>,
+    last_reattach_check: Instant,
 }
 
 impl Default for LightScanApp {
@@ -43,11 +276,21 @@ impl Default for LightScanApp {
 
         Self {
             process_list_view: ProcessListView::default(),
-            selected_process: None,
-            scanner: None,
-            scan_view: ScanView::default(),
-            results_view: ResultsView::default(),
+            thread_list_view: ThreadListView::default(),
+            module_list_view: ModuleListView::default(),
+            region_map_view: RegionMapView::default(),
+            sessions: vec![SessionTab::new(0)],
+            active_session: 0,
+            next_session_id: 1,
+            engine_detect_task: None,
+            detected_engine: None,
+            engine_session: None,
+            memory_view: MemoryView::default(),
+            pointer_scan_view: PointerScanView::default(),
             engine_view: EngineView::default(),
+            session_dialog: None,
+            session_path: DEFAULT_SESSION_PATH.to_string(),
+            cheat_table_path: DEFAULT_CHEAT_TABLE_PATH.to_string(),
             current_tab: AppTab::MemoryScan,
             show_process_list: false,
             error_message: None,
@@ -57,6 +300,8 @@ impl Default for LightScanApp {
                 "Running without administrator privileges. Some processes may be inaccessible.".to_string()
             },
             is_elevated,
+            integrity_level: crate::platform::elevation::get_integrity_level().ok(),
+            last_reattach_check: Instant::now(),
         }
     }
 }
@@ -66,92 +311,717 @@ impl LightScanApp {
         Self::default()
     }
 
+    fn session(&self) -> &SessionTab {
+        &self.sessions[self.active_session]
+    }
+
+    fn session_mut(&mut self) -> &mut SessionTab {
+        &mut self.sessions[self.active_session]
+    }
+
+    /// Opens a new empty session tab and switches to it
+    fn new_session(&mut self) {
+        self.sessions.push(SessionTab::new(self.next_session_id));
+        self.next_session_id += 1;
+        self.active_session = self.sessions.len() - 1;
+    }
+
+    /// Closes a session tab, dropping its process handle
+    ///
+    /// The engine backend borrows the handle of its session, so it is detached first.
+    fn close_session(&mut self, index: usize) {
+        let id = self.sessions[index].id;
+        if self.engine_session == Some(id) {
+            self.engine_view.clear_engine();
+            self.detected_engine = None;
+            self.engine_session = None;
+        }
+        if self.engine_detect_task.as_ref().is_some_and(|task| task.session_id == id) {
+            self.engine_detect_task = None;
+        }
+
+        self.sessions.remove(index);
+        if self.sessions.is_empty() {
+            self.new_session();
+        }
+        if self.active_session >= index && self.active_session > 0 {
+            self.active_session -= 1;
+        }
+        self.active_session = self.active_session.min(self.sessions.len() - 1);
+    }
+
+    /// Tab bar with one tab per session, a close button per tab and "New Session"
+    fn show_session_tabs(&mut self, ui: &mut egui::Ui) {
+        let mut close = None;
+
+        ui.horizontal(|ui| {
+            for (index, session) in self.sessions.iter().enumerate() {
+                if ui
+                    .selectable_label(index == self.active_session, session.title(index))
+                    .clicked()
+                {
+                    self.active_session = index;
+                }
+                if ui.small_button("×").on_hover_text("Close session").clicked() {
+                    close = Some(index);
+                }
+                ui.separator();
+            }
+
+            if ui.button("+ New Session").clicked() {
+                self.new_session();
+            }
+        });
+
+        if let Some(index) = close {
+            self.close_session(index);
+        }
+    }
+
     fn select_process(&mut self, process_info: ProcessInfo) {
+        self.attach_process(process_info, false);
+    }
+
+    /// Opens a process for the active session
+    ///
+    /// Frozen watchlist entries are unfrozen when a different process is
+    /// attached, unless `keep_frozen` is set (reattaching after a restart).
+    fn attach_process(&mut self, process_info: ProcessInfo, keep_frozen: bool) {
+        // Without SeDebugPrivilege some processes cannot be opened; warn but still try
+        let privilege_warning = crate::platform::elevation::request_debug_privilege().err();
+        if let Some(ref e) = privilege_warning {
+            tracing::warn!("Failed to enable SeDebugPrivilege: {}", e);
+        }
+
+        // The engine borrows the handle that is about to be replaced
+        let session_id = self.session().id;
+        if self.engine_session == Some(session_id) {
+            self.engine_view.clear_engine();
+            self.detected_engine = None;
+            self.engine_session = None;
+        }
+
+        let session = &mut self.sessions[self.active_session];
         match Process::from_info(&process_info) {
             Ok(process) => {
-                self.selected_process = Some(process_info.clone());
-                let process_handle = process.handle_as_usize();
-                let process_id = process_info.pid;
-                self.scanner = Some(Arc::new(Mutex::new(Scanner::new(process))));
+                let same_process =
+                    session.selected_process.as_ref().map(|p| p.pid) == Some(process_info.pid);
+                session.selected_process = Some(process_info.clone());
+
+                // Stop writing into the old process
+                session.frozen_addresses.clear();
+                let unfrozen = if keep_frozen || same_process {
+                    0
+                } else {
+                    session.watchlist.lock().map(|mut w| unfreeze_watchlist(&mut w)).unwrap_or(0)
+                };
+                session.scanner = Some(Arc::new(Mutex::new(Scanner::new(process))));
                 self.status_message = format!(
                     "Process {} ({}) opened successfully",
                     process_info.name, process_info.pid
                 );
+                if unfrozen > 0 {
+                    self.status_message
+                        .push_str(&format!(" ({} frozen entries unfrozen)", unfrozen));
+                }
+                if let Some(e) = privilege_warning {
+                    self.status_message.push_str(&format!(" (warning: SeDebugPrivilege unavailable: {})", e));
+                }
                 self.error_message = None;
                 self.show_process_list = false;
 
                 // Reset scan state
-                self.scan_view.reset();
-                self.results_view.clear();
+                session.cancel_scan_task();
+                session.scan_view.reset();
+                session.results_view.clear();
+                session.scan_address_range = None;
+
+                // Restore results from a previously loaded session
+                if let Some(results) = session.loaded_results.take() {
+                    if let Some(Ok(mut scanner)) = session.scanner.as_ref().map(|s| s.lock()) {
+                        *scanner.results_mut() = results;
+                        session.results_view.update_from_scanner(&scanner);
+                    }
+                }
 
-                // Try to detect and initialize engine
-                self.try_init_engine(process_handle, process_id);
+                // Detect the engine in the background and attach it when done
+                self.start_engine_detection(&process_info);
             }
             Err(e) => {
-                self.error_message = Some(format!("Failed to open process: {}", e));
-                self.scanner = None;
+                let mut message = format!("Failed to open process: {}", e);
+                if let Some(privilege_error) = privilege_warning {
+                    message.push_str(&format!(" (SeDebugPrivilege unavailable: {})", privilege_error));
+                }
+                self.error_message = Some(message);
+                session.scanner = None;
             }
         }
     }
 
-    fn try_init_engine(&mut self, process_handle: usize, process_id: u32) {
-        // Try Unreal Engine first
-        let engine = Box::new(crate::engine::unreal::UnrealEngine::new(process_handle, process_id));
+    /// Reattaches the active session when its process has exited and was restarted
+    ///
+    /// A restarted game gets a new PID, so the first process with the same name
+    /// is opened instead and the last value scan is replayed as a first scan to
+    /// restore approximate results. Returns true if a new process was attached.
+    fn try_reattach(&mut self) -> bool {
+        let tab = self.session();
+        let Some(old_info) = tab.selected_process.clone() else {
+            return false;
+        };
+        if tab.scan_task.is_some() {
+            return false;
+        }
+        match tab.scanner.as_ref().map(|s| s.lock()) {
+            Some(Ok(scanner)) if !scanner.process().is_running() => {}
+            _ => return false,
+        }
+
+        // Not restarted (yet); checked again on the next interval
+        let Ok(process) = Process::try_from_name(&old_info.name) else {
+            return false;
+        };
+        let process_info = Process::list_all()
+            .ok()
+            .and_then(|processes| processes.into_iter().find(|p| p.pid == process.pid))
+            .unwrap_or_else(|| ProcessInfo {
+                pid: process.pid,
+                ..old_info.clone()
+            });
+        // attach_process opens its own handle
+        drop(process);
+
+        let replay = self
+            .session()
+            .scan_history
+            .iter()
+            .rev()
+            .find_map(|entry| Some((entry.value.clone()?, entry.scan_type)));
+
+        tracing::info!(
+            "{} (PID {}) exited, reattaching to PID {}",
+            old_info.name,
+            old_info.pid,
+            process_info.pid
+        );
+        // Same game restarted: keep freezing the watchlist
+        self.attach_process(process_info.clone(), true);
+        if self.session().selected_process.as_ref().map(|p| p.pid) != Some(process_info.pid) {
+            return false;
+        }
+
+        if let Some((value, scan_type)) = replay {
+            let tab = &mut self.sessions[self.active_session];
+            tab.scan_view.selected_value_type = value.value_type();
+            tab.scan_view.selected_scan_type = scan_type;
+            tab.scan_view.value_input = value.to_string();
+            self.start_first_scan(value, scan_type);
+        }
+
+        self.status_message = "Process restarted – reattached automatically".to_string();
+        true
+    }
+
+    /// Starts detecting the engine of the selected process in the background
+    ///
+    /// The thread opens its own handle (HANDLE is not Send); the backend is
+    /// created on the UI thread with the scanner's handle once detection finishes.
+    fn start_engine_detection(&mut self, process_info: &ProcessInfo) {
+        self.detected_engine = None;
+        self.engine_session = None;
+        self.engine_view.clear_engine();
+
+        let info = process_info.clone();
+        self.engine_detect_task = Some(EngineDetectTask {
+            session_id: self.session().id,
+            pid: info.pid,
+            thread: std::thread::spawn(move || {
+                let process = Process::from_info(&info)?;
+                AutoEngineDetector::detect_kind(&process)
+            }),
+        });
+    }
+
+    /// Attaches the detected engine backend when background detection finishes
+    fn poll_engine_detection(&mut self) {
+        if !self.engine_detect_task.as_ref().is_some_and(|task| task.thread.is_finished()) {
+            return;
+        }
+        let Some(task) = self.engine_detect_task.take() else {
+            return;
+        };
+
+        let kind = match task.thread.join() {
+            Ok(Ok(kind)) => kind,
+            Ok(Err(e)) => {
+                // Detection is best effort; the scanner keeps working without it
+                tracing::warn!("Engine detection failed: {}", e);
+                return;
+            }
+            Err(_) => {
+                tracing::warn!("Engine detection thread panicked");
+                return;
+            }
+        };
+
+        // The session may have been closed or switched to another process meanwhile
+        let Some(session) = self.sessions.iter().find(|s| s.id == task.session_id) else {
+            return;
+        };
+        let Some(Ok(scanner)) = session.scanner.as_ref().map(|s| s.lock()) else {
+            return;
+        };
+        if scanner.process().pid != task.pid {
+            return;
+        }
+        let engine = AutoEngineDetector::create(kind, scanner.process().handle_as_usize(), task.pid);
+        drop(scanner);
+
+        tracing::info!("Attached {} engine backend", kind.label());
         self.engine_view.set_engine(engine);
+        self.detected_engine = Some(kind);
+        self.engine_session = Some(task.session_id);
+    }
+
+    /// Saves the current scan results and watchlist
+    fn save_session(&mut self, path: &Path) -> anyhow::Result<()> {
+        let tab = self.session();
+        let mut scan_results = match tab.scanner.as_ref().map(|s| s.lock()) {
+            Some(Ok(scanner)) => scanner.results().clone(),
+            _ => tab
+                .loaded_results
+                .clone()
+                .unwrap_or_else(|| ScanResults::new(tab.scan_view.selected_value_type)),
+        };
+        tab.results_view.label_results(&mut scan_results);
+        let watchlist = tab
+            .watchlist
+            .lock()
+            .map_err(|e| anyhow::anyhow!("Failed to lock watchlist: {}", e))?
+            .clone();
+        let process_name = tab
+            .selected_process
+            .as_ref()
+            .map(|p| p.name.clone())
+            .unwrap_or_default();
+
+        save_session(&ScanSession::new(scan_results, watchlist, process_name), path)
+    }
+
+    /// Loads scan results and watchlist from a session file
+    ///
+    /// If no process is selected, the results are kept until one is selected.
+    /// Frozen entries stay frozen only if the attached process has the name
+    /// the session was saved with.
+    fn load_session(&mut self, path: &Path) -> anyhow::Result<ScanSession> {
+        let session = load_session(path)?;
+        let tab = &mut self.sessions[self.active_session];
+
+        let same_process = tab
+            .selected_process
+            .as_ref()
+            .is_some_and(|p| p.name.eq_ignore_ascii_case(&session.process_name));
+        if let Ok(mut watchlist) = tab.watchlist.lock() {
+            *watchlist = session.watchlist.clone();
+            if !same_process {
+                unfreeze_watchlist(&mut watchlist);
+            }
+        }
+
+        tab.results_view.update_from_results(&session.scan_results);
+        match tab.scanner.as_ref().map(|s| s.lock()) {
+            Some(Ok(mut scanner)) => *scanner.results_mut() = session.scan_results.clone(),
+            _ => tab.loaded_results = Some(session.scan_results.clone()),
+        }
+
+        Ok(session)
+    }
+
+    /// Exports the watchlist as a cheat table
+    fn export_cheat_table(&self, path: &Path) -> anyhow::Result<usize> {
+        let tab = self.session();
+        let watchlist = tab
+            .watchlist
+            .lock()
+            .map_err(|e| anyhow::anyhow!("Failed to lock watchlist: {}", e))?
+            .clone();
+
+        CheatTable::save(path, &watchlist)?;
+        Ok(watchlist.len())
+    }
+
+    /// Replaces the watchlist with the entries of a cheat table
+    ///
+    /// Frozen entries are frozen at their current value when a process is attached.
+    fn import_cheat_table(&mut self, path: &Path) -> anyhow::Result<CheatTable> {
+        let table = CheatTable::load(path)?;
+        let tab = &mut self.sessions[self.active_session];
+
+        let entries = match tab.scanner.as_ref().map(|s| s.lock()) {
+            Some(Ok(scanner)) => {
+                table.to_watchlist(|address, value_type| scanner.read_value(address, value_type).ok())
+            }
+            _ => table.to_watchlist(|_, _| None),
+        };
+
+        if let Ok(mut watchlist) = tab.watchlist.lock() {
+            *watchlist = entries;
+        }
+
+        Ok(table)
+    }
+
+    /// Replaces the watchlist with the entries of a Cheat Engine table
+    ///
+    /// Module-relative and pointer addresses are resolved against the selected
+    /// process; entries that cannot be resolved are skipped.
+    /// Returns (imported, total parsed).
+    fn import_cheat_engine_table(&mut self, path: &Path) -> anyhow::Result<(usize, usize)> {
+        let imported = CheatEngineImporter::parse_ct_file(path)?;
+        let tab = &mut self.sessions[self.active_session];
+        let Some(Ok(scanner)) = tab.scanner.as_ref().map(|s| s.lock()) else {
+            anyhow::bail!("Select a process first");
+        };
+
+        let entries: Vec<WatchlistEntry> = imported
+            .iter()
+            .filter_map(|entry| match entry.resolve(scanner.process()) {
+                Ok(address) => {
+                    let mut watch = WatchlistEntry::new(address, entry.value_type);
+                    watch.label = entry.description.clone();
+                    Some(watch)
+                }
+                Err(e) => {
+                    tracing::warn!("Skipping \"{}\": {}", entry.description, e);
+                    None
+                }
+            })
+            .collect();
+        drop(scanner);
+
+        let count = entries.len();
+        if let Ok(mut watchlist) = tab.watchlist.lock() {
+            *watchlist = entries;
+        }
+
+        Ok((count, imported.len()))
+    }
+
+    fn show_session_dialog(&mut self, ctx: &egui::Context) {
+        let Some(dialog) = &self.session_dialog else {
+            return;
+        };
+        let title = match dialog {
+            SessionDialog::Save => "Save Session",
+            SessionDialog::Load => "Load Session",
+            SessionDialog::ExportCheatTable => "Export Cheat Table",
+            SessionDialog::ImportCheatTable => "Import Cheat Table",
+            SessionDialog::ImportCheatEngineTable => "Import Cheat Engine Table",
+        };
+        let is_cheat_table = matches!(
+            dialog,
+            SessionDialog::ExportCheatTable
+                | SessionDialog::ImportCheatTable
+                | SessionDialog::ImportCheatEngineTable
+        );
+
+        let mut confirmed = false;
+        let mut cancelled = false;
+
+        egui::Window::new(title)
+            .collapsible(false)
+            .resizable(false)
+            .show(ctx, |ui| {
+                ui.horizontal(|ui| {
+                    ui.label("File:");
+                    if is_cheat_table {
+                        ui.text_edit_singleline(&mut self.cheat_table_path);
+                    } else {
+                        ui.text_edit_singleline(&mut self.session_path);
+                    }
+                });
+
+                ui.horizontal(|ui| {
+                    if ui.button(title).clicked() {
+                        confirmed = true;
+                    }
+                    if ui.button("Cancel").clicked() {
+                        cancelled = true;
+                    }
+                });
+            });
+
+        if confirmed {
+            let path = std::path::PathBuf::from(if is_cheat_table {
+                &self.cheat_table_path
+            } else {
+                &self.session_path
+            });
+            let result = match self.session_dialog {
+                Some(SessionDialog::Save) => self
+                    .save_session(&path)
+                    .map(|_| format!("Session saved to {}", path.display())),
+                Some(SessionDialog::ExportCheatTable) => self
+                    .export_cheat_table(&path)
+                    .map(|count| format!("Exported {} entries to {}", count, path.display())),
+                Some(SessionDialog::ImportCheatTable) => self.import_cheat_table(&path).map(|table| {
+                    format!("Imported {} entries from cheat table \"{}\"", table.entries.len(), table.name)
+                }),
+                Some(SessionDialog::ImportCheatEngineTable) => {
+                    self.import_cheat_engine_table(&path).map(|(imported, total)| {
+                        format!("Imported {} of {} entries from {}", imported, total, path.display())
+                    })
+                }
+                _ => self.load_session(&path).map(|session| {
+                    format!(
+                        "Session loaded ({} results from {}). Re-select the process to continue scanning.",
+                        session.scan_results.len(),
+                        session.process_name
+                    )
+                }),
+            };
+
+            match result {
+                Ok(message) => {
+                    self.status_message = message;
+                    self.error_message = None;
+                }
+                Err(e) => self.error_message = Some(format!("{} failed: {}", title, e)),
+            }
+            self.session_dialog = None;
+        } else if cancelled {
+            self.session_dialog = None;
+        }
+    }
+
+    /// Global keyboard shortcuts (F5 next scan, Ctrl+F first scan, Ctrl+R reset, Ctrl+S/O sessions)
+    fn handle_shortcuts(&mut self, ctx: &egui::Context) {
+        use egui::{Key, Modifiers};
+
+        let (next_scan, first_scan, reset, save, load) = ctx.input_mut(|i| {
+            (
+                i.consume_key(Modifiers::NONE, Key::F5),
+                i.consume_key(Modifiers::COMMAND, Key::F),
+                i.consume_key(Modifiers::COMMAND, Key::R),
+                i.consume_key(Modifiers::COMMAND, Key::S),
+                i.consume_key(Modifiers::COMMAND, Key::O),
+            )
+        });
+
+        // Scan shortcuts need a process and must not overlap a running first scan
+        let has_scanner = self.session().scanner.is_some();
+        let can_scan = has_scanner && self.session().scan_task.is_none();
+        if next_scan && can_scan {
+            self.perform_next_scan();
+        }
+        if first_scan && can_scan {
+            self.perform_first_scan();
+        }
+        if reset && has_scanner {
+            self.reset_scan();
+        }
+        if save {
+            self.session_dialog = Some(SessionDialog::Save);
+        }
+        if load {
+            self.session_dialog = Some(SessionDialog::Load);
+        }
     }
 
     fn perform_first_scan(&mut self) {
-        if let Some(scanner) = &self.scanner {
-            let value_str = &self.scan_view.value_input;
-            let value_type = self.scan_view.selected_value_type;
-            let scan_type = self.scan_view.selected_scan_type;
+        let tab = &mut self.sessions[self.active_session];
+        if tab.scan_task.is_some() || tab.selected_process.is_none() {
+            return;
+        }
+
+        let value_str = &tab.scan_view.value_input;
+        let value_type = tab.scan_view.selected_value_type;
+        let scan_type = tab.scan_view.selected_scan_type;
 
-            // Parse value
-            let value = match self.parse_value(value_str, value_type) {
+        // Parse value (the input is disabled for scans that don't need it)
+        let value = if scan_type.requires_value() {
+            match ScanValue::parse(value_str, value_type) {
                 Ok(v) => v,
                 Err(e) => {
                     self.error_message = Some(format!("Invalid value: {}", e));
                     return;
                 }
+            }
+        } else {
+            ScanValue::I32(0) // Dummy value for scans that don't need it
+        };
+
+        self.start_first_scan(value, scan_type);
+    }
+
+    /// Starts a first scan for `value` with the options of the scan view
+    fn start_first_scan(&mut self, value: ScanValue, scan_type: ScanType) {
+        let tab = &mut self.sessions[self.active_session];
+        let Some(process_info) = tab.selected_process.clone() else {
+            return;
+        };
+        let value_type = tab.scan_view.selected_value_type;
+
+        let progress = Arc::new(Mutex::new(ScanProgress::default()));
+        let cancel = Arc::new(AtomicBool::new(false));
+
+        // Create scan options
+        let progress_sink = progress.clone();
+        let mut options = ScanOptions::new(value_type)
+            .with_alignment(tab.scan_view.alignment)
+            .with_region_types(tab.scan_view.region_types)
+            .with_endianness(tab.scan_view.endianness)
+            .with_cancellation_token(cancel.clone())
+            .with_progress_callback(Box::new(move |p| {
+                if let Ok(mut progress) = progress_sink.lock() {
+                    *progress = p;
+                }
+            }));
+        if tab.scan_view.heap_only {
+            options = options.heap_only();
+        }
+        if tab.scan_view.timeout_secs > 0 {
+            options = options.with_timeout(Duration::from_secs(tab.scan_view.timeout_secs));
+        }
+        if let Some((start, end)) = tab.scan_address_range {
+            options = options.with_address_range(start, end);
+        }
+
+        // HANDLE is not Send, so the scan thread opens its own handle to the process
+        let max_results = tab.scan_view.max_results;
+        let history_value = scan_type.requires_value().then(|| value.clone());
+        let task_options = ScanOptions::for_next_scan(&options);
+        let thread = std::thread::spawn(move || {
+            let mut scanner = Scanner::new(Process::from_info(&process_info)?);
+
+            // Stream matches so only the first max_results are ever stored
+            let mut kept = Vec::new();
+            let scanned = scanner.first_scan_stream(&value, scan_type, &options, |result| {
+                if kept.len() < max_results {
+                    kept.push(result);
+                }
+            });
+
+            // A timed-out scan still keeps the matches found before the deadline
+            let (total, timeout) = match scanned.map_err(|e| e.downcast::<ScanError>()) {
+                Ok(total) => (total, None),
+                Err(Ok(timeout @ ScanError::Timeout { results_found, .. })) => (results_found, Some(timeout)),
+                Err(Err(e)) => return Err(e),
             };
 
-            // Create scan options
-            let options = ScanOptions::new(value_type)
-                .with_alignment(self.scan_view.alignment);
+            scanner.results_mut().results = kept;
+            scanner.results_mut().deduplicate();
+            Ok((scanner.into_results(), total, timeout))
+        });
 
-            // Perform scan
-            self.status_message = "Scanning...".to_string();
-            self.error_message = None;
+        self.status_message = "Scanning...".to_string();
+        self.error_message = None;
+        tab.scan_start_time = Some(Instant::now());
+        tab.scan_task = Some(ScanTask {
+            thread,
+            options: task_options,
+            progress,
+            cancel,
+            scan_type,
+            value: history_value,
+        });
+    }
 
-            match scanner.lock() {
-                Ok(mut scanner) => {
-                    match scanner.first_scan(&value, scan_type, &options) {
-                        Ok(count) => {
-                            self.status_message = format!("First scan complete. Found {} results", count);
-                            self.results_view.update_from_scanner(&scanner);
-                        }
-                        Err(e) => {
-                            self.error_message = Some(format!("Scan failed: {}", e));
-                            self.status_message = "Scan failed".to_string();
-                        }
+    /// Picks up the results of background scans once they finish
+    ///
+    /// Every session is polled so scans in inactive tabs complete too.
+    fn poll_scan_task(&mut self) {
+        for tab in &mut self.sessions {
+            if !tab.scan_task.as_ref().is_some_and(|task| task.thread.is_finished()) {
+                continue;
+            }
+
+            let Some(task) = tab.scan_task.take() else {
+                continue;
+            };
+            let cancelled = task.cancel.load(Ordering::Relaxed);
+            let progress = task.progress.lock().map(|p| *p).unwrap_or_default();
+            let elapsed = tab.scan_start_time.map(|start| start.elapsed()).unwrap_or_default();
+
+            match task.thread.join() {
+                Ok(Ok((results, total, timeout))) => {
+                    let count = results.len();
+                    tab.scan_history
+                        .push(ScanHistoryEntry::new(task.scan_type, task.value, total));
+                    if let Some(Ok(mut scanner)) = tab.scanner.as_ref().map(|s| s.lock()) {
+                        scanner.set_first_scan_results(results, &task.options);
+                        tab.results_view.update_from_scanner(&scanner);
                     }
+                    let summary = if let Some(timeout) = timeout {
+                        format!("{}. Kept {} partial results", timeout, count)
+                    } else if cancelled {
+                        format!("Scan cancelled. Kept {} partial results", count)
+                    } else if count < total {
+                        format!("First scan complete. Found {} results (kept first {})", total, count)
+                    } else {
+                        format!("First scan complete. Found {} results", count)
+                    };
+                    self.status_message = format!(
+                        "{}. Scanned {} regions, {} in {:.2}s",
+                        summary,
+                        progress.regions_done,
+                        format_bytes(progress.bytes_scanned),
+                        elapsed.as_secs_f64()
+                    );
                 }
-                Err(e) => {
-                    self.error_message = Some(format!("Failed to lock scanner: {}", e));
+                Ok(Err(e)) => {
+                    self.error_message = Some(format!("Scan failed: {}", e));
+                    self.status_message = "Scan failed".to_string();
+                }
+                Err(_) => {
+                    self.error_message = Some("Scan thread panicked".to_string());
+                    self.status_message = "Scan failed".to_string();
                 }
             }
         }
     }
 
+    /// Progress bar and Cancel button for the running scan
+    fn show_scan_progress(&mut self, ui: &mut egui::Ui) {
+        let Some(task) = &self.session().scan_task else {
+            return;
+        };
+
+        let progress = task.progress.lock().map(|p| *p).unwrap_or_default();
+        let fraction = if progress.regions_total > 0 {
+            progress.regions_done as f32 / progress.regions_total as f32
+        } else {
+            0.0
+        };
+
+        ui.add(egui::ProgressBar::new(fraction).show_percentage().text(format!(
+            "{}/{} regions, {} found",
+            progress.regions_done, progress.regions_total, progress.results_found
+        )));
+
+        if ui.button("Cancel").clicked() {
+            task.cancel.store(true, Ordering::Relaxed);
+        }
+
+        ui.ctx().request_repaint();
+    }
+
     fn perform_next_scan(&mut self) {
-        if let Some(scanner) = &self.scanner {
-            let value_str = &self.scan_view.value_input;
-            let value_type = self.scan_view.selected_value_type;
-            let scan_type = self.scan_view.selected_scan_type;
+        let tab = &mut self.sessions[self.active_session];
+        if let Some(scanner) = &tab.scanner {
+            let value_str = &tab.scan_view.value_input;
+            let value_type = tab.scan_view.selected_value_type;
+            let scan_type = tab.scan_view.selected_scan_type;
+
+            if matches!(scan_type, ScanType::IncreasedByExactly(_) | ScanType::DecreasedByExactly(_)) {
+                if let Err(e) = tab.scan_view.by_amount() {
+                    self.error_message = Some(format!("Invalid amount: {}", e));
+                    return;
+                }
+            }
 
             // Parse value if needed
             let value = if scan_type.requires_value() {
-                match self.parse_value(value_str, value_type) {
+                match ScanValue::parse(value_str, value_type) {
                     Ok(v) => v,
                     Err(e) => {
                         self.error_message = Some(format!("Invalid value: {}", e));
@@ -167,10 +1037,21 @@ impl LightScanApp {
 
             match scanner.lock() {
                 Ok(mut scanner) => {
-                    match scanner.next_scan(&value, scan_type) {
+                    let start = Instant::now();
+                    tab.scan_start_time = Some(start);
+                    match scanner.next_scan_batch(&value, scan_type) {
                         Ok(count) => {
-                            self.status_message = format!("Next scan complete. {} results remaining", count);
-                            self.results_view.update_from_scanner(&scanner);
+                            self.status_message = format!(
+                                "Next scan complete. {} results remaining. Scan completed in {:.2}s",
+                                count,
+                                start.elapsed().as_secs_f64()
+                            );
+                            tab.scan_history.push(ScanHistoryEntry::new(
+                                scan_type,
+                                scan_type.requires_value().then_some(value),
+                                count,
+                            ));
+                            tab.results_view.update_from_scanner(&scanner);
                         }
                         Err(e) => {
                             self.error_message = Some(format!("Scan failed: {}", e));
@@ -186,101 +1067,130 @@ impl LightScanApp {
     }
 
     fn reset_scan(&mut self) {
-        if let Some(scanner) = &self.scanner {
+        let tab = &mut self.sessions[self.active_session];
+        tab.cancel_scan_task();
+        tab.scan_history.clear();
+
+        if let Some(scanner) = &tab.scanner {
             if let Ok(mut scanner) = scanner.lock() {
                 scanner.reset();
-                self.results_view.clear();
-                self.scan_view.reset();
+                tab.results_view.clear();
+                tab.scan_view.reset();
                 self.status_message = "Scan reset".to_string();
             }
         }
     }
 
-    fn parse_value(&self, value_str: &str, value_type: ValueType) -> Result<ScanValue, String> {
-        match value_type {
-            ValueType::I8 => value_str
-                .parse::<i8>()
-                .map(ScanValue::I8)
-                .map_err(|e| e.to_string()),
-            ValueType::I16 => value_str
-                .parse::<i16>()
-                .map(ScanValue::I16)
-                .map_err(|e| e.to_string()),
-            ValueType::I32 => value_str
-                .parse::<i32>()
-                .map(ScanValue::I32)
-                .map_err(|e| e.to_string()),
-            ValueType::I64 => value_str
-                .parse::<i64>()
-                .map(ScanValue::I64)
-                .map_err(|e| e.to_string()),
-            ValueType::U8 => value_str
-                .parse::<u8>()
-                .map(ScanValue::U8)
-                .map_err(|e| e.to_string()),
-            ValueType::U16 => value_str
-                .parse::<u16>()
-                .map(ScanValue::U16)
-                .map_err(|e| e.to_string()),
-            ValueType::U32 => value_str
-                .parse::<u32>()
-                .map(ScanValue::U32)
-                .map_err(|e| e.to_string()),
-            ValueType::U64 => value_str
-                .parse::<u64>()
-                .map(ScanValue::U64)
-                .map_err(|e| e.to_string()),
-            ValueType::F32 => value_str
-                .parse::<f32>()
-                .map(ScanValue::F32)
-                .map_err(|e| e.to_string()),
-            ValueType::F64 => value_str
-                .parse::<f64>()
-                .map(ScanValue::F64)
-                .map_err(|e| e.to_string()),
-            ValueType::ByteArray(_) => Err("Byte array input not yet implemented".to_string()),
-        }
-    }
-
     fn show_memory_scan_tab(&mut self, ui: &mut egui::Ui) {
         // Only show scan UI if a process is selected
-        if self.scanner.is_some() {
+        if self.session().scanner.is_some() {
             ui.columns(2, |columns| {
                 // Left panel - Scan controls
                 columns[0].vertical(|ui| {
                     ui.heading("Scan");
 
-                    self.scan_view.ui(ui);
+                    self.session_mut().scan_view.ui(ui);
 
                     ui.separator();
 
                     // Scan buttons
+                    let scanning = self.session().scan_task.is_some();
                     ui.horizontal(|ui| {
-                        if ui.button("First Scan").clicked() {
+                        if ui.add_enabled(!scanning, egui::Button::new("First Scan (Ctrl+F)")).clicked() {
                             self.perform_first_scan();
                         }
 
-                        if ui.button("Next Scan").clicked() {
+                        if ui.add_enabled(!scanning, egui::Button::new("Next Scan (F5)")).clicked() {
                             self.perform_next_scan();
                         }
                     });
 
+                    self.show_scan_progress(ui);
+
                     ui.horizontal(|ui| {
-                        if ui.button("Reset").clicked() {
+                        if ui.button("Reset (Ctrl+R)").clicked() {
                             self.reset_scan();
                         }
                     });
 
+                    let tab = self.session_mut();
+                    if let Some((start, end)) = tab.scan_address_range {
+                        ui.horizontal(|ui| {
+                            ui.label(format!("Scan range: 0x{:X} - 0x{:X}", start, end));
+                            if ui.small_button("Clear").clicked() {
+                                tab.scan_address_range = None;
+                            }
+                        });
+                    }
+
                     ui.separator();
-                    ui.label(format!("Results: {}", self.results_view.result_count()));
+                    ui.label(format!("Results: {}", tab.results_view.result_count()));
+
+                    ui.collapsing(format!("Scan History ({})", tab.scan_history.len()), |ui| {
+                        self.show_scan_history(ui);
+                    });
                 });
 
                 // Right panel - Results
                 columns[1].vertical(|ui| {
                     ui.heading("Results");
-                    self.results_view.ui(ui, &self.scanner);
+                    let tab = self.session_mut();
+                    tab.results_view.ui(ui, &tab.scanner);
                 });
             });
+
+            let tab = &mut self.sessions[self.active_session];
+
+            // Add entries requested from the results context menu
+            if let Some(address) = tab.results_view.take_watch_request() {
+                let value_type = tab.results_view.value_type();
+                if let Ok(mut entries) = tab.watchlist.lock() {
+                    if !entries.iter().any(|e| e.address == address) {
+                        let mut entry = WatchlistEntry::new(address, value_type);
+                        entry.endianness = tab.results_view.endianness();
+                        entries.push(entry);
+                    }
+                }
+            }
+
+            if let Some(address) = tab.results_view.take_module_range_request() {
+                if let Some(pid) = tab.selected_process.as_ref().map(|p| p.pid) {
+                    match crate::platform::module::find_module_containing(pid, address) {
+                        Ok(module) => {
+                            let range = (module.base_address, module.base_address + module.size);
+                            tab.scan_address_range = Some(range);
+                            self.status_message = format!(
+                                "Scan range set to {} (0x{:X} - 0x{:X})",
+                                module.name, range.0, range.1
+                            );
+                        }
+                        Err(e) => self.error_message = Some(e.to_string()),
+                    }
+                }
+            }
+
+            if tab.results_view.take_module_filter_request() {
+                match self.module_list_view.selected_range() {
+                    Some((start, end)) => {
+                        if let Some(Ok(scanner)) = tab.scanner.as_ref().map(|s| s.lock()) {
+                            tab.results_view.set_address_filter(start, end - 1, &scanner);
+                        }
+                    }
+                    None => {
+                        self.error_message = Some("Select a module in the Modules list first".to_string())
+                    }
+                }
+            }
+
+            if let Some(address) = tab.results_view.take_memory_view_request() {
+                self.memory_view.go_to(address);
+                self.current_tab = AppTab::Memory;
+            }
+
+            ui.separator();
+            ui.collapsing("Watchlist", |ui| {
+                self.show_watchlist(ui);
+            });
         } else {
             ui.vertical_centered(|ui| {
                 ui.add_space(100.0);
@@ -290,13 +1200,190 @@ impl LightScanApp {
         }
     }
 
+    fn show_scan_history(&self, ui: &mut egui::Ui) {
+        let tab = self.session();
+        if tab.scan_history.is_empty() {
+            ui.label("No scans yet");
+            return;
+        }
+
+        egui::ScrollArea::vertical()
+            .id_salt("scan_history_scroll")
+            .max_height(150.0)
+            .show(ui, |ui| {
+                for (index, entry) in tab.scan_history.iter().enumerate() {
+                    let details = format!(
+                        "Scan type: {}\nValue: {}\nResults: {}\n{:.0} seconds ago",
+                        entry.scan_type,
+                        entry.value.as_ref().map_or("-".to_string(), |v| v.to_string()),
+                        entry.result_count,
+                        entry.timestamp.elapsed().as_secs_f64()
+                    );
+                    ui.add(egui::Label::new(entry.summary(index)).sense(egui::Sense::click()))
+                        .on_hover_text(details);
+                }
+            });
+    }
+
+    fn show_watchlist(&mut self, ui: &mut egui::Ui) {
+        let tab = self.session();
+        // Read current values first so the watchlist lock is not held while locking the scanner
+        let entries: Vec<WatchlistEntry> = match tab.watchlist.lock() {
+            Ok(entries) => entries.clone(),
+            Err(_) => return,
+        };
+
+        let current_values: Vec<Option<ScanValue>> = match tab.scanner.as_ref().map(|s| s.lock()) {
+            Some(Ok(scanner)) => entries
+                .iter()
+                .map(|e| scanner.read_value_endian(e.address, e.value_type, e.endianness).ok())
+                .collect(),
+            _ => vec![None; entries.len()],
+        };
+
+        let Ok(mut entries) = tab.watchlist.lock() else {
+            return;
+        };
+
+        if entries.is_empty() {
+            ui.label("No entries. Right-click a result and choose \"Add to Watchlist\".");
+            return;
+        }
+
+        let mut remove = None;
+
+        egui::Grid::new("watchlist_grid")
+            .striped(true)
+            .num_columns(5)
+            .show(ui, |ui| {
+                ui.label(egui::RichText::new("Freeze").strong());
+                ui.label(egui::RichText::new("Label").strong());
+                ui.label(egui::RichText::new("Address").strong());
+                ui.label(egui::RichText::new("Value").strong());
+                ui.label(egui::RichText::new("Actions").strong());
+                ui.end_row();
+
+                for (index, entry) in entries.iter_mut().enumerate() {
+                    let current = current_values.get(index).cloned().flatten();
+
+                    let mut frozen = entry.is_frozen();
+                    if ui.checkbox(&mut frozen, "").changed() {
+                        // Freeze at the current value
+                        entry.frozen_value = if frozen { current.clone() } else { None };
+                    }
+
+                    ui.add(egui::TextEdit::singleline(&mut entry.label).desired_width(120.0));
+                    ui.label(format!("0x{:X}", entry.address));
+
+                    match &current {
+                        Some(value) => ui.label(format!("{} ({})", value, entry.value_type)),
+                        None => ui.label("???"),
+                    };
+
+                    if ui.small_button("Remove").clicked() {
+                        remove = Some(index);
+                    }
+                    ui.end_row();
+                }
+            });
+
+        if let Some(index) = remove {
+            entries.remove(index);
+        }
+    }
+
     fn show_engine_tab(&mut self, ui: &mut egui::Ui) {
+        if self.engine_detect_task.is_some() {
+            ui.horizontal(|ui| {
+                ui.spinner();
+                ui.label("Detecting engine...");
+            });
+            ui.ctx().request_repaint();
+            return;
+        }
+
         self.engine_view.ui(ui);
+
+        if self.engine_view.take_auto_detect_request() {
+            self.auto_detect_engine();
+        }
+        if let Some(choice) = self.engine_view.take_backend_request() {
+            self.select_engine_backend(choice);
+        }
+    }
+
+    /// Replaces the engine backend with one picked in the backend selector
+    fn select_engine_backend(&mut self, choice: BackendChoice) {
+        let session_id = self.session().id;
+        let Some(Ok(scanner)) = self.sessions[self.active_session].scanner.as_ref().map(|s| s.lock()) else {
+            self.engine_view.set_error("No process selected".to_string());
+            return;
+        };
+        let (handle, pid) = (scanner.process().handle_as_usize(), scanner.process().pid);
+        drop(scanner);
+
+        let engine = match &choice {
+            BackendChoice::BuiltIn(kind) => AutoEngineDetector::create(*kind, handle, pid),
+            BackendChoice::Plugin(name) => match PluginRegistry::create(name, handle, pid) {
+                Some(engine) => engine,
+                None => {
+                    self.engine_view.set_error(format!("Engine plugin {} is not registered", name));
+                    return;
+                }
+            },
+        };
+
+        let name = engine.name();
+        self.engine_view.set_engine(engine);
+        self.engine_view.set_status(format!("Using {} backend", name));
+        self.detected_engine = match choice {
+            BackendChoice::BuiltIn(kind) => Some(kind),
+            BackendChoice::Plugin(_) => None,
+        };
+        self.engine_session = Some(session_id);
+    }
+
+    /// Replaces the engine backend with the one detected from the process's loaded modules
+    fn auto_detect_engine(&mut self) {
+        let session_id = self.session().id;
+        let Some(Ok(scanner)) = self.sessions[self.active_session].scanner.as_ref().map(|s| s.lock()) else {
+            self.engine_view.set_error("No process selected".to_string());
+            return;
+        };
+
+        // The engine borrows the scanner's process handle, which stays open while selected
+        match AutoEngineDetector::detect_kind(scanner.process()) {
+            Ok(kind) => {
+                let process = scanner.process();
+                let engine = AutoEngineDetector::create(kind, process.handle_as_usize(), process.pid);
+                drop(scanner);
+                self.engine_view.set_engine(engine);
+                self.engine_view.set_status(format!("Detected engine: {}", kind.label()));
+                self.detected_engine = Some(kind);
+                self.engine_session = Some(session_id);
+            }
+            Err(e) => {
+                drop(scanner);
+                self.engine_view.set_error(format!("Engine detection failed: {}", e));
+            }
+        }
     }
 }
 
 impl eframe::App for LightScanApp {
     fn update(&mut self, ctx: &egui::Context, _frame: &mut eframe::Frame) {
+        self.poll_scan_task();
+        self.poll_engine_detection();
+        self.handle_shortcuts(ctx);
+
+        if self.session().selected_process.is_some() {
+            if self.last_reattach_check.elapsed() >= REATTACH_CHECK_INTERVAL {
+                self.last_reattach_check = Instant::now();
+                self.try_reattach();
+            }
+            ctx.request_repaint_after(REATTACH_CHECK_INTERVAL);
+        }
+
         // Top panel with menu
         egui::TopBottomPanel::top("top_panel").show(ctx, |ui| {
             egui::menu::bar(ui, |ui| {
@@ -305,6 +1392,32 @@ impl eframe::App for LightScanApp {
                         self.show_process_list = true;
                         ui.close_menu();
                     }
+                    if ui.button("New Session").clicked() {
+                        self.new_session();
+                        ui.close_menu();
+                    }
+                    ui.separator();
+                    if ui.add(egui::Button::new("Save Session").shortcut_text("Ctrl+S")).clicked() {
+                        self.session_dialog = Some(SessionDialog::Save);
+                        ui.close_menu();
+                    }
+                    if ui.add(egui::Button::new("Load Session").shortcut_text("Ctrl+O")).clicked() {
+                        self.session_dialog = Some(SessionDialog::Load);
+                        ui.close_menu();
+                    }
+                    ui.separator();
+                    if ui.button("Export Cheat Table").clicked() {
+                        self.session_dialog = Some(SessionDialog::ExportCheatTable);
+                        ui.close_menu();
+                    }
+                    if ui.button("Import Cheat Table").clicked() {
+                        self.session_dialog = Some(SessionDialog::ImportCheatTable);
+                        ui.close_menu();
+                    }
+                    if ui.button("Import Cheat Engine Table (.CT)").clicked() {
+                        self.session_dialog = Some(SessionDialog::ImportCheatEngineTable);
+                        ui.close_menu();
+                    }
                     ui.separator();
                     if ui.button("Exit").clicked() {
                         ctx.send_viewport_cmd(egui::ViewportCommand::Close);
@@ -312,7 +1425,7 @@ impl eframe::App for LightScanApp {
                 });
 
                 ui.menu_button("Edit", |ui| {
-                    if ui.button("Reset Scan").clicked() {
+                    if ui.add(egui::Button::new("Reset Scan").shortcut_text("Ctrl+R")).clicked() {
                         self.reset_scan();
                         ui.close_menu();
                     }
@@ -331,9 +1444,23 @@ impl eframe::App for LightScanApp {
             ui.horizontal(|ui| {
                 ui.label("Status:");
                 ui.label(&self.status_message);
+
+                ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                    if let Some(level) = self.integrity_level {
+                        ui.label(format!("LightScan: {} integrity", level));
+                    }
+                    if let Some(ref process) = self.session().selected_process {
+                        let target_level = process.integrity.map_or("?", |level| level.label());
+                        ui.label(format!("{} ({}): {} integrity", process.name, process.pid, target_level));
+                        ui.separator();
+                    }
+                });
             });
         });
 
+        // Session save/load dialog
+        self.show_session_dialog(ctx);
+
         // Process list dialog
         if self.show_process_list {
             egui::Window::new("Select Process")
@@ -372,10 +1499,14 @@ impl eframe::App for LightScanApp {
                 ui.separator();
             }
 
+            // Scan session tabs
+            self.show_session_tabs(ui);
+            ui.separator();
+
             // Process selection header
             ui.horizontal(|ui| {
                 ui.label("Process:");
-                if let Some(ref process) = self.selected_process {
+                if let Some(ref process) = self.session().selected_process {
                     ui.label(format!("{} ({})", process.name, process.pid));
                 } else {
                     ui.label("None selected");
@@ -386,6 +1517,25 @@ impl eframe::App for LightScanApp {
                 }
             });
 
+            // Memory map, threads and modules of the selected process
+            if let Some(pid) = self.session().selected_process.as_ref().map(|p| p.pid) {
+                ui.collapsing("Memory Map", |ui| {
+                    self.region_map_view.ui(ui, pid);
+                });
+
+                ui.collapsing("Threads", |ui| {
+                    self.thread_list_view.ui(ui, pid);
+                });
+
+                ui.collapsing("Modules", |ui| {
+                    if let Some(range) = self.module_list_view.ui(ui, pid) {
+                        self.session_mut().scan_address_range = Some(range);
+                        self.status_message =
+                            format!("Scan range set to 0x{:X} - 0x{:X}", range.0, range.1);
+                    }
+                });
+            }
+
             ui.separator();
 
             // Error message
@@ -403,7 +1553,25 @@ impl eframe::App for LightScanApp {
                     self.current_tab = AppTab::MemoryScan;
                 }
                 if ui
-                    .selectable_label(self.current_tab == AppTab::Engine, "Engine Functions")
+                    .selectable_label(self.current_tab == AppTab::Memory, "Memory")
+                    .clicked()
+                {
+                    self.current_tab = AppTab::Memory;
+                }
+                if ui
+                    .selectable_label(self.current_tab == AppTab::PointerScan, "Pointer Scan")
+                    .clicked()
+                {
+                    self.current_tab = AppTab::PointerScan;
+                }
+                let engine_tab_label = match self.detected_engine {
+                    Some(kind) if kind != DetectedEngine::Native => {
+                        format!("Engine Functions ({} detected)", kind.label())
+                    }
+                    _ => "Engine Functions".to_string(),
+                };
+                if ui
+                    .selectable_label(self.current_tab == AppTab::Engine, engine_tab_label)
                     .clicked()
                 {
                     self.current_tab = AppTab::Engine;
@@ -415,8 +1583,17 @@ impl eframe::App for LightScanApp {
             // Tab content
             match self.current_tab {
                 AppTab::MemoryScan => self.show_memory_scan_tab(ui),
+                AppTab::Memory => self.memory_view.ui(ui, &self.sessions[self.active_session].scanner),
+                AppTab::PointerScan => self
+                    .pointer_scan_view
+                    .ui(ui, self.sessions[self.active_session].selected_process.as_ref()),
                 AppTab::Engine => self.show_engine_tab(ui),
             }
         });
+
+        // After the watchlist was drawn, so a toggled Freeze checkbox applies this frame
+        for tab in &mut self.sessions {
+            tab.sync_frozen_addresses();
+        }
     }
 }
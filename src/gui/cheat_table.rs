@@ -0,0 +1,368 @@
+use crate::platform::is_process_64bit;
+use crate::platform::module::list_modules;
+use crate::scanner::{MemoryScanner, Process, WatchlistEntry};
+use crate::types::{ScanValue, ValueType};
+use anyhow::Result;
+use quick_xml::events::Event;
+use quick_xml::Reader;
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+
+/// Default file name for exported cheat tables
+pub const DEFAULT_CHEAT_TABLE_PATH: &str = "lightscan.lct";
+
+/// A shareable list of addresses (simplified, JSON-based take on Cheat Engine's .CT)
+///
+/// Unlike a session, a cheat table carries no scan results and no frozen values,
+/// so it stays small and readable when shared as a `.lct` file.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct CheatTable {
+    pub name: String,
+    pub entries: Vec<CheatEntry>,
+}
+
+/// One address in a cheat table
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct CheatEntry {
+    pub address: usize,
+    pub description: String,
+    pub value_type: ValueType,
+    /// Whether the entry should be frozen (at its current value) after import
+    pub frozen: bool,
+    /// Offsets from a module-relative base, if the address was found through a pointer scan
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pointer_chain: Option<Vec<i64>>,
+}
+
+impl CheatTable {
+    /// Builds a table from watchlist entries
+    pub fn from_watchlist(name: String, watchlist: &[WatchlistEntry]) -> Self {
+        let entries = watchlist
+            .iter()
+            .map(|entry| CheatEntry {
+                address: entry.address,
+                description: entry.label.clone(),
+                value_type: entry.value_type,
+                frozen: entry.is_frozen(),
+                pointer_chain: None,
+            })
+            .collect();
+
+        Self { name, entries }
+    }
+
+    /// Saves the watchlist as a pretty-printed cheat table named after the file
+    pub fn save(path: &Path, watchlist: &[WatchlistEntry]) -> Result<()> {
+        let name = path
+            .file_stem()
+            .map(|stem| stem.to_string_lossy().into_owned())
+            .unwrap_or_default();
+
+        let json = serde_json::to_string_pretty(&Self::from_watchlist(name, watchlist))?;
+        std::fs::write(path, json)?;
+        Ok(())
+    }
+
+    /// Loads a cheat table from a JSON file
+    pub fn load(path: &Path) -> Result<CheatTable> {
+        let json = std::fs::read_to_string(path)?;
+        Ok(serde_json::from_str(&json)?)
+    }
+
+    /// Converts the entries to watchlist entries
+    ///
+    /// `read_value` is used to freeze frozen entries at their current value;
+    /// entries that cannot be read are imported unfrozen.
+    pub fn to_watchlist(
+        &self,
+        mut read_value: impl FnMut(usize, ValueType) -> Option<ScanValue>,
+    ) -> Vec<WatchlistEntry> {
+        self.entries
+            .iter()
+            .map(|entry| {
+                let mut watch = WatchlistEntry::new(entry.address, entry.value_type);
+                watch.label = entry.description.clone();
+                if entry.frozen {
+                    watch.frozen_value = read_value(entry.address, entry.value_type);
+                }
+                watch
+            })
+            .collect()
+    }
+}
+
+/// One address imported from a Cheat Engine table
+#[derive(Clone, Debug, PartialEq)]
+pub struct ImportedEntry {
+    pub description: String,
+    /// Absolute address, or the offset from `module` when it is set
+    pub address: usize,
+    /// Module of a "game.exe+1234" style address
+    pub module: Option<String>,
+    pub value_type: ValueType,
+    /// Offsets in the order they are applied (CE lists them last-first)
+    pub pointer_offsets: Vec<i64>,
+}
+
+impl ImportedEntry {
+    /// Resolves the module base and follows the pointer offsets in the target process
+    ///
+    /// Pointers are read at the target's pointer size (4 bytes for WOW64 processes).
+    pub fn resolve(&self, process: &Process) -> Result<usize> {
+        let mut address = match &self.module {
+            Some(name) => {
+                let module = list_modules(process.pid)?
+                    .into_iter()
+                    .find(|m| m.name.eq_ignore_ascii_case(name))
+                    .ok_or_else(|| anyhow::anyhow!("Module {} not loaded", name))?;
+                module.base_address.wrapping_add(self.address)
+            }
+            None => self.address,
+        };
+
+        if self.pointer_offsets.is_empty() {
+            return Ok(address);
+        }
+
+        let pointer_size = if is_process_64bit(process.handle())? { 8 } else { 4 };
+        let memory = MemoryScanner::new(process);
+        for offset in &self.pointer_offsets {
+            let bytes = memory.read_memory(address, pointer_size)?;
+            let pointer = match pointer_size {
+                4 => u32::from_le_bytes(bytes[..4].try_into().unwrap()) as usize,
+                _ => usize::from_le_bytes(bytes[..8].try_into().unwrap()),
+            };
+            address = pointer.wrapping_add(*offset as usize);
+        }
+
+        Ok(address)
+    }
+}
+
+/// Reads the address list of a Cheat Engine `.CT` file (read-only, partial)
+///
+/// Only entries with a plain numeric type and a numeric or "module+offset"
+/// address are imported; scripts, strings, custom types and symbol addresses
+/// are skipped. Entries nested in groups are flattened.
+pub struct CheatEngineImporter;
+
+/// Fields of a `<CheatEntry>` collected while its children are read
+#[derive(Default)]
+struct RawCheatEntry {
+    description: String,
+    address: Option<String>,
+    variable_type: Option<String>,
+    byte_length: Option<usize>,
+    offsets: Vec<String>,
+}
+
+impl RawCheatEntry {
+    fn into_entry(self) -> Option<ImportedEntry> {
+        let value_type =
+            CheatEngineImporter::map_value_type(self.variable_type.as_deref()?, self.byte_length)?;
+        let (module, address) = CheatEngineImporter::parse_address(self.address.as_deref()?)?;
+        let mut pointer_offsets = self
+            .offsets
+            .iter()
+            .map(|offset| CheatEngineImporter::parse_offset(offset))
+            .collect::<Option<Vec<_>>>()?;
+        pointer_offsets.reverse();
+
+        Some(ImportedEntry {
+            description: self.description,
+            address,
+            module,
+            value_type,
+            pointer_offsets,
+        })
+    }
+}
+
+impl CheatEngineImporter {
+    pub fn parse_ct_file(path: &Path) -> Result<Vec<ImportedEntry>> {
+        Self::parse_ct(&std::fs::read_to_string(path)?)
+    }
+
+    /// Parses the XML contents of a `.CT` file
+    pub fn parse_ct(xml: &str) -> Result<Vec<ImportedEntry>> {
+        let mut reader = Reader::from_str(xml);
+        reader.trim_text(true);
+
+        // Open elements, and the CheatEntry elements among them (groups nest entries)
+        let mut path: Vec<Vec<u8>> = Vec::new();
+        let mut open_entries: Vec<RawCheatEntry> = Vec::new();
+        let mut entries = Vec::new();
+
+        loop {
+            match reader.read_event()? {
+                Event::Start(element) => {
+                    let name = element.name().as_ref().to_vec();
+                    if name == b"CheatEntry" {
+                        open_entries.push(RawCheatEntry::default());
+                    }
+                    path.push(name);
+                }
+                Event::Text(text) => {
+                    let Some(entry) = open_entries.last_mut() else {
+                        continue;
+                    };
+                    let text = text.unescape()?.into_owned();
+
+                    // Only direct children of the entry (and its <Offsets>) count;
+                    // hotkeys and similar sub-elements reuse some of the names
+                    match path.as_slice() {
+                        [.., parent, tag] if parent == b"CheatEntry" => match tag.as_slice() {
+                            b"Description" => entry.description = text.trim_matches('"').to_string(),
+                            b"Address" => entry.address = Some(text),
+                            b"VariableType" => entry.variable_type = Some(text),
+                            b"ByteLength" => entry.byte_length = text.parse().ok(),
+                            _ => {}
+                        },
+                        [.., entry_tag, offsets, tag]
+                            if entry_tag == b"CheatEntry" && offsets == b"Offsets" && tag == b"Offset" =>
+                        {
+                            entry.offsets.push(text)
+                        }
+                        _ => {}
+                    }
+                }
+                Event::End(element) => {
+                    path.pop();
+                    if element.name().as_ref() == b"CheatEntry" {
+                        let Some(raw) = open_entries.pop() else {
+                            continue;
+                        };
+                        let description = raw.description.clone();
+                        match raw.into_entry() {
+                            Some(entry) => entries.push(entry),
+                            None => tracing::debug!("Skipping cheat entry \"{}\"", description),
+                        }
+                    }
+                }
+                Event::Eof => break,
+                _ => {}
+            }
+        }
+
+        Ok(entries)
+    }
+
+    /// Maps a CE `<VariableType>` to a value type
+    pub fn map_value_type(name: &str, byte_length: Option<usize>) -> Option<ValueType> {
+        match name {
+            "Byte" => Some(ValueType::U8),
+            "2 Bytes" => Some(ValueType::I16),
+            "4 Bytes" => Some(ValueType::I32),
+            "8 Bytes" => Some(ValueType::I64),
+            "Float" => Some(ValueType::F32),
+            "Double" => Some(ValueType::F64),
+            "Array of byte" => byte_length.filter(|&len| len > 0).map(ValueType::ByteArray),
+            _ => None,
+        }
+    }
+
+    /// Parses "1A2B3C", "0x1A2B3C" or "game.exe+1A2B" (hex) into (module, address)
+    fn parse_address(address: &str) -> Option<(Option<String>, usize)> {
+        let parse_hex = |s: &str| {
+            let s = s.trim();
+            usize::from_str_radix(s.strip_prefix("0x").unwrap_or(s), 16).ok()
+        };
+
+        match address.rsplit_once('+') {
+            Some((module, offset)) => {
+                let module = module.trim().trim_matches('"');
+                // Bracketed pointer expressions ("[game.exe+10]+8") are not supported
+                if module.contains(['[', ']']) {
+                    return None;
+                }
+                Some((Some(module.to_string()), parse_hex(offset)?))
+            }
+            None => Some((None, parse_hex(address)?)),
+        }
+    }
+
+    /// Parses a hex pointer offset, which may be negative
+    fn parse_offset(offset: &str) -> Option<i64> {
+        let offset = offset.trim();
+        let (negative, digits) = match offset.strip_prefix('-') {
+            Some(digits) => (true, digits),
+            None => (false, offset),
+        };
+        let value = i64::from_str_radix(digits.strip_prefix("0x").unwrap_or(digits), 16).ok()?;
+        Some(if negative { -value } else { value })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const FIXTURE: &str = include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/tests/fixtures/cheat_engine.CT"));
+
+    #[test]
+    fn test_parse_ct_fixture() {
+        let entries = CheatEngineImporter::parse_ct(FIXTURE).expect("Failed to parse fixture");
+
+        // The script and string entries and the group header are skipped
+        assert_eq!(
+            entries,
+            vec![
+                ImportedEntry {
+                    description: "Health".to_string(),
+                    address: 0x1A2B3C,
+                    module: None,
+                    value_type: ValueType::I32,
+                    pointer_offsets: Vec::new(),
+                },
+                ImportedEntry {
+                    description: "Gold".to_string(),
+                    address: 0x1F00,
+                    module: Some("game.exe".to_string()),
+                    value_type: ValueType::F32,
+                    pointer_offsets: vec![0x1C, -8],
+                },
+                ImportedEntry {
+                    description: "Signature".to_string(),
+                    address: 0x7FF600001000,
+                    module: None,
+                    value_type: ValueType::ByteArray(4),
+                    pointer_offsets: Vec::new(),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_address() {
+        assert_eq!(CheatEngineImporter::parse_address("1A2B"), Some((None, 0x1A2B)));
+        assert_eq!(CheatEngineImporter::parse_address("0x1A2B"), Some((None, 0x1A2B)));
+        assert_eq!(
+            CheatEngineImporter::parse_address("\"game.exe\"+10"),
+            Some((Some("game.exe".to_string()), 0x10))
+        );
+        assert_eq!(CheatEngineImporter::parse_address("[game.exe+10]+8"), None);
+        assert_eq!(CheatEngineImporter::parse_address("PlayerBase"), None);
+    }
+
+    #[test]
+    fn test_parse_offset() {
+        assert_eq!(CheatEngineImporter::parse_offset("1C"), Some(0x1C));
+        assert_eq!(CheatEngineImporter::parse_offset(" 0x10 "), Some(0x10));
+        assert_eq!(CheatEngineImporter::parse_offset("-8"), Some(-8));
+        assert_eq!(CheatEngineImporter::parse_offset("xyz"), None);
+    }
+
+    #[test]
+    fn test_map_value_type() {
+        assert_eq!(CheatEngineImporter::map_value_type("Byte", None), Some(ValueType::U8));
+        assert_eq!(CheatEngineImporter::map_value_type("2 Bytes", None), Some(ValueType::I16));
+        assert_eq!(CheatEngineImporter::map_value_type("8 Bytes", None), Some(ValueType::I64));
+        assert_eq!(CheatEngineImporter::map_value_type("Double", None), Some(ValueType::F64));
+        assert_eq!(
+            CheatEngineImporter::map_value_type("Array of byte", Some(8)),
+            Some(ValueType::ByteArray(8))
+        );
+        assert_eq!(CheatEngineImporter::map_value_type("Array of byte", None), None);
+        assert_eq!(CheatEngineImporter::map_value_type("String", Some(16)), None);
+    }
+}
@@ -2,9 +2,21 @@
 
 use crate::engine::{GameEngine, *};
 use eframe::egui;
-use std::collections::HashMap;
+use std::collections::{HashMap, VecDeque};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::mpsc::{self, Receiver, TryRecvError};
 use std::sync::{Arc, Mutex};
 
+/// バックエンド選択で選ばれたもの
+#[derive(Clone, Debug, PartialEq)]
+pub enum BackendChoice {
+    BuiltIn(DetectedEngine),
+    /// PluginRegistry に登録された名前
+    Plugin(String),
+}
+
 /// インスタンスのプロパティ値とその編集用文字列
 #[derive(Clone, Debug)]
 struct PropertyState {
@@ -19,12 +31,155 @@ struct MethodInvokeState {
     arg_strings: Vec<String>,
 }
 
+/// エンジン初期化の進行状態
+///
+/// 初期化はパターンスキャンで数秒かかるため別スレッドで実行する
+enum InitState {
+    Idle,
+    /// 結果の受信側と開始時刻
+    Running(Receiver<Result<()>>, std::time::Instant),
+    Done,
+}
+
+/// 初期化の制限時間（対象プロセスが固まっている場合に待ち続けないように）
+const INIT_TIMEOUT: std::time::Duration = std::time::Duration::from_secs(30);
+
+/// クラスダンプの既定の出力先
+const DEFAULT_CLASS_DUMP_PATH: &str = "class_dump.txt";
+
+/// バックグラウンドで実行中のクラスダンプ
+struct ClassDumpTask {
+    /// 書き込んだクラス数（結果）の受信側
+    receiver: Receiver<Result<usize>>,
+    /// 処理済みのクラス数
+    done: Arc<AtomicUsize>,
+    total: usize,
+    path: PathBuf,
+}
+
+/// ヘルスチェックの間隔
+const HEALTH_CHECK_INTERVAL: std::time::Duration = std::time::Duration::from_secs(5);
+
+/// 初期化中に表示する回転スピナーの文字
+const SPINNER_FRAMES: [char; 4] = ['|', '/', '-', '\\'];
+
+/// クラスブラウザの表示モード
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum ClassViewMode {
+    Flat,
+    Tree,
+}
+
+/// 継承ツリーの最大表示深さ
+const MAX_TREE_DEPTH: usize = 10;
+
+/// プロパティ書き込み履歴の保持件数
+const WRITE_LOG_LEN: usize = 20;
+
+/// クラスの継承ツリー（親 → 子）
+#[derive(Default)]
+struct ClassTree {
+    /// 親を持たない（または親が一覧に無い）クラス
+    roots: Vec<ClassHandle>,
+    children: HashMap<ClassHandle, Vec<ClassHandle>>,
+    /// ClassHandle -> classes 内のインデックス
+    index: HashMap<ClassHandle, usize>,
+}
+
+impl ClassTree {
+    fn build(classes: &[ClassInfo]) -> Self {
+        let index: HashMap<ClassHandle, usize> =
+            classes.iter().enumerate().map(|(i, c)| (c.handle, i)).collect();
+
+        let mut tree = Self::default();
+        for class in classes {
+            match class.parent.filter(|parent| index.contains_key(parent)) {
+                Some(parent) => tree.children.entry(parent).or_default().push(class.handle),
+                None => tree.roots.push(class.handle),
+            }
+        }
+
+        // 名前順に並べる
+        let name = |handle: &ClassHandle| classes[index[handle]].name.to_lowercase();
+        tree.roots.sort_by_cached_key(name);
+        for children in tree.children.values_mut() {
+            children.sort_by_cached_key(name);
+        }
+
+        tree.index = index;
+        tree
+    }
+}
+
+/// メソッド一覧のフラグフィルタ (flags_summary のタグで絞り込む)
+#[derive(Clone, Copy, Debug, Default)]
+struct MethodFlagFilter {
+    native_only: bool,
+    /// BlueprintCallable / BlueprintEvent / BlueprintPure のいずれか
+    blueprint_only: bool,
+    static_only: bool,
+}
+
+impl MethodFlagFilter {
+    fn matches(&self, method: &MethodInfo) -> bool {
+        let tags = method_flag_tags(&method.flags_summary);
+        (!self.native_only || tags.contains(&"Native"))
+            && (!self.blueprint_only || tags.iter().any(|t| t.starts_with("Blueprint")))
+            && (!self.static_only || method.is_static)
+    }
+}
+
+/// needle の全文字が haystack に順番通り現れるか (部分列判定、大文字小文字は無視)
+fn fuzzy_match(haystack: &str, needle: &str) -> bool {
+    fuzzy_score(haystack, needle).is_some()
+}
+
+/// 部分列として一致した場合、一致した文字の間の隙間の合計を返す (小さいほど良い一致)
+///
+/// 各文字は最初に見つかった位置で一致させる
+fn fuzzy_score(haystack: &str, needle: &str) -> Option<usize> {
+    let mut chars = haystack.chars().enumerate();
+    let mut previous: Option<usize> = None;
+    let mut gaps = 0;
+
+    for n in needle.chars() {
+        let (index, _) = chars.by_ref().find(|(_, h)| h.to_lowercase().eq(n.to_lowercase()))?;
+        if let Some(previous) = previous {
+            gaps += index - previous - 1;
+        }
+        previous = Some(index);
+    }
+
+    Some(gaps)
+}
+
+/// "[Native][Static]" → ["Native", "Static"]
+fn method_flag_tags(summary: &str) -> Vec<&str> {
+    summary
+        .split(']')
+        .filter_map(|tag| tag.strip_prefix('['))
+        .collect()
+}
+
+/// フラグタグのバッジ色
+fn method_flag_color(tag: &str) -> egui::Color32 {
+    match tag {
+        "Native" => egui::Color32::from_rgb(230, 150, 60),
+        "Static" => egui::Color32::from_rgb(180, 120, 230),
+        t if t.starts_with("Blueprint") => egui::Color32::from_rgb(80, 160, 240),
+        _ => egui::Color32::GRAY,
+    }
+}
+
 pub struct EngineView {
     /// エンジンインスタンス
     engine: Option<Arc<Mutex<Box<dyn GameEngine>>>>,
 
-    /// 初期化済みフラグ
-    initialized: bool,
+    /// エンジン名（初期化スレッドがロック中でも表示できるよう保持）
+    engine_name: &'static str,
+
+    /// 初期化の状態
+    init_state: InitState,
 
     /// 選択されたクラス
     selected_class: Option<ClassHandle>,
@@ -33,6 +188,10 @@ pub struct EngineView {
     /// クラス一覧
     classes: Vec<ClassInfo>,
 
+    /// クラスブラウザの表示モードと継承ツリー
+    class_view_mode: ClassViewMode,
+    class_tree: ClassTree,
+
     /// 選択されたクラスのメソッド
     methods: Vec<MethodInfo>,
 
@@ -45,6 +204,12 @@ pub struct EngineView {
     /// インスタンス一覧
     instances: Vec<InstanceHandle>,
 
+    /// 読み込むインスタンスの上限（大量のオブジェクトで UI が固まらないように）
+    max_instances: usize,
+
+    /// 上限で切り詰める前のインスタンス数
+    total_instances: usize,
+
     /// 選択されたインスタンス
     selected_instance: Option<InstanceHandle>,
 
@@ -62,6 +227,12 @@ pub struct EngineView {
     method_filter: String,
     field_filter: String,
 
+    /// contains で一致するクラスが無いとき、あいまい検索 (部分列一致) を使う
+    fuzzy_class_search: bool,
+
+    /// メソッド一覧のフラグフィルタ
+    method_flag_filter: MethodFlagFilter,
+
     // ===== インスタンス詳細パネル用 =====
     /// 選択されたインスタンスのプロパティ値（FieldHandle -> PropertyState）
     instance_properties: HashMap<FieldHandle, PropertyState>,
@@ -80,20 +251,68 @@ pub struct EngineView {
 
     /// 最後のメソッド呼び出し結果
     last_invoke_result: Option<String>,
+
+    /// プロパティ書き込み履歴（古い順、最大 WRITE_LOG_LEN 件）
+    write_log: VecDeque<(FieldHandle, Value, std::time::Instant)>,
+
+    // ===== コンソール (UE のみ) =====
+    /// コンソールコマンド入力
+    console_command: String,
+
+    /// 最後のコンソールコマンド出力
+    console_output: Option<String>,
+
+    // ===== シンボル (Native のみ) =====
+    /// エクスポート・インポートのシンボル一覧
+    symbols: Vec<(String, usize)>,
+
+    /// シンボルのフィルター
+    symbol_filter: String,
+
+    /// Auto-Detect ボタンが押された（app 側でプロセスを渡して処理する）
+    auto_detect_request: bool,
+
+    /// バックエンド選択で選ばれた（app 側でプロセスを渡して生成する）
+    backend_request: Option<BackendChoice>,
+
+    /// FNamePool の統計 (UE のみ、初期化完了時に取得)
+    gnames_stats: Option<unreal::FNamePoolStats>,
+
+    // ===== ヘルスチェック =====
+    /// 初期化後に定期的にポインタの有効性を確認する
+    auto_health_check: bool,
+
+    /// 最後にヘルスチェックした時刻
+    last_health_check: Option<std::time::Instant>,
+
+    /// 最後のヘルスチェックの失敗内容
+    health_warning: Option<String>,
+
+    // ===== クラスダンプ =====
+    /// クラスダンプの出力先
+    class_dump_path: String,
+
+    /// 実行中のクラスダンプ
+    class_dump: Option<ClassDumpTask>,
 }
 
 impl Default for EngineView {
     fn default() -> Self {
         Self {
             engine: None,
-            initialized: false,
+            engine_name: "",
+            init_state: InitState::Idle,
             selected_class: None,
             selected_class_name: String::new(),
             classes: Vec::new(),
+            class_view_mode: ClassViewMode::Flat,
+            class_tree: ClassTree::default(),
             methods: Vec::new(),
             fields: Vec::new(),
             selected_method: None,
             instances: Vec::new(),
+            max_instances: 1000,
+            total_instances: 0,
             selected_instance: None,
             invoke_param: String::new(),
             status_message: String::new(),
@@ -101,28 +320,87 @@ impl Default for EngineView {
             class_filter: String::new(),
             method_filter: String::new(),
             field_filter: String::new(),
+            fuzzy_class_search: true,
+            method_flag_filter: MethodFlagFilter::default(),
             instance_properties: HashMap::new(),
             instance_methods: Vec::new(),
             method_invoke_states: HashMap::new(),
             instance_method_filter: String::new(),
             instance_property_filter: String::new(),
             last_invoke_result: None,
+            write_log: VecDeque::new(),
+            console_command: String::new(),
+            console_output: None,
+            symbols: Vec::new(),
+            symbol_filter: String::new(),
+            auto_detect_request: false,
+            backend_request: None,
+            gnames_stats: None,
+            auto_health_check: true,
+            last_health_check: None,
+            health_warning: None,
+            class_dump_path: DEFAULT_CLASS_DUMP_PATH.to_string(),
+            class_dump: None,
         }
     }
 }
 
 impl EngineView {
     pub fn set_engine(&mut self, engine: Box<dyn GameEngine>) {
+        self.engine_name = engine.name();
         self.engine = Some(Arc::new(Mutex::new(engine)));
-        self.initialized = false;
+        // 実行中の初期化スレッドの結果は受信側を捨てて無視する
+        self.init_state = InitState::Idle;
+        self.clear_loaded_data();
+    }
+
+    /// 読み込み済みのクラス・インスタンスなどを破棄する
+    fn clear_loaded_data(&mut self) {
         self.classes.clear();
+        self.class_tree = ClassTree::default();
         self.methods.clear();
         self.fields.clear();
         self.instances.clear();
+        self.total_instances = 0;
         self.instance_properties.clear();
         self.instance_methods.clear();
         self.method_invoke_states.clear();
+        self.write_log.clear();
         self.selected_instance = None;
+        self.gnames_stats = None;
+        self.last_health_check = None;
+        self.health_warning = None;
+    }
+
+    /// エンジンを外す（プロセス切り替え時）
+    pub fn clear_engine(&mut self) {
+        self.engine = None;
+        self.engine_name = "";
+        self.init_state = InitState::Idle;
+        self.status_message.clear();
+        self.error_message.clear();
+        self.gnames_stats = None;
+    }
+
+    /// Auto-Detect の要求を取り出す
+    pub fn take_auto_detect_request(&mut self) -> bool {
+        std::mem::take(&mut self.auto_detect_request)
+    }
+
+    /// バックエンド選択の要求を取り出す
+    pub fn take_backend_request(&mut self) -> Option<BackendChoice> {
+        self.backend_request.take()
+    }
+
+    /// 検出結果などのステータスを表示する
+    pub fn set_status(&mut self, message: String) {
+        self.status_message = message;
+        self.error_message.clear();
+    }
+
+    /// エラーメッセージを表示する
+    pub fn set_error(&mut self, message: String) {
+        self.error_message = message;
     }
 
     pub fn ui(&mut self, ui: &mut egui::Ui) {
@@ -134,26 +412,106 @@ impl EngineView {
             return;
         }
 
+        self.poll_initialization();
+        self.poll_health_check(ui.ctx());
+        self.poll_class_dump();
+
         ui.heading("Engine Abstraction");
         ui.separator();
 
-        // エンジン情報
-        if let Some(engine) = &self.engine {
-            if let Ok(eng) = engine.lock() {
-                ui.horizontal(|ui| {
-                    ui.label("Engine:");
-                    ui.label(egui::RichText::new(eng.name()).strong());
-                    if let Some(version) = eng.version() {
-                        ui.label(format!("({})", version));
+        // エンジン情報（初期化中はスレッドがロックを持つので try_lock）
+        let running = matches!(self.init_state, InitState::Running(..));
+        let version = self
+            .engine
+            .as_ref()
+            .and_then(|engine| engine.try_lock().ok())
+            .and_then(|eng| eng.version());
+        ui.horizontal(|ui| {
+            ui.label("Engine:");
+            ui.label(egui::RichText::new(self.engine_name).strong());
+            if let Some(version) = version {
+                ui.label(format!("({})", version));
+            }
+
+            if let Some(stats) = self.gnames_stats {
+                ui.separator();
+                let text = format!(
+                    "GNames: {} blocks, {} names, ~{} KB",
+                    stats.total_blocks, stats.used_entries, stats.estimated_memory_kb
+                );
+                if stats.total_blocks == 0 {
+                    ui.colored_label(egui::Color32::YELLOW, text)
+                        .on_hover_text("No name blocks yet. The game has not loaded names; retry after it finishes loading.");
+                } else {
+                    ui.label(text);
+                }
+                if ui.small_button("⟳").on_hover_text("Refresh GNames stats").clicked() {
+                    self.load_gnames_stats();
+                }
+            }
+
+            if self.is_initialized() {
+                ui.separator();
+                ui.checkbox(&mut self.auto_health_check, "Health check")
+                    .on_hover_text("Check every 5 seconds that the engine pointers are still valid");
+                if let Some(warning) = self.health_warning.clone() {
+                    ui.colored_label(egui::Color32::YELLOW, "⚠ Stale")
+                        .on_hover_text(format!("{}\nThe game may have reloaded; re-initialize the engine.", warning));
+                    if ui.small_button("Re-initialize").clicked() {
+                        self.refresh_engine();
                     }
-                });
+                }
             }
-        }
+
+            // ロード済み DLL から適切なバックエンドを選び直す
+            if ui
+                .add_enabled(!running, egui::Button::new("Auto-Detect"))
+                .on_hover_text("Pick the backend from loaded modules (Mono / IL2CPP / Unreal / Native)")
+                .clicked()
+            {
+                self.auto_detect_request = true;
+            }
+
+            // 組み込みバックエンドと登録済みプラグインから手動で選ぶ
+            ui.add_enabled_ui(!running, |ui| {
+                egui::ComboBox::new("engine_backend", "")
+                    .selected_text(self.engine_name)
+                    .show_ui(ui, |ui| {
+                        for kind in DetectedEngine::ALL {
+                            if ui.selectable_label(false, kind.label()).clicked() {
+                                self.backend_request = Some(BackendChoice::BuiltIn(kind));
+                            }
+                        }
+
+                        let plugins = PluginRegistry::names();
+                        if !plugins.is_empty() {
+                            ui.separator();
+                            for name in plugins {
+                                if ui.selectable_label(false, &name).clicked() {
+                                    self.backend_request = Some(BackendChoice::Plugin(name));
+                                }
+                            }
+                        }
+                    })
+                    .response
+                    .on_hover_text("Switch to a built-in backend or a registered engine plugin");
+            });
+        });
 
         ui.separator();
 
+        if running {
+            let frame = (ui.input(|i| i.time) * 8.0) as usize % SPINNER_FRAMES.len();
+            ui.horizontal(|ui| {
+                ui.label(egui::RichText::new(SPINNER_FRAMES[frame].to_string()).monospace().strong());
+                ui.label("Initializing...");
+            });
+            ui.ctx().request_repaint();
+            return;
+        }
+
         // 初期化ボタン
-        if !self.initialized {
+        if !self.is_initialized() {
             ui.horizontal(|ui| {
                 if ui.button("Initialize Engine").clicked() {
                     self.initialize_engine();
@@ -170,7 +528,11 @@ impl EngineView {
                 ui.colored_label(egui::Color32::RED, &self.error_message);
 
                 // エラーの場合、リトライボタンを表示
-                if self.error_message.contains("not initialized yet") {
+                if self.can_refresh_after_error() {
+                    if ui.button("Refresh Engine").clicked() {
+                        self.refresh_engine();
+                    }
+                } else if self.error_message.contains("not initialized yet") {
                     if ui.button("Retry Initialization").clicked() {
                         self.initialize_engine();
                     }
@@ -180,47 +542,160 @@ impl EngineView {
             return;
         }
 
+        // コンソールコマンド (UE のみ)
+        if self.is_unreal_engine() {
+            ui.collapsing("Console", |ui| {
+                ui.horizontal(|ui| {
+                    ui.label("Command:");
+                    let response = ui.text_edit_singleline(&mut self.console_command);
+                    let submitted =
+                        response.lost_focus() && ui.input(|i| i.key_pressed(egui::Key::Enter));
+                    if ui.button("Execute").clicked() || submitted {
+                        self.execute_console_command();
+                    }
+                });
+
+                if let Some(output) = &self.console_output {
+                    ui.label(format!("Output: {}", output));
+                }
+            });
+
+            // よく使うオブジェクトへのショートカット
+            ui.horizontal(|ui| {
+                if ui.button("Jump to UWorld").clicked() {
+                    self.jump_to_ue_object(|ue| ue.find_uworld());
+                }
+                if ui.button("Jump to GameState").clicked() {
+                    self.jump_to_ue_object(|ue| ue.get_game_state());
+                }
+            });
+
+            ui.separator();
+        }
+
+        // シンボル一覧 (Native のみ)
+        if self.is_native_engine() {
+            ui.collapsing("Symbols", |ui| {
+                ui.horizontal(|ui| {
+                    ui.label("Filter:");
+                    ui.text_edit_singleline(&mut self.symbol_filter);
+                    if ui.button("Refresh Symbols").clicked() {
+                        self.load_symbols();
+                    }
+                });
+
+                ui.label(format!("Found {} symbols", self.symbols.len()));
+
+                egui::ScrollArea::vertical()
+                    .id_salt("symbols_scroll")
+                    .max_height(200.0)
+                    .show(ui, |ui| {
+                        let filter = self.symbol_filter.to_lowercase();
+                        for (name, addr) in &self.symbols {
+                            if !filter.is_empty() && !name.to_lowercase().contains(&filter) {
+                                continue;
+                            }
+                            ui.horizontal(|ui| {
+                                ui.monospace(format!("0x{:016X}", addr));
+                                ui.label(name);
+                            });
+                        }
+                    });
+            });
+
+            ui.separator();
+        }
+
         // クラスブラウザ
         ui.collapsing("Class Browser", |ui| {
             ui.horizontal(|ui| {
                 ui.label("Filter:");
                 ui.text_edit_singleline(&mut self.class_filter);
+                ui.checkbox(&mut self.fuzzy_class_search, "Fuzzy")
+                    .on_hover_text("When nothing contains the filter, match its characters in order (\"plyr\" finds \"PlayerCharacter\")");
                 if ui.button("Refresh Classes").clicked() {
                     self.load_classes();
                 }
             });
 
+            // 全クラスのフィールド・メソッドをテキストに書き出す
+            ui.horizontal(|ui| match &self.class_dump {
+                Some(task) => {
+                    let done = task.done.load(Ordering::Relaxed);
+                    ui.add(
+                        egui::ProgressBar::new(done as f32 / task.total.max(1) as f32)
+                            .text(format!("Dumping classes... {} / {}", done, task.total))
+                            .desired_width(300.0),
+                    );
+                    ui.ctx().request_repaint();
+                }
+                None => {
+                    ui.label("Dump to:");
+                    ui.text_edit_singleline(&mut self.class_dump_path);
+                    if ui
+                        .add_enabled(!self.classes.is_empty(), egui::Button::new("Dump Classes"))
+                        .on_hover_text("Write every class with its fields and methods to a text file")
+                        .clicked()
+                    {
+                        let path = PathBuf::from(&self.class_dump_path);
+                        if let Err(e) = self.export_class_dump(&path) {
+                            self.error_message = format!("Failed to dump classes: {}", e);
+                        }
+                    }
+                }
+            });
+
             ui.separator();
 
-            egui::ScrollArea::vertical().max_height(200.0).show(ui, |ui| {
-                let filter = self.class_filter.to_lowercase();
-                let filtered: Vec<_> = self
-                    .classes
-                    .iter()
-                    .filter(|c| filter.is_empty() || c.name.to_lowercase().contains(&filter))
-                    .collect();
+            ui.horizontal(|ui| {
+                ui.label("View:");
+                ui.selectable_value(&mut self.class_view_mode, ClassViewMode::Flat, "Flat");
+                ui.selectable_value(&mut self.class_view_mode, ClassViewMode::Tree, "Tree");
+                if self.class_view_mode == ClassViewMode::Tree {
+                    ui.label(egui::RichText::new("(filter applies to the flat view)").weak());
+                }
+            });
 
-                let mut clicked_class: Option<(ClassHandle, String)> = None;
+            let mut clicked_class: Option<ClassHandle> = None;
 
-                for class in filtered {
-                    let selected = self
-                        .selected_class
-                        .map(|c| c == class.handle)
-                        .unwrap_or(false);
+            egui::ScrollArea::vertical()
+                .id_salt("class_browser_scroll")
+                .max_height(200.0)
+                .show(ui, |ui| match self.class_view_mode {
+                    ClassViewMode::Flat => {
+                        let filter = self.class_filter.to_lowercase();
+                        let mut filtered: Vec<&ClassInfo> = self
+                            .classes
+                            .iter()
+                            .filter(|c| filter.is_empty() || c.name.to_lowercase().contains(&filter))
+                            .collect();
+
+                        // 完全な部分文字列で見つからなければ、隙間の小さい順にあいまい一致を表示
+                        if filtered.is_empty() && self.fuzzy_class_search && !filter.is_empty() {
+                            filtered = self.classes.iter().filter(|c| fuzzy_match(&c.name, &filter)).collect();
+                            filtered.sort_by_cached_key(|c| fuzzy_score(&c.name, &filter));
+                            if !filtered.is_empty() {
+                                ui.label(egui::RichText::new("No exact matches, showing fuzzy matches").weak());
+                            }
+                        }
 
-                    if ui.selectable_label(selected, &class.name).clicked() {
-                        clicked_class = Some((class.handle, class.name.clone()));
+                        for class in filtered {
+                            let selected = self.selected_class == Some(class.handle);
+                            if ui.selectable_label(selected, &class.name).clicked() {
+                                clicked_class = Some(class.handle);
+                            }
+                        }
                     }
-                }
+                    ClassViewMode::Tree => {
+                        for &root in &self.class_tree.roots {
+                            self.class_tree_node(ui, root, 0, &mut clicked_class);
+                        }
+                    }
+                });
 
-                if let Some((handle, name)) = clicked_class {
-                    self.selected_class = Some(handle);
-                    self.selected_class_name = name;
-                    self.load_methods();
-                    self.load_fields();
-                    self.load_instances();
-                }
-            });
+            if let Some(handle) = clicked_class {
+                self.select_class(handle);
+            }
 
             if !self.selected_class_name.is_empty() {
                 ui.label(format!("Selected: {}", self.selected_class_name));
@@ -237,6 +712,16 @@ impl EngineView {
                     ui.text_edit_singleline(&mut self.method_filter);
                 });
 
+                let is_unreal = self.is_unreal_engine();
+                ui.horizontal(|ui| {
+                    let filter = &mut self.method_flag_filter;
+                    if is_unreal {
+                        ui.checkbox(&mut filter.native_only, "Native only");
+                        ui.checkbox(&mut filter.blueprint_only, "Blueprint only");
+                    }
+                    ui.checkbox(&mut filter.static_only, "Static only");
+                });
+
                 ui.label(format!("Found {} methods", self.methods.len()));
 
                 egui::ScrollArea::vertical().max_height(200.0).show(ui, |ui| {
@@ -249,6 +734,7 @@ impl EngineView {
                                     .to_lowercase()
                                     .contains(&self.method_filter.to_lowercase())
                         })
+                        .filter(|m| self.method_flag_filter.matches(m))
                         .collect();
 
                     for method in filtered {
@@ -257,9 +743,19 @@ impl EngineView {
                             .map(|m| m == method.handle)
                             .unwrap_or(false);
 
-                        if ui.selectable_label(selected, &method.name).clicked() {
-                            self.selected_method = Some(method.handle);
-                        }
+                        ui.horizontal(|ui| {
+                            if ui.selectable_label(selected, &method.name).clicked() {
+                                self.selected_method = Some(method.handle);
+                            }
+                            for tag in method_flag_tags(&method.flags_summary) {
+                                ui.label(
+                                    egui::RichText::new(tag)
+                                        .small()
+                                        .color(egui::Color32::WHITE)
+                                        .background_color(method_flag_color(tag)),
+                                );
+                            }
+                        });
                     }
                 });
             });
@@ -297,8 +793,32 @@ impl EngineView {
             ui.separator();
 
             // インスタンス選択
+            ui.horizontal(|ui| {
+                ui.label("Max instances to load:");
+                let response = ui.add(
+                    egui::DragValue::new(&mut self.max_instances)
+                        .range(1..=1_000_000)
+                        .speed(10),
+                );
+                // ドラッグ中に毎フレーム GObjects を走査しないよう、確定時のみ再読み込み
+                if response.drag_stopped() || response.lost_focus() {
+                    self.load_instances();
+                }
+            });
+
             ui.collapsing("Instances", |ui| {
-                ui.label(format!("Found {} instances", self.instances.len()));
+                if self.total_instances > self.instances.len() {
+                    ui.colored_label(
+                        egui::Color32::YELLOW,
+                        format!(
+                            "Showing {} of {} — narrow your selection",
+                            self.instances.len(),
+                            self.total_instances
+                        ),
+                    );
+                } else {
+                    ui.label(format!("Found {} instances", self.instances.len()));
+                }
 
                 let mut clicked_instance: Option<InstanceHandle> = None;
                 egui::ScrollArea::vertical().max_height(150.0).show(ui, |ui| {
@@ -337,42 +857,408 @@ impl EngineView {
 
         if !self.error_message.is_empty() {
             ui.separator();
-            ui.colored_label(egui::Color32::RED, &self.error_message);
+            ui.horizontal(|ui| {
+                ui.colored_label(egui::Color32::RED, &self.error_message);
+                if self.can_refresh_after_error() && ui.button("Refresh Engine").clicked() {
+                    self.refresh_engine();
+                }
+            });
         }
     }
 
+    /// 別スレッドでエンジンを初期化する
     fn initialize_engine(&mut self) {
-        let result = if let Some(engine) = &self.engine {
-            if let Ok(mut eng) = engine.lock() {
-                eng.initialize()
-            } else {
-                return;
-            }
-        } else {
+        self.start_initialization(false);
+        self.status_message = "Initializing engine...".to_string();
+    }
+
+    /// UE の GNames / GObjects を検索し直す（ゲームのロード完了前に初期化した場合など）
+    fn refresh_engine(&mut self) {
+        self.clear_loaded_data();
+        self.start_initialization(true);
+        self.status_message = "Refreshing engine...".to_string();
+    }
+
+    /// 初期化スレッドを起動する。refresh なら UE のアドレスとキャッシュを作り直す
+    fn start_initialization(&mut self, refresh: bool) {
+        let Some(engine) = self.engine.clone() else {
             return;
         };
 
-        self.status_message = "Initializing engine...".to_string();
+        let (sender, receiver) = mpsc::channel();
+        std::thread::spawn(move || {
+            let result = match engine.lock() {
+                Ok(mut eng) => match eng.as_any_mut().downcast_mut::<unreal::UnrealEngine>() {
+                    Some(ue) if refresh => ue.refresh(),
+                    _ => eng.initialize(),
+                },
+                Err(_) => Err(EngineError::InitializationFailed("Engine lock poisoned".into())),
+            };
+            // 受信側が破棄されていれば（エンジン差し替え後）結果は捨てる
+            let _ = sender.send(result);
+        });
+
+        self.init_state = InitState::Running(receiver, std::time::Instant::now());
         self.error_message.clear();
+    }
+
+    /// エラーが未初期化・初期化失敗によるもので、UE の再検索で直る可能性があるか
+    fn can_refresh_after_error(&self) -> bool {
+        let message = &self.error_message;
+        (message.contains(&EngineError::NotInitialized.to_string())
+            || message.contains("Engine initialization failed"))
+            && self.is_unreal_engine()
+    }
+
+    /// 初期化スレッドの結果を確認する
+    fn poll_initialization(&mut self) {
+        let InitState::Running(receiver, started) = &self.init_state else {
+            return;
+        };
+
+        // 制限時間を過ぎたら受信側を破棄して諦める（スレッド自体は止められない）
+        let result = match receiver.try_recv() {
+            Ok(result) => result,
+            Err(TryRecvError::Empty) if started.elapsed() >= INIT_TIMEOUT => {
+                Err(EngineError::Timeout(started.elapsed()))
+            }
+            Err(TryRecvError::Empty) => return,
+            Err(TryRecvError::Disconnected) => {
+                Err(EngineError::InitializationFailed("Initialization thread panicked".into()))
+            }
+        };
 
         match result {
-            Ok(_) => {
-                self.initialized = true;
+            Ok(()) => {
+                self.init_state = InitState::Done;
                 self.status_message = "Engine initialized successfully!".to_string();
+                self.load_gnames_stats();
                 self.load_classes();
             }
             Err(e) => {
+                self.init_state = InitState::Idle;
                 self.error_message = format!("Failed to initialize: {}", e);
                 self.status_message.clear();
             }
         }
     }
 
+    /// 初期化済みなら HEALTH_CHECK_INTERVAL ごとにヘルスチェックを行う
+    fn poll_health_check(&mut self, ctx: &egui::Context) {
+        if !self.auto_health_check || !self.is_initialized() {
+            return;
+        }
+
+        // 操作がない間もチェックが走るように再描画を予約する
+        ctx.request_repaint_after(HEALTH_CHECK_INTERVAL);
+        if self.last_health_check.is_some_and(|last| last.elapsed() < HEALTH_CHECK_INTERVAL) {
+            return;
+        }
+
+        // 他の処理がロック中なら次のフレームで再試行する
+        let Some(engine) = &self.engine else {
+            return;
+        };
+        let Ok(eng) = engine.try_lock() else {
+            return;
+        };
+
+        self.health_warning = eng.health_check().err().map(|e| e.to_string());
+        self.last_health_check = Some(std::time::Instant::now());
+        if let Some(warning) = &self.health_warning {
+            tracing::warn!("Engine health check failed: {}", warning);
+        }
+    }
+
+    /// 読み込み済みの全クラスのフィールドとメソッドを別スレッドでテキストファイルに書き出す
+    ///
+    /// 形式: `ClassName (0xADDR, size 0x40) { [+0x00 FloatProperty Health] ... }`。
+    /// クラス数が多いとメソッド列挙に時間がかかるため、進捗は UI に表示する
+    pub fn export_class_dump(&mut self, path: &Path) -> Result<()> {
+        let Some(engine) = self.engine.clone() else {
+            return Err(EngineError::NotInitialized);
+        };
+        if self.class_dump.is_some() {
+            return Err(EngineError::UnsupportedOperation("A class dump is already running".into()));
+        }
+
+        // 書き込めない場合はスレッドを起動する前に失敗させる
+        let file = std::fs::File::create(path)?;
+        let classes = self.classes.clone();
+        let done = Arc::new(AtomicUsize::new(0));
+
+        let (sender, receiver) = mpsc::channel();
+        let progress = done.clone();
+        std::thread::spawn(move || {
+            let result = Self::write_class_dump(&engine, &classes, file, &progress);
+            let _ = sender.send(result);
+        });
+
+        self.class_dump = Some(ClassDumpTask {
+            receiver,
+            done,
+            total: self.classes.len(),
+            path: path.to_path_buf(),
+        });
+        self.status_message = format!("Dumping {} classes...", self.classes.len());
+        self.error_message.clear();
+        Ok(())
+    }
+
+    /// クラスごとにロックを取り直して書き出す（UI 側の操作を長時間止めないため）
+    fn write_class_dump(
+        engine: &Mutex<Box<dyn GameEngine>>,
+        classes: &[ClassInfo],
+        file: std::fs::File,
+        progress: &AtomicUsize,
+    ) -> Result<usize> {
+        let mut out = std::io::BufWriter::new(file);
+
+        for class in classes {
+            let (mut fields, methods) = {
+                let eng = engine
+                    .lock()
+                    .map_err(|_| EngineError::PlatformError(anyhow::anyhow!("Engine lock poisoned")))?;
+                (
+                    eng.enumerate_fields(class.handle).unwrap_or_default(),
+                    eng.enumerate_methods(class.handle).unwrap_or_default(),
+                )
+            };
+            fields.sort_by_key(|f| f.offset);
+
+            writeln!(out, "{} (0x{:X}, size 0x{:X}) {{", class.name, class.handle.0, class.size)?;
+            for field in &fields {
+                writeln!(out, "    [+0x{:02X} {} {}]", field.offset, field.type_info.name, field.name)?;
+            }
+            for method in &methods {
+                let params: Vec<String> = method
+                    .params
+                    .iter()
+                    .map(|p| format!("{} {}", p.type_info.name, p.name))
+                    .collect();
+                let return_type = method.return_type.as_ref().map_or("void", |t| t.name.as_str());
+                writeln!(out, "    {} {}({}) {}", return_type, method.name, params.join(", "), method.flags_summary)?;
+            }
+            writeln!(out, "}}")?;
+            writeln!(out)?;
+
+            progress.fetch_add(1, Ordering::Relaxed);
+        }
+
+        out.flush()?;
+        Ok(classes.len())
+    }
+
+    /// クラスダンプの完了を確認する
+    fn poll_class_dump(&mut self) {
+        let Some(task) = &self.class_dump else {
+            return;
+        };
+
+        let result = match task.receiver.try_recv() {
+            Ok(result) => result,
+            Err(TryRecvError::Empty) => return,
+            Err(TryRecvError::Disconnected) => {
+                Err(EngineError::PlatformError(anyhow::anyhow!("Class dump thread panicked")))
+            }
+        };
+
+        match result {
+            Ok(count) => {
+                self.status_message = format!("Dumped {} classes to {}", count, task.path.display());
+                self.error_message.clear();
+            }
+            Err(e) => {
+                self.error_message = format!("Failed to dump classes: {}", e);
+            }
+        }
+        self.class_dump = None;
+    }
+
+    /// UE の場合に FNamePool の統計を読み込む
+    fn load_gnames_stats(&mut self) {
+        let Some(engine) = &self.engine else {
+            return;
+        };
+        let Ok(eng) = engine.lock() else {
+            return;
+        };
+        let Some(ue) = eng.as_any().downcast_ref::<unreal::UnrealEngine>() else {
+            return;
+        };
+
+        self.gnames_stats = match ue.gnames_stats() {
+            Ok(stats) => Some(stats),
+            Err(e) => {
+                tracing::warn!("Failed to read GNames stats: {}", e);
+                None
+            }
+        };
+    }
+
+    fn is_initialized(&self) -> bool {
+        matches!(self.init_state, InitState::Done)
+    }
+
+    /// エンジンが Unreal Engine バックエンドかどうか
+    fn is_unreal_engine(&self) -> bool {
+        let Some(engine) = &self.engine else {
+            return false;
+        };
+        let Ok(eng) = engine.lock() else {
+            return false;
+        };
+        eng.as_any().downcast_ref::<unreal::UnrealEngine>().is_some()
+    }
+
+    /// エンジンが Native バックエンドかどうか
+    fn is_native_engine(&self) -> bool {
+        let Some(engine) = &self.engine else {
+            return false;
+        };
+        let Ok(eng) = engine.lock() else {
+            return false;
+        };
+        eng.as_any().downcast_ref::<native::NativeEngine>().is_some()
+    }
+
+    /// シンボル一覧を読み込む (Native のみ)
+    fn load_symbols(&mut self) {
+        let Some(engine) = &self.engine else { return };
+        let Ok(eng) = engine.lock() else { return };
+        let Some(native) = eng.as_any().downcast_ref::<native::NativeEngine>() else {
+            return;
+        };
+
+        self.symbols = native.enumerate_all_symbols();
+        self.status_message = format!("Loaded {} symbols", self.symbols.len());
+        self.error_message.clear();
+    }
+
+    /// UE オブジェクトを検索し、そのクラスとインスタンスをブラウザで選択する
+    fn jump_to_ue_object(
+        &mut self,
+        find: impl FnOnce(&unreal::UnrealEngine) -> crate::engine::Result<InstanceHandle>,
+    ) {
+        let Some(engine) = &self.engine else { return };
+        let Ok(eng) = engine.lock() else { return };
+        let Some(ue) = eng.as_any().downcast_ref::<unreal::UnrealEngine>() else {
+            return;
+        };
+
+        let found = find(ue).and_then(|instance| {
+            let class = eng.get_instance_class(instance)?;
+            let class_info = eng.get_class_info(class)?;
+            Ok((instance, class, class_info.name))
+        });
+        drop(eng);
+
+        match found {
+            Ok((instance, class, class_name)) => {
+                self.selected_class = Some(class);
+                self.selected_class_name = class_name;
+                self.load_methods();
+                self.load_fields();
+                self.load_instances();
+
+                // 列挙に含まれない場合もあるので追加しておく
+                if !self.instances.contains(&instance) {
+                    self.instances.push(instance);
+                }
+                self.selected_instance = Some(instance);
+                self.load_instance_details();
+            }
+            Err(e) => {
+                self.error_message = format!("Object not found: {}", e);
+            }
+        }
+    }
+
+    /// コンソールコマンドを実行 (UE のみ)
+    fn execute_console_command(&mut self) {
+        let command = self.console_command.trim().to_string();
+        if command.is_empty() {
+            return;
+        }
+
+        let Some(engine) = &self.engine else { return };
+        let Ok(eng) = engine.lock() else { return };
+        let Some(ue) = eng.as_any().downcast_ref::<unreal::UnrealEngine>() else {
+            return;
+        };
+
+        match ue.execute_console_command(&command) {
+            Ok(output) => {
+                self.console_output = output;
+                self.status_message = format!("Executed console command: {}", command);
+                self.error_message.clear();
+            }
+            Err(e) => {
+                self.console_output = None;
+                self.error_message = format!("Console command failed: {}", e);
+            }
+        }
+    }
+
+    /// 継承ツリーの 1 ノードを描画（子を持つノードは折りたたみ可能）
+    fn class_tree_node(
+        &self,
+        ui: &mut egui::Ui,
+        handle: ClassHandle,
+        depth: usize,
+        clicked: &mut Option<ClassHandle>,
+    ) {
+        let Some(class) = self.class_tree.index.get(&handle).map(|&i| &self.classes[i]) else {
+            return;
+        };
+        let selected = self.selected_class == Some(handle);
+        let children = self
+            .class_tree
+            .children
+            .get(&handle)
+            .filter(|_| depth + 1 < MAX_TREE_DEPTH);
+
+        let Some(children) = children else {
+            if ui.selectable_label(selected, &class.name).clicked() {
+                *clicked = Some(handle);
+            }
+            return;
+        };
+
+        // ヘッダーのクリックで選択、矢印で開閉する
+        let id = ui.make_persistent_id(("class_tree", handle.0));
+        egui::collapsing_header::CollapsingState::load_with_default_open(ui.ctx(), id, depth == 0)
+            .show_header(ui, |ui| {
+                let label = format!("{} ({})", class.name, children.len());
+                if ui.selectable_label(selected, label).clicked() {
+                    *clicked = Some(handle);
+                }
+            })
+            .body(|ui| {
+                for &child in children {
+                    self.class_tree_node(ui, child, depth + 1, clicked);
+                }
+            });
+    }
+
+    /// クラスを選択してメソッド・フィールド・インスタンスを読み込む
+    fn select_class(&mut self, handle: ClassHandle) {
+        let Some(class) = self.classes.iter().find(|c| c.handle == handle) else {
+            return;
+        };
+        self.selected_class = Some(handle);
+        self.selected_class_name = class.name.clone();
+        self.load_methods();
+        self.load_fields();
+        self.load_instances();
+    }
+
     fn load_classes(&mut self) {
         if let Some(engine) = &self.engine {
             if let Ok(eng) = engine.lock() {
                 match eng.enumerate_classes() {
                     Ok(classes) => {
+                        self.class_tree = ClassTree::build(&classes);
                         self.classes = classes;
                         self.status_message = format!("Loaded {} classes", self.classes.len());
                         self.error_message.clear();
@@ -430,10 +1316,12 @@ impl EngineView {
             if let Some(engine) = &self.engine {
                 if let Ok(eng) = engine.lock() {
                     match eng.get_instances(class) {
-                        Ok(instances) => {
+                        Ok(mut instances) => {
+                            self.total_instances = instances.len();
+                            instances.truncate(self.max_instances);
                             self.instances = instances;
                             self.status_message =
-                                format!("Found {} instances", self.instances.len());
+                                format!("Found {} instances", self.total_instances);
                             self.error_message.clear();
                         }
                         Err(e) => {
@@ -591,6 +1479,63 @@ impl EngineView {
                     self.render_methods_invoker(ui, instance);
                 });
         });
+
+        ui.separator();
+
+        // ===== 書き込み履歴 =====
+        self.render_write_history(ui);
+    }
+
+    /// プロパティ書き込み履歴を描画（クリックで値を編集欄に戻す）
+    fn render_write_history(&mut self, ui: &mut egui::Ui) {
+        let mut restore: Option<(FieldHandle, String)> = None;
+
+        ui.collapsing(format!("Write History ({})", self.write_log.len()), |ui| {
+            if self.write_log.is_empty() {
+                ui.label("No writes yet.");
+                return;
+            }
+
+            let now = crate::platform::local_seconds_of_day() as u64;
+            egui::ScrollArea::vertical()
+                .id_salt("write_history_scroll")
+                .max_height(200.0)
+                .show(ui, |ui| {
+                    for (field_handle, value, timestamp) in self.write_log.iter().rev() {
+                        // 記録時刻 = 現在のローカル時刻 - 経過時間
+                        let secs = (now + 86400 - timestamp.elapsed().as_secs() % 86400) % 86400;
+                        let name = self
+                            .fields
+                            .iter()
+                            .find(|f| f.handle == *field_handle)
+                            .map(|f| f.name.as_str())
+                            .unwrap_or("?");
+                        let text = format!(
+                            "{:02}:{:02}:{:02} {} ← {}",
+                            secs / 3600,
+                            secs / 60 % 60,
+                            secs % 60,
+                            name,
+                            value
+                        );
+
+                        if ui
+                            .add(egui::Label::new(egui::RichText::new(text).monospace()).sense(egui::Sense::click()))
+                            .on_hover_text("Click to restore this value to the edit field")
+                            .clicked()
+                        {
+                            restore = Some((*field_handle, Self::value_to_edit_string(value)));
+                        }
+                    }
+                });
+        });
+
+        if let Some((field_handle, edit_string)) = restore {
+            if let Some(state) = self.instance_properties.get_mut(&field_handle) {
+                state.edit_string = edit_string;
+                state.is_dirty = true;
+            }
+        }
     }
 
     /// プロパティエディタを描画
@@ -622,6 +1567,33 @@ impl EngineView {
                     ui.label(format!("[0x{:X}]", field.offset));
                 });
 
+                // FText は読み取り専用（書き込みは未対応）
+                if field.type_info.name == "TextProperty" {
+                    ui.horizontal(|ui| {
+                        ui.label("Value:");
+                        ui.label(prop_state.value.to_string());
+                        ui.label(egui::RichText::new("(read-only)").weak());
+                    });
+                    return;
+                }
+
+                // デリゲートはバインド先の一覧を表示するだけ（読み取り専用）
+                if field.type_info.name.ends_with("DelegateProperty") {
+                    let Value::Array(bindings) = &prop_state.value else {
+                        ui.label(egui::RichText::new("(unsupported delegate)").weak());
+                        return;
+                    };
+                    ui.label(format!("Bound: {} (read-only)", bindings.len()));
+                    for binding in bindings {
+                        if let Value::Array(pair) = binding {
+                            if let [Value::Object(object), Value::String(function)] = pair.as_slice() {
+                                ui.label(format!("  0x{:X} → {}", object.0, function));
+                            }
+                        }
+                    }
+                    return;
+                }
+
                 ui.horizontal(|ui| {
                     ui.label("Value:");
 
@@ -801,6 +1773,10 @@ impl EngineView {
                     state.edit_string = Self::value_to_edit_string(&value);
                     state.is_dirty = false;
                 }
+                if self.write_log.len() >= WRITE_LOG_LEN {
+                    self.write_log.pop_front();
+                }
+                self.write_log.push_back((field_handle, value, std::time::Instant::now()));
                 self.status_message = "Property written successfully".to_string();
                 self.error_message.clear();
             }
@@ -857,6 +1833,12 @@ impl EngineView {
             Value::Object(h) => format!("0x{:X}", h.0),
             Value::Array(arr) => format!("[{} items]", arr.len()),
             Value::Struct(bytes) => format!("Struct[{} bytes]", bytes.len()),
+            Value::Vector3(x, y, z) => format!("{} {} {}", x, y, z),
+            // double 精度を活かすため小数 6 桁で表示
+            Value::Vector3D(x, y, z) => format!("{:.6}, {:.6}, {:.6}", x, y, z),
+            Value::Quaternion(x, y, z, w) => format!("{} {} {} {}", x, y, z, w),
+            Value::QuaternionD(x, y, z, w) => format!("{:.6}, {:.6}, {:.6}, {:.6}", x, y, z, w),
+            Value::Color(r, g, b, a) => format!("{} {} {} {}", r, g, b, a),
         }
     }
 
@@ -869,6 +1851,56 @@ impl EngineView {
             return Some(Value::Null);
         }
 
+        // FVector / FQuat / FColor は "x y z" 形式（カンマ区切りも可）
+        let components = || -> Vec<&str> {
+            s.trim_matches(|c| c == '(' || c == ')')
+                .split(|c: char| c == ',' || c.is_whitespace())
+                .filter(|c| !c.is_empty())
+                .collect()
+        };
+        match type_info.name.as_str() {
+            // 12 バイトなら float x3、それ以外（LWC）は double x3
+            "FVector" if type_info.size == 12 => {
+                let values: Vec<f32> = components().iter().map(|c| c.parse().ok()).collect::<Option<_>>()?;
+                return match values[..] {
+                    [x, y, z] => Some(Value::Vector3(x, y, z)),
+                    _ => None,
+                };
+            }
+            "FVector" => {
+                let values: Vec<f64> = components().iter().map(|c| c.parse().ok()).collect::<Option<_>>()?;
+                return match values[..] {
+                    [x, y, z] => Some(Value::Vector3D(x, y, z)),
+                    _ => None,
+                };
+            }
+            // FVector と同様に 16 バイトなら float x4、それ以外（LWC）は double x4
+            "FQuat" if type_info.size == 16 => {
+                let values: Vec<f32> = components().iter().map(|c| c.parse().ok()).collect::<Option<_>>()?;
+                return match values[..] {
+                    [x, y, z, w] => Some(Value::Quaternion(x, y, z, w)),
+                    _ => None,
+                };
+            }
+            "FQuat" => {
+                let values: Vec<f64> = components().iter().map(|c| c.parse().ok()).collect::<Option<_>>()?;
+                return match values[..] {
+                    [x, y, z, w] => Some(Value::QuaternionD(x, y, z, w)),
+                    _ => None,
+                };
+            }
+            // アルファ省略時は不透明
+            "FColor" => {
+                let values: Vec<u8> = components().iter().map(|c| c.parse().ok()).collect::<Option<_>>()?;
+                return match values[..] {
+                    [r, g, b] => Some(Value::Color(r, g, b, 255)),
+                    [r, g, b, a] => Some(Value::Color(r, g, b, a)),
+                    _ => None,
+                };
+            }
+            _ => {}
+        }
+
         match &type_info.kind {
             TypeKind::Primitive(prim) => match prim {
                 PrimitiveType::Bool => {
@@ -902,7 +1934,7 @@ impl EngineView {
     }
 
     /// 16進数または10進数の u64 をパース
-    fn parse_u64(s: &str) -> Option<u64> {
+    pub(crate) fn parse_u64(s: &str) -> Option<u64> {
         let s = s.trim();
         if s.starts_with("0x") || s.starts_with("0X") {
             u64::from_str_radix(&s[2..], 16).ok()
@@ -0,0 +1,223 @@
+use crate::scanner::{MemoryScanner, Scanner};
+use eframe::egui;
+use std::sync::{Arc, Mutex};
+
+/// Bytes per row in the hex view
+const BYTES_PER_ROW: usize = 16;
+
+/// Interval at which the displayed memory is re-read while the inputs stay the same
+const REFRESH_INTERVAL: f64 = 0.5;
+
+/// UI component for inspecting raw process memory
+pub struct MemoryView {
+    /// "Go to Address" input (hex)
+    pub base_address: String,
+    /// Number of rows to display (8-256)
+    pub rows: usize,
+    /// Bytes read at the current address (None for unreadable rows)
+    pub data: Vec<Option<u8>>,
+    /// Treat the 8-byte value at the address as a pointer and display its target
+    follow_pointer: bool,
+    /// Address the data was read from (after following the pointer)
+    display_address: Option<usize>,
+    /// Highlighted byte address
+    selected: Option<usize>,
+    error: Option<String>,
+    /// Inputs (address text, rows, follow pointer) of the last read and when it happened
+    last_read: Option<(String, usize, bool, f64)>,
+}
+
+impl Default for MemoryView {
+    fn default() -> Self {
+        Self {
+            base_address: String::new(),
+            rows: 16,
+            data: Vec::new(),
+            follow_pointer: false,
+            display_address: None,
+            selected: None,
+            error: None,
+            last_read: None,
+        }
+    }
+}
+
+impl MemoryView {
+    /// Parses the hex address input (with or without "0x")
+    fn parse_address(input: &str) -> Option<usize> {
+        let trimmed = input.trim();
+        let hex = trimmed
+            .strip_prefix("0x")
+            .or_else(|| trimmed.strip_prefix("0X"))
+            .unwrap_or(trimmed);
+        usize::from_str_radix(hex, 16).ok()
+    }
+
+    /// Jumps to the given address and highlights it
+    pub fn go_to(&mut self, address: usize) {
+        self.base_address = format!("0x{:X}", address);
+        self.follow_pointer = false;
+        self.selected = Some(address);
+    }
+
+    /// Whether the inputs changed since the last read or the refresh interval passed
+    fn needs_read(&self, now: f64) -> bool {
+        match &self.last_read {
+            Some((address, rows, follow_pointer, time)) => {
+                *address != self.base_address
+                    || *rows != self.rows
+                    || *follow_pointer != self.follow_pointer
+                    || now - time >= REFRESH_INTERVAL
+            }
+            None => true,
+        }
+    }
+
+    /// Reads the displayed memory from the target process
+    fn read(&mut self, scanner: &Scanner) {
+        self.data.clear();
+        self.display_address = None;
+
+        if self.base_address.trim().is_empty() {
+            self.error = None;
+            return;
+        }
+
+        let Some(mut address) = Self::parse_address(&self.base_address) else {
+            self.error = Some("Invalid address".to_string());
+            return;
+        };
+
+        let memory = MemoryScanner::new(scanner.process());
+
+        if self.follow_pointer {
+            match memory.read_memory(address, 8) {
+                Ok(bytes) => address = usize::from_le_bytes(bytes[..8].try_into().unwrap()),
+                Err(e) => {
+                    self.error = Some(format!("Failed to read pointer: {}", e));
+                    return;
+                }
+            }
+        }
+
+        self.display_address = Some(address);
+        self.error = None;
+
+        // Read row by row so unreadable pages only blank out the affected rows.
+        // Rows that would run past the end of the address space are left out
+        for row in 0..self.rows {
+            let Some(row_end) = address.checked_add(row * BYTES_PER_ROW + BYTES_PER_ROW - 1) else {
+                break;
+            };
+            let row_address = row_end - (BYTES_PER_ROW - 1);
+            match memory.read_memory(row_address, BYTES_PER_ROW) {
+                Ok(bytes) => self.data.extend(bytes.into_iter().map(Some)),
+                Err(_) => self.data.extend(std::iter::repeat_n(None, BYTES_PER_ROW)),
+            }
+        }
+    }
+
+    /// Renders the memory view UI
+    pub fn ui(&mut self, ui: &mut egui::Ui, scanner: &Option<Arc<Mutex<Scanner>>>) {
+        let Some(scanner) = scanner else {
+            ui.label("No process selected.");
+            return;
+        };
+
+        ui.horizontal(|ui| {
+            ui.label("Go to Address:");
+            ui.text_edit_singleline(&mut self.base_address);
+
+            ui.separator();
+
+            ui.label("Rows:");
+            ui.add(egui::DragValue::new(&mut self.rows).range(8..=256));
+
+            ui.checkbox(&mut self.follow_pointer, "Follow pointer");
+        });
+
+        let now = ui.input(|i| i.time);
+        if self.needs_read(now) {
+            if let Ok(scanner) = scanner.lock() {
+                self.read(&scanner);
+            }
+            self.last_read = Some((self.base_address.clone(), self.rows, self.follow_pointer, now));
+        }
+        ui.ctx()
+            .request_repaint_after(std::time::Duration::from_secs_f64(REFRESH_INTERVAL));
+
+        if let Some(ref error) = self.error {
+            ui.colored_label(egui::Color32::RED, error);
+            return;
+        }
+
+        let Some(address) = self.display_address else {
+            ui.label("Enter an address to view memory.");
+            return;
+        };
+
+        if self.follow_pointer {
+            ui.label(format!("Pointer target: 0x{:X}", address));
+        }
+
+        ui.separator();
+
+        let mut clicked = None;
+
+        egui::ScrollArea::vertical()
+            .id_salt("memory_view_scroll")
+            .show(ui, |ui| {
+                for (row, bytes) in self.data.chunks(BYTES_PER_ROW).enumerate() {
+                    let row_address = address + row * BYTES_PER_ROW;
+
+                    ui.horizontal(|ui| {
+                        ui.spacing_mut().item_spacing.x = 4.0;
+
+                        // Address
+                        ui.monospace(format!("{:016X}", row_address));
+                        ui.separator();
+
+                        // Hex bytes
+                        for (column, byte) in bytes.iter().enumerate() {
+                            let byte_address = row_address + column;
+                            let text = match byte {
+                                Some(b) => format!("{:02X}", b),
+                                None => "??".to_string(),
+                            };
+                            let selected = self.selected == Some(byte_address);
+                            if ui
+                                .selectable_label(selected, egui::RichText::new(text).monospace())
+                                .clicked()
+                            {
+                                clicked = Some(byte_address);
+                            }
+                        }
+
+                        ui.separator();
+
+                        // ASCII
+                        let ascii: String = bytes
+                            .iter()
+                            .map(|byte| match byte {
+                                Some(b) if b.is_ascii_graphic() || *b == b' ' => *b as char,
+                                _ => '.',
+                            })
+                            .collect();
+                        ui.monospace(ascii);
+                    });
+                }
+            });
+
+        if let Some(byte_address) = clicked {
+            self.selected = Some(byte_address);
+        }
+
+        if let Some(selected) = self.selected {
+            let offset = selected.wrapping_sub(address);
+            if let Some(Some(byte)) = self.data.get(offset) {
+                ui.separator();
+                ui.label(format!("Selected: 0x{:X} = 0x{:02X} ({})", selected, byte, byte));
+            }
+        }
+    }
+}
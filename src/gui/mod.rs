@@ -1,7 +1,13 @@
 pub mod app;
+pub mod cheat_table;
 pub mod engine_view;
+pub mod memory_view;
+pub mod module_list_view;
+pub mod pointer_scan_view;
 pub mod process_list;
+pub mod region_map;
 pub mod results_view;
 pub mod scan_view;
+pub mod thread_list;
 
 pub use app::LightScanApp;
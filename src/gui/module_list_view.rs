@@ -0,0 +1,111 @@
+use crate::platform::module::{list_modules, ModuleInfo};
+use eframe::egui;
+
+/// UI component for displaying the modules loaded in the selected process
+#[derive(Default)]
+pub struct ModuleListView {
+    modules: Vec<ModuleInfo>,
+    filter: String,
+    pid: Option<u32>,
+    selected: Option<usize>,
+    error: Option<String>,
+}
+
+impl ModuleListView {
+    /// Refreshes the module list for the given process
+    fn refresh(&mut self, pid: u32) {
+        self.pid = Some(pid);
+        self.selected = None;
+        match list_modules(pid) {
+            Ok(mut modules) => {
+                modules.sort_by_key(|m| m.name.to_lowercase());
+                self.modules = modules;
+                self.error = None;
+            }
+            Err(e) => {
+                self.modules.clear();
+                self.error = Some(format!("Failed to list modules: {}", e));
+            }
+        }
+    }
+
+    /// Address range [start, end) of the selected module
+    pub fn selected_range(&self) -> Option<(usize, usize)> {
+        let module = self.modules.get(self.selected?)?;
+        Some((module.base_address, module.base_address + module.size))
+    }
+
+    /// Renders the module list UI
+    /// Returns Some((start, end)) if "Set Scan Range" was clicked for the selected module
+    pub fn ui(&mut self, ui: &mut egui::Ui, pid: u32) -> Option<(usize, usize)> {
+        let mut scan_range = None;
+
+        // Auto-refresh when the selected process changes
+        if self.pid != Some(pid) {
+            self.refresh(pid);
+        }
+
+        ui.horizontal(|ui| {
+            if ui.button("Refresh").clicked() {
+                self.refresh(pid);
+            }
+
+            ui.label("Filter:");
+            ui.text_edit_singleline(&mut self.filter);
+
+            let selected = self.selected.and_then(|i| self.modules.get(i));
+            if ui
+                .add_enabled(selected.is_some(), egui::Button::new("Set Scan Range"))
+                .clicked()
+            {
+                if let Some(module) = selected {
+                    scan_range = Some((module.base_address, module.base_address + module.size));
+                }
+            }
+        });
+
+        if let Some(ref error) = self.error {
+            ui.colored_label(egui::Color32::RED, error);
+        }
+
+        let filter_lower = self.filter.to_lowercase();
+        let mut clicked = None;
+
+        egui::ScrollArea::vertical()
+            .id_salt("module_list_scroll")
+            .max_height(200.0)
+            .show(ui, |ui| {
+                egui::Grid::new("module_grid")
+                    .striped(true)
+                    .num_columns(3)
+                    .show(ui, |ui| {
+                        ui.label(egui::RichText::new("Module").strong());
+                        ui.label(egui::RichText::new("Base Address").strong());
+                        ui.label(egui::RichText::new("Size").strong());
+                        ui.end_row();
+
+                        for (index, module) in self.modules.iter().enumerate() {
+                            if !filter_lower.is_empty() && !module.name.to_lowercase().contains(&filter_lower) {
+                                continue;
+                            }
+
+                            let is_selected = self.selected == Some(index);
+                            if ui.selectable_label(is_selected, &module.name).clicked() {
+                                clicked = Some(index);
+                            }
+                            ui.monospace(format!("0x{:016X}", module.base_address));
+                            ui.monospace(format!("0x{:X}", module.size));
+                            ui.end_row();
+                        }
+                    });
+            });
+
+        // Select the row and copy its base address to the clipboard
+        if let Some(index) = clicked {
+            self.selected = Some(index);
+            ui.ctx().copy_text(format!("0x{:X}", self.modules[index].base_address));
+        }
+
+        scan_range
+    }
+}
@@ -0,0 +1,127 @@
+use crate::platform::ProcessInfo;
+use crate::scanner::{PointerChain, PointerScanner, Process};
+use eframe::egui;
+use std::thread::JoinHandle;
+
+/// UI component for searching pointer chains to an address
+pub struct PointerScanView {
+    /// Target address input (hex)
+    target_address: String,
+    max_depth: u8,
+    max_offset: usize,
+    chains: Vec<PointerChain>,
+    /// Running scan (HANDLE is not Send, so the thread opens its own process handle)
+    task: Option<JoinHandle<anyhow::Result<Vec<PointerChain>>>>,
+    error: Option<String>,
+}
+
+impl Default for PointerScanView {
+    fn default() -> Self {
+        Self {
+            target_address: String::new(),
+            max_depth: 3,
+            max_offset: 0x400,
+            chains: Vec::new(),
+            task: None,
+            error: None,
+        }
+    }
+}
+
+impl PointerScanView {
+    fn parse_address(input: &str) -> Option<usize> {
+        let trimmed = input.trim();
+        let hex = trimmed
+            .strip_prefix("0x")
+            .or_else(|| trimmed.strip_prefix("0X"))
+            .unwrap_or(trimmed);
+        usize::from_str_radix(hex, 16).ok()
+    }
+
+    fn start_scan(&mut self, process_info: &ProcessInfo) {
+        let Some(target) = Self::parse_address(&self.target_address) else {
+            self.error = Some("Invalid target address".to_string());
+            return;
+        };
+
+        let process_info = process_info.clone();
+        let (max_depth, max_offset) = (self.max_depth, self.max_offset);
+
+        self.chains.clear();
+        self.error = None;
+        self.task = Some(std::thread::spawn(move || {
+            let process = Process::from_info(&process_info)?;
+            PointerScanner::new().scan(target, max_depth, max_offset, &process)
+        }));
+    }
+
+    fn poll_task(&mut self) {
+        if !self.task.as_ref().is_some_and(|task| task.is_finished()) {
+            return;
+        }
+
+        match self.task.take().map(|task| task.join()) {
+            Some(Ok(Ok(chains))) => self.chains = chains,
+            Some(Ok(Err(e))) => self.error = Some(format!("Pointer scan failed: {}", e)),
+            Some(Err(_)) => self.error = Some("Pointer scan thread panicked".to_string()),
+            None => {}
+        }
+    }
+
+    /// Renders the pointer scan UI
+    pub fn ui(&mut self, ui: &mut egui::Ui, process: Option<&ProcessInfo>) {
+        let Some(process) = process else {
+            ui.label("No process selected.");
+            return;
+        };
+
+        self.poll_task();
+        let scanning = self.task.is_some();
+
+        ui.horizontal(|ui| {
+            ui.label("Target address:");
+            ui.text_edit_singleline(&mut self.target_address);
+
+            ui.separator();
+
+            ui.label("Max depth:");
+            ui.add(egui::DragValue::new(&mut self.max_depth).range(1..=6));
+
+            ui.label("Max offset:");
+            ui.add(egui::DragValue::new(&mut self.max_offset).range(0..=0x10000).hexadecimal(1, false, true));
+
+            if ui.add_enabled(!scanning, egui::Button::new("Scan")).clicked() {
+                self.start_scan(process);
+            }
+        });
+
+        if scanning {
+            ui.horizontal(|ui| {
+                ui.spinner();
+                ui.label("Scanning for pointers...");
+            });
+            ui.ctx().request_repaint();
+        }
+
+        if let Some(ref error) = self.error {
+            ui.colored_label(egui::Color32::RED, error);
+        }
+
+        ui.separator();
+        ui.label(format!("Chains found: {} (click to copy)", self.chains.len()));
+
+        egui::ScrollArea::vertical()
+            .id_salt("pointer_scan_scroll")
+            .show(ui, |ui| {
+                for chain in &self.chains {
+                    let text = chain.to_string();
+                    if ui
+                        .add(egui::Label::new(egui::RichText::new(&text).monospace()).sense(egui::Sense::click()))
+                        .clicked()
+                    {
+                        ui.ctx().copy_text(text);
+                    }
+                }
+            });
+    }
+}
@@ -1,23 +1,92 @@
-use crate::platform::ProcessInfo;
+use crate::platform::elevation::IntegrityLevel;
+use crate::platform::{ProcessArch, ProcessInfo};
 use crate::scanner::Process;
 use eframe::egui;
 
+/// Processes using less memory than this are marked as "small"
+const SMALL_PROCESS_BYTES: u64 = 10 * 1024 * 1024;
+
+/// Command lines longer than this are cut off in the tooltip
+const COMMAND_LINE_DISPLAY_CHARS: usize = 100;
+
+/// Sort order of the process list
+#[derive(Clone, Copy, PartialEq)]
+enum ProcessSort {
+    /// Largest working set first (games are usually at the top)
+    Memory,
+    Name,
+}
+
+/// Section grouping of the process list
+#[derive(Clone, Copy, PartialEq)]
+enum ProcessGroup {
+    /// Flat list in sort order
+    Name,
+    Architecture,
+    IntegrityLevel,
+}
+
+impl ProcessGroup {
+    fn label(&self) -> &'static str {
+        match self {
+            ProcessGroup::Name => "Name",
+            ProcessGroup::Architecture => "Architecture",
+            ProcessGroup::IntegrityLevel => "Integrity Level",
+        }
+    }
+
+    /// Section order and header of a process, or None when not grouping
+    fn section(&self, process: &ProcessInfo) -> Option<(u8, &'static str)> {
+        match self {
+            ProcessGroup::Name => None,
+            ProcessGroup::Architecture => Some(match process.arch {
+                ProcessArch::X64 => (0, "[64-bit]"),
+                ProcessArch::X86 => (1, "[32-bit]"),
+                ProcessArch::Unknown => (2, "[Unknown]"),
+            }),
+            ProcessGroup::IntegrityLevel => Some(match process.integrity {
+                Some(IntegrityLevel::System) => (0, "System"),
+                Some(IntegrityLevel::High) => (1, "High"),
+                Some(IntegrityLevel::Medium) => (2, "Medium"),
+                Some(IntegrityLevel::Low) => (3, "Low"),
+                Some(IntegrityLevel::Untrusted) => (4, "Untrusted"),
+                None => (5, "Unknown"),
+            }),
+        }
+    }
+}
+
 /// UI component for displaying and selecting processes
-#[derive(Default)]
 pub struct ProcessListView {
     processes: Vec<ProcessInfo>,
     filter: String,
+    /// Also match the filter against window titles
+    filter_window_title: bool,
+    sort: ProcessSort,
+    group: ProcessGroup,
     last_refresh: Option<std::time::Instant>,
 }
 
+impl Default for ProcessListView {
+    fn default() -> Self {
+        Self {
+            processes: Vec::new(),
+            filter: String::new(),
+            filter_window_title: true,
+            sort: ProcessSort::Memory,
+            group: ProcessGroup::Name,
+            last_refresh: None,
+        }
+    }
+}
+
 impl ProcessListView {
     /// Refreshes the process list
     fn refresh(&mut self) {
         match Process::list_all() {
-            Ok(mut processes) => {
-                // Sort by name
-                processes.sort_by(|a, b| a.name.to_lowercase().cmp(&b.name.to_lowercase()));
+            Ok(processes) => {
                 self.processes = processes;
+                self.apply_sort();
                 self.last_refresh = Some(std::time::Instant::now());
             }
             Err(e) => {
@@ -26,6 +95,51 @@ impl ProcessListView {
         }
     }
 
+    /// Sorts by the selected order, with processes that have a visible window first
+    fn apply_sort(&mut self) {
+        match self.sort {
+            ProcessSort::Memory => self
+                .processes
+                .sort_by_key(|p| (p.window_title.is_none(), std::cmp::Reverse(p.memory_usage))),
+            ProcessSort::Name => self
+                .processes
+                .sort_by_cached_key(|p| (p.window_title.is_none(), p.name.to_lowercase())),
+        }
+    }
+
+    /// Formats a byte count as MB or GB
+    fn format_memory(bytes: u64) -> String {
+        const MB: f64 = 1024.0 * 1024.0;
+        let mb = bytes as f64 / MB;
+        if mb >= 1024.0 {
+            format!("{:.2} GB", mb / 1024.0)
+        } else {
+            format!("{:.0} MB", mb)
+        }
+    }
+
+    /// Shortens text to `max_chars` characters, marking the cut with "..."
+    fn truncate(text: &str, max_chars: usize) -> String {
+        match text.char_indices().nth(max_chars) {
+            Some((index, _)) => format!("{}...", &text[..index]),
+            None => text.to_string(),
+        }
+    }
+
+    /// Integrity badge; sandboxed (low/untrusted) processes stand out
+    fn integrity_text(level: IntegrityLevel) -> egui::RichText {
+        let text = egui::RichText::new(format!("[{}]", level)).size(11.0);
+        match level {
+            IntegrityLevel::Untrusted | IntegrityLevel::Low => {
+                text.color(egui::Color32::from_rgb(255, 200, 0))
+            }
+            IntegrityLevel::Medium => text.weak(),
+            IntegrityLevel::High | IntegrityLevel::System => {
+                text.color(egui::Color32::from_rgb(120, 170, 255))
+            }
+        }
+    }
+
     /// Renders the process list UI
     /// Returns Some(ProcessInfo) if a process was selected
     pub fn ui(&mut self, ui: &mut egui::Ui) -> Option<ProcessInfo> {
@@ -44,6 +158,31 @@ impl ProcessListView {
 
             ui.label("Filter:");
             ui.text_edit_singleline(&mut self.filter);
+            ui.checkbox(&mut self.filter_window_title, "Window titles");
+
+            ui.separator();
+
+            ui.label("Sort:");
+            let mut changed = ui.selectable_value(&mut self.sort, ProcessSort::Memory, "Memory").changed();
+            changed |= ui.selectable_value(&mut self.sort, ProcessSort::Name, "Name").changed();
+            if changed {
+                self.apply_sort();
+            }
+
+            ui.separator();
+
+            ui.label("Group by:");
+            egui::ComboBox::new("process_group", "")
+                .selected_text(self.group.label())
+                .show_ui(ui, |ui| {
+                    for group in [
+                        ProcessGroup::Name,
+                        ProcessGroup::Architecture,
+                        ProcessGroup::IntegrityLevel,
+                    ] {
+                        ui.selectable_value(&mut self.group, group, group.label());
+                    }
+                });
         });
 
         ui.separator();
@@ -68,6 +207,8 @@ impl ProcessListView {
                 ui.horizontal(|ui| {
                     ui.label(egui::RichText::new("PID").strong().size(12.0));
                     ui.add_space(60.0);
+                    ui.label(egui::RichText::new("Memory").strong().size(12.0));
+                    ui.add_space(40.0);
                     ui.label(egui::RichText::new("Process Name").strong().size(12.0));
                 });
 
@@ -75,23 +216,66 @@ impl ProcessListView {
 
                 // Filter processes
                 let filter_lower = self.filter.to_lowercase();
-                let filtered: Vec<&ProcessInfo> = self
+                let filter_window_title = self.filter_window_title;
+                let mut filtered: Vec<&ProcessInfo> = self
                     .processes
                     .iter()
                     .filter(|p| {
                         filter_lower.is_empty()
                             || p.name.to_lowercase().contains(&filter_lower)
                             || p.pid.to_string().contains(&filter_lower)
+                            || (filter_window_title
+                                && p.window_title
+                                    .as_ref()
+                                    .is_some_and(|t| t.to_lowercase().contains(&filter_lower)))
                     })
                     .collect();
 
+                // Stable sort keeps the selected order within each section
+                let group = self.group;
+                filtered.sort_by_key(|p| group.section(p).map(|(order, _)| order));
+
                 // Display processes
+                let mut current_section = None;
                 for process in filtered {
+                    let section = group.section(process);
+                    if section != current_section {
+                        if let Some((_, header)) = section {
+                            ui.add_space(4.0);
+                            ui.label(egui::RichText::new(header).strong());
+                        }
+                        current_section = section;
+                    }
+
                     ui.horizontal(|ui| {
                         ui.label(format!("{:<8}", process.pid));
-                        if ui.button(&process.name).clicked() {
+
+                        let memory = if process.memory_usage == 0 {
+                            egui::RichText::new(format!("{:>10}", "?")).monospace().weak()
+                        } else if process.memory_usage < SMALL_PROCESS_BYTES {
+                            egui::RichText::new(format!("{:>10}", "small")).monospace().weak()
+                        } else {
+                            egui::RichText::new(format!("{:>10}", Self::format_memory(process.memory_usage)))
+                                .monospace()
+                        };
+                        ui.label(memory).on_hover_text(format!("{} bytes", process.memory_usage));
+
+                        let mut button = ui.button(&process.name);
+                        if let Some(command_line) = &process.command_line {
+                            button = button.on_hover_text(Self::truncate(command_line, COMMAND_LINE_DISPLAY_CHARS));
+                        }
+                        if button.clicked() {
                             selected = Some(process.clone());
                         }
+                        if let Some(title) = &process.window_title {
+                            ui.label(format!("({})", title));
+                        }
+                        if process.arch == ProcessArch::X86 {
+                            ui.label(egui::RichText::new(process.arch.label()).weak());
+                        }
+                        if let Some(level) = process.integrity {
+                            ui.label(Self::integrity_text(level));
+                        }
                     });
                 }
 
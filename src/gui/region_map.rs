@@ -0,0 +1,207 @@
+use crate::platform::{query_memory_regions, MemoryRegion};
+use crate::scanner::Process;
+use eframe::egui;
+
+/// Height of the region bar in points
+const BAR_HEIGHT: f32 = 24.0;
+
+/// What a committed region is most likely used for, as shown on the map
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum RegionKind {
+    Image,
+    MappedFile,
+    Heap,
+    Stack,
+    /// Executable and writable at the same time (JIT code, unpacked or injected code)
+    ExecutableWritable,
+}
+
+impl RegionKind {
+    const ALL: [RegionKind; 5] = [
+        RegionKind::Image,
+        RegionKind::MappedFile,
+        RegionKind::Heap,
+        RegionKind::Stack,
+        RegionKind::ExecutableWritable,
+    ];
+
+    fn label(self) -> &'static str {
+        match self {
+            RegionKind::Image => "Image",
+            RegionKind::MappedFile => "Mapped file",
+            RegionKind::Heap => "Private (heap)",
+            RegionKind::Stack => "Stack",
+            RegionKind::ExecutableWritable => "Executable + writable",
+        }
+    }
+
+    fn color(self) -> egui::Color32 {
+        match self {
+            RegionKind::Image => egui::Color32::from_rgb(70, 130, 220),
+            RegionKind::MappedFile => egui::Color32::from_rgb(80, 180, 90),
+            RegionKind::Heap => egui::Color32::from_rgb(240, 150, 50),
+            RegionKind::Stack => egui::Color32::GRAY,
+            RegionKind::ExecutableWritable => egui::Color32::from_rgb(220, 50, 50),
+        }
+    }
+}
+
+/// A region with its classification
+struct MapSegment {
+    region: MemoryRegion,
+    kind: RegionKind,
+}
+
+/// UI component showing the committed memory of the selected process as a proportional bar
+#[derive(Default)]
+pub struct RegionMapView {
+    segments: Vec<MapSegment>,
+    total_size: usize,
+    pid: Option<u32>,
+    error: Option<String>,
+}
+
+impl RegionMapView {
+    /// Re-queries the regions of the given process
+    fn refresh(&mut self, pid: u32) {
+        self.pid = Some(pid);
+        self.segments.clear();
+        self.total_size = 0;
+
+        let regions = Process::open(pid, String::new()).and_then(|process| query_memory_regions(process.handle()));
+        match regions {
+            Ok(mut regions) => {
+                regions.sort_by_key(|r| r.base_address);
+                self.segments = classify_regions(regions);
+                self.total_size = self.segments.iter().map(|s| s.region.size).sum();
+                self.error = None;
+            }
+            Err(e) => {
+                self.error = Some(format!("Failed to query memory regions: {}", e));
+            }
+        }
+    }
+
+    /// Renders the region map UI
+    pub fn ui(&mut self, ui: &mut egui::Ui, pid: u32) {
+        // Auto-refresh when the selected process changes
+        if self.pid != Some(pid) {
+            self.refresh(pid);
+        }
+
+        ui.horizontal(|ui| {
+            if ui.button("Refresh").clicked() {
+                self.refresh(pid);
+            }
+            ui.label(format!(
+                "{} regions, {:.1} MB committed",
+                self.segments.len(),
+                self.total_size as f64 / (1024.0 * 1024.0)
+            ));
+        });
+
+        if let Some(ref error) = self.error {
+            ui.colored_label(egui::Color32::RED, error);
+            return;
+        }
+
+        self.show_bar(ui);
+
+        // Legend
+        ui.horizontal_wrapped(|ui| {
+            for kind in RegionKind::ALL {
+                let (rect, _) = ui.allocate_exact_size(egui::vec2(12.0, 12.0), egui::Sense::hover());
+                ui.painter().rect_filled(rect, 2.0, kind.color());
+                ui.label(kind.label());
+                ui.add_space(8.0);
+            }
+        });
+    }
+
+    /// Draws one segment per region, its width proportional to the region size
+    fn show_bar(&self, ui: &mut egui::Ui) {
+        let width = ui.available_width();
+        let (rect, response) = ui.allocate_exact_size(egui::vec2(width, BAR_HEIGHT), egui::Sense::hover());
+        let painter = ui.painter_at(rect);
+        painter.rect_filled(rect, 0.0, ui.visuals().extreme_bg_color);
+
+        if self.total_size == 0 {
+            return;
+        }
+
+        let hover_x = response.hover_pos().map(|pos| pos.x);
+        let mut hovered = None;
+        let mut x = rect.left();
+
+        for segment in &self.segments {
+            let segment_width = segment.region.size as f32 / self.total_size as f32 * width;
+            let segment_rect = egui::Rect::from_min_max(
+                egui::pos2(x, rect.top()),
+                egui::pos2(x + segment_width, rect.bottom()),
+            );
+            painter.rect_filled(segment_rect, 0.0, segment.kind.color());
+
+            if hover_x.is_some_and(|hx| hx >= x && hx < x + segment_width.max(1.0)) {
+                hovered = Some(segment);
+            }
+            x += segment_width;
+        }
+
+        if let Some(segment) = hovered {
+            let region = &segment.region;
+            response.on_hover_ui_at_pointer(|ui| {
+                ui.label(segment.kind.label());
+                ui.monospace(format!("Base: 0x{:X}", region.base_address));
+                ui.monospace(format!("Size: 0x{:X} ({} KB)", region.size, region.size / 1024));
+                ui.monospace(format!(
+                    "Protection: {} (0x{:X})",
+                    protection_flags(region),
+                    region.protection
+                ));
+            });
+        }
+    }
+}
+
+/// Classifies regions sorted by base address
+///
+/// Stacks are not marked as such by the OS; a private region directly above a
+/// guard page is treated as a thread stack.
+fn classify_regions(regions: Vec<MemoryRegion>) -> Vec<MapSegment> {
+    let mut segments: Vec<MapSegment> = Vec::with_capacity(regions.len());
+
+    for region in regions {
+        let above_guard = segments.last().is_some_and(|prev| {
+            prev.region.is_guard
+                && prev.region.base_address + prev.region.size == region.base_address
+        });
+
+        let kind = if region.is_executable && region.is_writable {
+            RegionKind::ExecutableWritable
+        } else if region.is_image() {
+            RegionKind::Image
+        } else if region.is_mapped() {
+            RegionKind::MappedFile
+        } else if region.is_guard || (region.is_private() && above_guard) {
+            RegionKind::Stack
+        } else {
+            RegionKind::Heap
+        };
+
+        segments.push(MapSegment { region, kind });
+    }
+
+    segments
+}
+
+/// "RWX"-style summary of a region's protection
+fn protection_flags(region: &MemoryRegion) -> String {
+    let mut flags = String::with_capacity(4);
+    flags.push(if region.is_readable { 'R' } else { '-' });
+    flags.push(if region.is_writable { 'W' } else { '-' });
+    flags.push(if region.is_executable { 'X' } else { '-' });
+    if region.is_guard {
+        flags.push('G');
+    }
+    flags
+}
@@ -1,16 +1,72 @@
 use crate::scanner::Scanner;
-use crate::types::{ScanResult, ValueType};
+use crate::types::{Endianness, ScanResult, ScanResults, ScanValue, ValueType};
 use eframe::egui;
+use std::cmp::Ordering;
+use std::collections::HashMap;
 use std::sync::{Arc, Mutex};
 
+/// Column used to sort the results grid
+#[derive(Clone, Copy, PartialEq)]
+enum SortBy {
+    Address,
+    Value,
+    /// |current - previous|
+    Delta,
+}
+
+/// Export file format
+#[derive(Clone, Copy, PartialEq)]
+enum ExportFormat {
+    Csv,
+    Json,
+}
+
 /// UI component for displaying scan results
 pub struct ResultsView {
     results: Vec<ScanResult>,
     value_type: ValueType,
+    /// Byte order of the values in memory; `results` hold little-endian bytes
+    endianness: Endianness,
+    /// Display order as indices into `results` (None = insertion order)
+    sort: Option<(SortBy, bool)>,
+    sorted_indices: Vec<usize>,
     page_offset: usize,
     page_size: usize,
     edit_address: Option<usize>,
     edit_value: String,
+    /// Address requested to be added to the watchlist
+    watch_request: Option<usize>,
+    /// Address whose module should become the scan range
+    module_range_request: Option<usize>,
+    /// Address requested to be opened in the memory view
+    memory_view_request: Option<usize>,
+    /// Index into `results` to remove after the grid is drawn
+    remove_request: Option<usize>,
+    /// Pending export dialog
+    export_format: Option<ExportFormat>,
+    export_path: String,
+    export_message: Option<String>,
+    /// Periodically re-read the values on the current page
+    auto_refresh: bool,
+    /// Seconds between automatic refreshes
+    refresh_interval: f32,
+    /// egui time of the last automatic refresh
+    last_refresh_time: f64,
+    /// Show integer values as hexadecimal
+    display_hex: bool,
+    /// User labels by address; kept across scans and resets so a found value keeps its name
+    labels: HashMap<usize, String>,
+    /// Address whose label is being edited inline, and the text being typed
+    label_edit: Option<(usize, String)>,
+    /// Hex bounds of the address filter, inclusive (empty = unbounded)
+    address_min_input: String,
+    address_max_input: String,
+    /// Set when "In selected module" was clicked; the app fills in the range
+    module_filter_request: bool,
+    /// Show refreshed values XORed with the key at `xor_key_offset` (display only)
+    xor_guard: bool,
+    /// Offset of the XOR key from the stored value, in bytes
+    xor_key_offset: i32,
 }
 
 impl Default for ResultsView {
@@ -18,10 +74,31 @@ impl Default for ResultsView {
         Self {
             results: Vec::new(),
             value_type: ValueType::I32,
+            endianness: Endianness::Little,
+            sort: None,
+            sorted_indices: Vec::new(),
             page_offset: 0,
             page_size: 100,
             edit_address: None,
             edit_value: String::new(),
+            watch_request: None,
+            module_range_request: None,
+            memory_view_request: None,
+            remove_request: None,
+            export_format: None,
+            export_path: String::new(),
+            export_message: None,
+            auto_refresh: false,
+            refresh_interval: 1.0,
+            last_refresh_time: 0.0,
+            display_hex: false,
+            labels: HashMap::new(),
+            label_edit: None,
+            address_min_input: String::new(),
+            address_max_input: String::new(),
+            module_filter_request: false,
+            xor_guard: false,
+            xor_key_offset: 8,
         }
     }
 }
@@ -29,51 +106,470 @@ impl Default for ResultsView {
 impl ResultsView {
     pub fn clear(&mut self) {
         self.results.clear();
+        self.sorted_indices.clear();
         self.page_offset = 0;
         self.edit_address = None;
         self.edit_value.clear();
+        self.label_edit = None;
     }
 
     pub fn result_count(&self) -> usize {
         self.results.len()
     }
 
+    pub fn value_type(&self) -> ValueType {
+        self.value_type
+    }
+
+    /// Byte order the results are read and edited in
+    pub fn endianness(&self) -> Endianness {
+        self.endianness
+    }
+
+    /// Takes the pending "Add to Watchlist" request, if any
+    pub fn take_watch_request(&mut self) -> Option<usize> {
+        self.watch_request.take()
+    }
+
+    /// Takes the pending "Set Scan Range to This Module" request, if any
+    pub fn take_module_range_request(&mut self) -> Option<usize> {
+        self.module_range_request.take()
+    }
+
+    /// Takes the pending "Open in Memory View" request, if any
+    pub fn take_memory_view_request(&mut self) -> Option<usize> {
+        self.memory_view_request.take()
+    }
+
+    /// Takes the pending "In selected module" filter request, if any
+    pub fn take_module_filter_request(&mut self) -> bool {
+        std::mem::take(&mut self.module_filter_request)
+    }
+
+    /// Filters the displayed results to [start, end] and reloads them from the scanner
+    pub fn set_address_filter(&mut self, start: usize, end: usize, scanner: &Scanner) {
+        self.address_min_input = format!("0x{:X}", start);
+        self.address_max_input = format!("0x{:X}", end);
+        self.update_from_scanner(scanner);
+    }
+
     pub fn update_from_scanner(&mut self, scanner: &Scanner) {
-        self.results = scanner.results().results.clone();
-        self.value_type = scanner.results().value_type;
+        self.update_from_results(scanner.results());
+    }
+
+    /// Loads results for display, keeping only those inside the address filter
+    ///
+    /// The filter only narrows what is shown; the scanner keeps all results.
+    pub fn update_from_results(&mut self, results: &ScanResults) {
+        let min = Self::parse_address_input(&self.address_min_input).unwrap_or(0);
+        let max = Self::parse_address_input(&self.address_max_input).unwrap_or(usize::MAX);
+        self.results = if min == 0 && max == usize::MAX {
+            results.results.clone()
+        } else {
+            results
+                .results
+                .iter()
+                .filter(|r| (min..=max).contains(&r.address))
+                .cloned()
+                .collect()
+        };
+        self.value_type = results.value_type;
+        self.endianness = results.endianness;
+        self.page_offset = 0;
+        self.apply_labels();
+        self.apply_sort();
+    }
+
+    /// Merges labels carried by the results (e.g. from a loaded session) into the label map,
+    /// then copies the map back onto every result with a matching address
+    fn apply_labels(&mut self) {
+        for result in &self.results {
+            if let Some(label) = &result.label {
+                self.labels.insert(result.address, label.clone());
+            }
+        }
+        for result in &mut self.results {
+            result.label = self.labels.get(&result.address).cloned();
+        }
+    }
+
+    /// Copies the current labels onto results stored outside the view (e.g. for saving a session)
+    pub fn label_results(&self, results: &mut ScanResults) {
+        for result in &mut results.results {
+            result.label = self.labels.get(&result.address).cloned();
+        }
+    }
+
+    /// Sets or removes (empty text) the label of an address
+    fn set_label(&mut self, address: usize, label: &str) {
+        let label = label.trim();
+        let label = (!label.is_empty()).then(|| label.to_string());
+
+        match &label {
+            Some(label) => self.labels.insert(address, label.clone()),
+            None => self.labels.remove(&address),
+        };
+        for result in self.results.iter_mut().filter(|r| r.address == address) {
+            result.label = label.clone();
+        }
+    }
+
+    /// Address cell: "0x1234ABC — Health", double-click to edit the label inline
+    fn address_cell(&mut self, ui: &mut egui::Ui, index: usize) {
+        let address = self.results[index].address;
+
+        if let Some((edit_address, text)) = &mut self.label_edit {
+            if *edit_address == address {
+                let response = ui.add(
+                    egui::TextEdit::singleline(text)
+                        .hint_text(format!("0x{:X} label", address))
+                        .desired_width(160.0),
+                );
+                response.request_focus();
+
+                if ui.input(|i| i.key_pressed(egui::Key::Escape)) {
+                    self.label_edit = None;
+                } else if response.lost_focus() {
+                    let text = text.clone();
+                    self.set_label(address, &text);
+                    self.label_edit = None;
+                }
+                return;
+            }
+        }
+
+        let address_text = match &self.results[index].label {
+            Some(label) => format!("0x{:X} — {}", address, label),
+            None => format!("0x{:X}", address),
+        };
+        let response = ui
+            .add(egui::Label::new(address_text).sense(egui::Sense::click()))
+            .on_hover_text("Double-click to edit label");
+        if response.double_clicked() {
+            let current = self.labels.get(&address).cloned().unwrap_or_default();
+            self.label_edit = Some((address, current));
+        }
+        response.context_menu(|ui| self.row_context_menu(ui, index));
+    }
+
+    /// Re-reads the current values of the visible page only
+    ///
+    /// previous_value is left untouched so the delta stays relative to the last scan.
+    fn refresh_page(&mut self, scanner: &Scanner) {
+        let end = (self.page_offset + self.page_size).min(self.sorted_indices.len());
+        for &index in &self.sorted_indices[self.page_offset..end] {
+            let result = &mut self.results[index];
+            let value = match self.xor_guard.then_some(self.xor_key_offset) {
+                Some(key_offset) => {
+                    scanner.read_guarded_value(result.address, key_offset, self.value_type, self.endianness)
+                }
+                None => scanner.read_value_endian(result.address, self.value_type, self.endianness),
+            };
+            if let Ok(value) = value {
+                result.current_value = Some(value.to_bytes());
+            }
+        }
+    }
+
+    /// Toggles the sort order for a column (ascending first, then descending)
+    fn toggle_sort(&mut self, column: SortBy) {
+        self.sort = match self.sort {
+            Some((current, ascending)) if current == column => Some((column, !ascending)),
+            _ => Some((column, true)),
+        };
         self.page_offset = 0;
+        self.apply_sort();
+    }
+
+    /// Rebuilds sorted_indices without reordering the results themselves
+    fn apply_sort(&mut self) {
+        self.sorted_indices = (0..self.results.len()).collect();
+
+        let Some((column, ascending)) = self.sort else {
+            return;
+        };
+
+        let results = &self.results;
+        let value_type = self.value_type;
+        self.sorted_indices.sort_by(|&a, &b| {
+            let (a, b) = (&results[a], &results[b]);
+            let ordering = match column {
+                SortBy::Address => a.address.cmp(&b.address),
+                SortBy::Value => Self::compare_values(a, b, value_type),
+                SortBy::Delta => Self::compare_deltas(a, b, value_type),
+            };
+            if ascending {
+                ordering
+            } else {
+                ordering.reverse()
+            }
+        });
+    }
+
+    fn compare_values(a: &ScanResult, b: &ScanResult, value_type: ValueType) -> Ordering {
+        match (a.parse_value(value_type), b.parse_value(value_type)) {
+            (Some(ScanValue::ByteArray(a)), Some(ScanValue::ByteArray(b))) => a.cmp(&b),
+            (Some(a), Some(b)) => a.as_f64().total_cmp(&b.as_f64()),
+            (a, b) => a.is_some().cmp(&b.is_some()),
+        }
+    }
+
+    fn compare_deltas(a: &ScanResult, b: &ScanResult, value_type: ValueType) -> Ordering {
+        match value_type {
+            ValueType::F32 | ValueType::F64 => {
+                Self::float_delta(a, value_type).total_cmp(&Self::float_delta(b, value_type))
+            }
+            ValueType::ByteArray(_) => Self::byte_delta(a).cmp(&Self::byte_delta(b)),
+            _ => Self::int_delta(a, value_type).cmp(&Self::int_delta(b, value_type)),
+        }
+    }
+
+    fn float_delta(result: &ScanResult, value_type: ValueType) -> f64 {
+        match (
+            result.parse_value(value_type),
+            ScanValue::from_bytes(&result.previous_value, value_type),
+        ) {
+            (Some(current), Some(previous)) => (current.as_f64() - previous.as_f64()).abs(),
+            _ => 0.0,
+        }
+    }
+
+    /// Integer delta computed in i128 so 64-bit values do not lose precision
+    fn int_delta(result: &ScanResult, value_type: ValueType) -> u128 {
+        let to_i128 = |value: Option<ScanValue>| match value? {
+            ScanValue::I8(v) => Some(v as i128),
+            ScanValue::I16(v) => Some(v as i128),
+            ScanValue::I32(v) => Some(v as i128),
+            ScanValue::I64(v) => Some(v as i128),
+            ScanValue::U8(v) => Some(v as i128),
+            ScanValue::U16(v) => Some(v as i128),
+            ScanValue::U32(v) => Some(v as i128),
+            ScanValue::U64(v) => Some(v as i128),
+            _ => None,
+        };
+
+        match (
+            to_i128(result.parse_value(value_type)),
+            to_i128(ScanValue::from_bytes(&result.previous_value, value_type)),
+        ) {
+            (Some(current), Some(previous)) => current.abs_diff(previous),
+            _ => 0,
+        }
+    }
+
+    /// Difference of the first byte that changed (0 if unchanged)
+    fn byte_delta(result: &ScanResult) -> u8 {
+        result
+            .get_current_value()
+            .iter()
+            .zip(&result.previous_value)
+            .find(|(current, previous)| current != previous)
+            .map(|(current, previous)| current.abs_diff(*previous))
+            .unwrap_or(0)
+    }
+
+    /// Context menu shown when right-clicking a result row
+    fn row_context_menu(&mut self, ui: &mut egui::Ui, index: usize) {
+        let address = self.results[index].address;
+
+        if ui.button("Copy Address").clicked() {
+            ui.ctx().copy_text(format!("0x{:X}", address));
+            ui.close_menu();
+        }
+        if ui.button("Copy Value").clicked() {
+            if let Some(value) = self.results[index].parse_value(self.value_type) {
+                ui.ctx().copy_text(value.to_string());
+            }
+            ui.close_menu();
+        }
+
+        ui.separator();
+
+        if ui.button("Add to Watchlist").clicked() {
+            self.watch_request = Some(address);
+            ui.close_menu();
+        }
+        if ui.button("Set Scan Range to This Module").clicked() {
+            self.module_range_request = Some(address);
+            ui.close_menu();
+        }
+        if ui.button("Open in Memory View").clicked() {
+            self.memory_view_request = Some(address);
+            ui.close_menu();
+        }
+
+        ui.separator();
+
+        if ui.button("Remove from Results").clicked() {
+            self.remove_request = Some(index);
+            ui.close_menu();
+        }
+    }
+
+    /// Clickable column header that toggles sorting and shows ▲/▼
+    fn sort_header(&mut self, ui: &mut egui::Ui, title: &str, column: SortBy) {
+        let indicator = match self.sort {
+            Some((current, true)) if current == column => " ▲",
+            Some((current, false)) if current == column => " ▼",
+            _ => "",
+        };
+
+        let label = egui::Label::new(egui::RichText::new(format!("{}{}", title, indicator)).strong())
+            .sense(egui::Sense::click());
+        if ui.add(label).clicked() {
+            self.toggle_sort(column);
+        }
     }
 
     pub fn ui(&mut self, ui: &mut egui::Ui, scanner: &Option<Arc<Mutex<Scanner>>>) {
-        if self.results.is_empty() {
+        let has_address_filter = !self.address_min_input.is_empty() || !self.address_max_input.is_empty();
+        if self.results.is_empty() && !has_address_filter {
             ui.label("No results. Perform a scan to see results here.");
             return;
         }
 
         // Pagination controls
         ui.horizontal(|ui| {
-            ui.label(format!("Total results: {}", self.results.len()));
+            ui.label(Self::format_count(self.results.len()))
+                .on_hover_text(format!("{} results", self.results.len()));
 
             ui.separator();
 
+            let total_pages = self.results.len().div_ceil(self.page_size).max(1);
+
+            if ui.button("⏮ First").clicked() {
+                self.page_offset = 0;
+            }
+
             if ui.button("◀ Prev").clicked() && self.page_offset > 0 {
                 self.page_offset = self.page_offset.saturating_sub(self.page_size);
             }
 
             let current_page = (self.page_offset / self.page_size) + 1;
-            let total_pages = (self.results.len() + self.page_size - 1) / self.page_size;
             ui.label(format!("Page {}/{}", current_page, total_pages));
 
             if ui.button("Next ▶").clicked() && self.page_offset + self.page_size < self.results.len() {
                 self.page_offset += self.page_size;
             }
 
+            if ui.button("Last ⏭").clicked() {
+                self.page_offset = (total_pages - 1) * self.page_size;
+            }
+
+            ui.label("Go to page:");
+            let mut goto_page = current_page;
+            if ui
+                .add(egui::DragValue::new(&mut goto_page).range(1..=total_pages))
+                .changed()
+            {
+                self.page_offset = (goto_page - 1) * self.page_size;
+            }
+
             ui.separator();
 
             ui.label("Per page:");
-            ui.add(egui::DragValue::new(&mut self.page_size).range(10..=1000));
+            if ui
+                .add(egui::DragValue::new(&mut self.page_size).range(10..=500))
+                .changed()
+            {
+                // Keep the first visible result on the new page
+                self.page_offset -= self.page_offset % self.page_size;
+            }
+
+            ui.separator();
+
+            if ui.button("Export CSV").clicked() {
+                self.export_format = Some(ExportFormat::Csv);
+                self.export_path = "scan_results.csv".to_string();
+            }
+            if ui.button("Export JSON").clicked() {
+                self.export_format = Some(ExportFormat::Json);
+                self.export_path = "scan_results.json".to_string();
+            }
+
+            ui.separator();
+
+            ui.checkbox(&mut self.auto_refresh, "Auto-refresh");
+            ui.add_enabled(
+                self.auto_refresh,
+                egui::DragValue::new(&mut self.refresh_interval)
+                    .range(0.1..=5.0)
+                    .speed(0.05)
+                    .suffix(" s"),
+            );
         });
 
+        // Address range filter
+        let mut reload = false;
+        ui.horizontal(|ui| {
+            for (label, input) in [
+                ("Address ≥", &mut self.address_min_input),
+                ("Address ≤", &mut self.address_max_input),
+            ] {
+                ui.label(label);
+                let valid = input.is_empty() || Self::parse_address_input(input).is_some();
+                ui.add(
+                    egui::TextEdit::singleline(input)
+                        .desired_width(130.0)
+                        .hint_text("0x...")
+                        .text_color_opt((!valid).then_some(egui::Color32::RED)),
+                );
+            }
+
+            if ui.button("Apply").clicked() {
+                reload = true;
+            }
+            if ui.add_enabled(has_address_filter, egui::Button::new("Clear")).clicked() {
+                self.address_min_input.clear();
+                self.address_max_input.clear();
+                reload = true;
+            }
+            if ui.button("In selected module").clicked() {
+                self.module_filter_request = true;
+            }
+        });
+        if reload {
+            if let Some(Ok(scanner)) = scanner.as_ref().map(|s| s.lock()) {
+                self.update_from_scanner(&scanner);
+            }
+        }
+
+        // Scans compare the raw stored bytes; the guard only changes the values shown here
+        let mut reread = false;
+        ui.horizontal(|ui| {
+            reread |= ui
+                .checkbox(&mut self.xor_guard, "XOR guard (display only)")
+                .on_hover_text("Shows values as the stored bytes XOR the key at the offset. Scans still compare the stored bytes.")
+                .changed();
+            ui.add_enabled_ui(self.xor_guard, |ui| {
+                ui.label("Key offset:");
+                reread |= ui
+                    .add(egui::DragValue::new(&mut self.xor_key_offset).range(-4096..=4096))
+                    .changed();
+            });
+        });
+        if reread {
+            if let Some(Ok(scanner)) = scanner.as_ref().map(|s| s.lock()) {
+                self.refresh_page(&scanner);
+            }
+        }
+
+        if self.auto_refresh {
+            let now = ui.input(|i| i.time);
+            if now - self.last_refresh_time >= self.refresh_interval as f64 {
+                if let Some(Ok(scanner)) = scanner.as_ref().map(|s| s.lock()) {
+                    self.refresh_page(&scanner);
+                }
+                self.last_refresh_time = now;
+            }
+            ui.ctx()
+                .request_repaint_after(std::time::Duration::from_secs_f32(self.refresh_interval));
+        }
+
+        if let Some(ref message) = self.export_message {
+            ui.label(message);
+        }
+
         ui.separator();
 
         // Results table
@@ -82,30 +578,39 @@ impl ResultsView {
             .show(ui, |ui| {
                 egui::Grid::new("results_grid")
                     .striped(true)
-                    .num_columns(4)
+                    .num_columns(6)
                     .show(ui, |ui| {
                         // Header
-                        ui.label(egui::RichText::new("Address").strong());
-                        ui.label(egui::RichText::new("Value").strong());
+                        self.sort_header(ui, "Address", SortBy::Address);
+                        ui.horizontal(|ui| {
+                            self.sort_header(ui, "Value", SortBy::Value);
+                            if self.value_type.is_integer() {
+                                ui.checkbox(&mut self.display_hex, "Hex");
+                            }
+                        });
                         ui.label(egui::RichText::new("Previous").strong());
+                        self.sort_header(ui, "Delta", SortBy::Delta);
+                        ui.label(egui::RichText::new("Trend").strong());
                         ui.label(egui::RichText::new("Actions").strong());
                         ui.end_row();
 
                         // Get current page of results
-                        let end = (self.page_offset + self.page_size).min(self.results.len());
-                        let page_results = &self.results[self.page_offset..end];
+                        let end = (self.page_offset + self.page_size).min(self.sorted_indices.len());
+                        let page_indices = self.sorted_indices[self.page_offset..end].to_vec();
 
                         // Display results
-                        for result in page_results {
-                            // Address
-                            ui.label(format!("0x{:X}", result.address));
+                        for index in page_indices {
+                            // Address and value (right-click for context menu)
+                            self.address_cell(ui, index);
 
-                            // Current value
-                            if let Some(value) = result.parse_value(self.value_type) {
-                                ui.label(value.to_string());
-                            } else {
-                                ui.label("???");
-                            }
+                            let value_text = self.results[index]
+                                .parse_value(self.value_type)
+                                .map(|value| self.format_value(&value))
+                                .unwrap_or_else(|| "???".to_string());
+                            ui.add(egui::Label::new(value_text).sense(egui::Sense::click()))
+                                .context_menu(|ui| self.row_context_menu(ui, index));
+
+                            let result = &self.results[index];
 
                             // Previous value
                             if let Some(prev_bytes) = &result.current_value {
@@ -118,7 +623,7 @@ impl ResultsView {
                                     {
                                         ui.colored_label(
                                             egui::Color32::YELLOW,
-                                            prev_val.to_string(),
+                                            self.format_value(&prev_val),
                                         );
                                     } else {
                                         ui.label("???");
@@ -130,12 +635,22 @@ impl ResultsView {
                                 ui.label("-");
                             }
 
+                            // Delta
+                            ui.label(Self::delta_text(result, self.value_type));
+
+                            // Trend
+                            ui.monospace(Self::trend(result, self.value_type));
+
                             // Actions
                             ui.horizontal(|ui| {
                                 if ui.small_button("Edit").clicked() {
                                     self.edit_address = Some(result.address);
                                     if let Some(value) = result.parse_value(self.value_type) {
-                                        self.edit_value = value.to_string();
+                                        self.edit_value = if self.display_hex {
+                                            Self::format_hex(&value)
+                                        } else {
+                                            value.to_string()
+                                        };
                                     }
                                 }
 
@@ -143,7 +658,11 @@ impl ResultsView {
                                     if let Some(scanner) = scanner {
                                         if let Ok(scanner) = scanner.lock() {
                                             if let Ok(_value) =
-                                                scanner.read_value(result.address, self.value_type)
+                                                scanner.read_value_endian(
+                                                    result.address,
+                                                    self.value_type,
+                                                    self.endianness,
+                                                )
                                             {
                                                 // Update display (note: this doesn't persist)
                                                 ui.ctx().request_repaint();
@@ -158,6 +677,16 @@ impl ResultsView {
                     });
             });
 
+        if let Some(index) = self.remove_request.take() {
+            if index < self.results.len() {
+                self.results.remove(index);
+                self.apply_sort();
+                self.page_offset = self.page_offset.min(self.results.len().saturating_sub(1));
+            }
+        }
+
+        self.show_export_dialog(ui.ctx());
+
         // Edit value dialog
         if let Some(edit_addr) = self.edit_address {
             egui::Window::new("Edit Value")
@@ -177,8 +706,8 @@ impl ResultsView {
                             if let Some(scanner) = scanner {
                                 if let Ok(scanner) = scanner.lock() {
                                     // Parse and write value
-                                    if let Ok(value) = self.parse_value(&self.edit_value) {
-                                        if let Err(e) = scanner.write_value(edit_addr, &value) {
+                                    if let Ok(value) = ScanValue::parse(&self.edit_value, self.value_type) {
+                                        if let Err(e) = scanner.write_value_endian(edit_addr, &value, self.endianness) {
                                             tracing::error!("Failed to write value: {}", e);
                                         } else {
                                             self.edit_address = None;
@@ -196,51 +725,165 @@ impl ResultsView {
         }
     }
 
-    fn parse_value(&self, value_str: &str) -> Result<crate::types::ScanValue, String> {
-        use crate::types::ScanValue;
-
-        match self.value_type {
-            ValueType::I8 => value_str
-                .parse::<i8>()
-                .map(ScanValue::I8)
-                .map_err(|e| e.to_string()),
-            ValueType::I16 => value_str
-                .parse::<i16>()
-                .map(ScanValue::I16)
-                .map_err(|e| e.to_string()),
-            ValueType::I32 => value_str
-                .parse::<i32>()
-                .map(ScanValue::I32)
-                .map_err(|e| e.to_string()),
-            ValueType::I64 => value_str
-                .parse::<i64>()
-                .map(ScanValue::I64)
-                .map_err(|e| e.to_string()),
-            ValueType::U8 => value_str
-                .parse::<u8>()
-                .map(ScanValue::U8)
-                .map_err(|e| e.to_string()),
-            ValueType::U16 => value_str
-                .parse::<u16>()
-                .map(ScanValue::U16)
-                .map_err(|e| e.to_string()),
-            ValueType::U32 => value_str
-                .parse::<u32>()
-                .map(ScanValue::U32)
-                .map_err(|e| e.to_string()),
-            ValueType::U64 => value_str
-                .parse::<u64>()
-                .map(ScanValue::U64)
-                .map_err(|e| e.to_string()),
-            ValueType::F32 => value_str
-                .parse::<f32>()
-                .map(ScanValue::F32)
-                .map_err(|e| e.to_string()),
-            ValueType::F64 => value_str
-                .parse::<f64>()
-                .map(ScanValue::F64)
-                .map_err(|e| e.to_string()),
-            ValueType::ByteArray(_) => Err("Byte array editing not yet implemented".to_string()),
+    /// Shows the export dialog and writes the file on confirmation
+    fn show_export_dialog(&mut self, ctx: &egui::Context) {
+        let Some(format) = self.export_format else {
+            return;
+        };
+
+        let mut confirmed = false;
+        let mut cancelled = false;
+
+        egui::Window::new("Export Results")
+            .collapsible(false)
+            .resizable(false)
+            .show(ctx, |ui| {
+                ui.horizontal(|ui| {
+                    ui.label("File:");
+                    ui.text_edit_singleline(&mut self.export_path);
+                });
+
+                ui.horizontal(|ui| {
+                    if ui.button("Export").clicked() {
+                        confirmed = true;
+                    }
+                    if ui.button("Cancel").clicked() {
+                        cancelled = true;
+                    }
+                });
+            });
+
+        if confirmed {
+            let results = ScanResults {
+                results: self.results.clone(),
+                value_type: self.value_type,
+                scan_count: 0,
+                endianness: self.endianness,
+            };
+
+            let contents = match format {
+                ExportFormat::Csv => Ok(results.to_csv()),
+                ExportFormat::Json => results.to_json().map_err(|e| e.to_string()),
+            };
+
+            self.export_message = Some(
+                match contents.and_then(|c| std::fs::write(&self.export_path, c).map_err(|e| e.to_string())) {
+                    Ok(()) => format!("Exported {} results to {}", self.results.len(), self.export_path),
+                    Err(e) => format!("Export failed: {}", e),
+                },
+            );
+            self.export_format = None;
+        } else if cancelled {
+            self.export_format = None;
+        }
+    }
+
+    /// Current minus previous value, colored by sign
+    ///
+    /// Byte arrays show the number of changed bytes instead.
+    fn delta_text(result: &ScanResult, value_type: ValueType) -> egui::RichText {
+        if let ValueType::ByteArray(_) = value_type {
+            let changed = result
+                .get_current_value()
+                .iter()
+                .zip(&result.previous_value)
+                .filter(|(current, previous)| current != previous)
+                .count();
+            let text = egui::RichText::new(format!("{} bytes", changed)).monospace();
+            let color = if changed == 0 { egui::Color32::GRAY } else { egui::Color32::YELLOW };
+            return text.color(color);
+        }
+
+        let (Some(current), Some(previous)) = (
+            result.parse_value(value_type),
+            ScanValue::from_bytes(&result.previous_value, value_type),
+        ) else {
+            return egui::RichText::new("-");
+        };
+
+        let delta = current.as_f64() - previous.as_f64();
+        let magnitude = match value_type {
+            ValueType::F32 | ValueType::F64 => format!("{:.3}", delta.abs()),
+            _ => format!("{}", delta.abs()),
+        };
+
+        let (text, color) = if delta > 0.0 {
+            (format!("+{}", magnitude), egui::Color32::GREEN)
+        } else if delta < 0.0 {
+            (format!("−{}", magnitude), egui::Color32::RED)
+        } else {
+            (magnitude, egui::Color32::GRAY)
+        };
+        egui::RichText::new(text).monospace().color(color)
+    }
+
+    /// Builds a ▲/▼/= sequence from consecutive differences in the value history
+    fn trend(result: &ScanResult, value_type: ValueType) -> String {
+        let mut values = result.get_history(value_type);
+        if let Some(current) = result.parse_value(value_type) {
+            values.push(current);
+        }
+
+        if values.len() < 2 {
+            return "-".to_string();
+        }
+
+        values
+            .windows(2)
+            .map(|pair| {
+                let (prev, next) = (pair[0].as_f64(), pair[1].as_f64());
+                if next > prev {
+                    '▲'
+                } else if next < prev {
+                    '▼'
+                } else {
+                    '='
+                }
+            })
+            .collect()
+    }
+
+    /// Formats a value, using hex for integers when display_hex is set
+    ///
+    /// Signed values are shown as their two's complement bits (-1i32 -> 0xFFFFFFFF).
+    fn format_value(&self, value: &ScanValue) -> String {
+        if self.display_hex {
+            Self::format_hex(value)
+        } else {
+            value.to_string()
+        }
+    }
+
+    /// Parses a hex address, with or without the 0x prefix
+    fn parse_address_input(input: &str) -> Option<usize> {
+        let input = input.trim();
+        let digits = input
+            .strip_prefix("0x")
+            .or_else(|| input.strip_prefix("0X"))
+            .unwrap_or(input);
+        usize::from_str_radix(digits, 16).ok()
+    }
+
+    /// Formats a result count, abbreviating large counts ("1.2M results")
+    fn format_count(count: usize) -> String {
+        if count >= 1_000_000 {
+            format!("{:.1}M results", count as f64 / 1_000_000.0)
+        } else {
+            format!("{} results", count)
+        }
+    }
+
+    fn format_hex(value: &ScanValue) -> String {
+        match *value {
+            ScanValue::I8(v) => format!("0x{:X}", v as u8),
+            ScanValue::I16(v) => format!("0x{:X}", v as u16),
+            ScanValue::I32(v) => format!("0x{:X}", v as u32),
+            ScanValue::I64(v) => format!("0x{:X}", v as u64),
+            ScanValue::U8(v) => format!("0x{:X}", v),
+            ScanValue::U16(v) => format!("0x{:X}", v),
+            ScanValue::U32(v) => format!("0x{:X}", v),
+            ScanValue::U64(v) => format!("0x{:X}", v),
+            _ => value.to_string(),
         }
     }
 }
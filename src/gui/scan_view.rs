@@ -1,4 +1,4 @@
-use crate::types::{ScanType, ValueType};
+use crate::types::{Endianness, RegionTypeFlags, ScanType, ScanValue, ValueType};
 use eframe::egui;
 
 /// UI component for scan configuration
@@ -7,6 +7,19 @@ pub struct ScanView {
     pub selected_value_type: ValueType,
     pub selected_scan_type: ScanType,
     pub alignment: usize,
+    pub region_types: RegionTypeFlags,
+    /// Threshold for the "by %" scan types
+    pub percent_change: f64,
+    /// Amount for the "by value" scan types, parsed as the selected value type
+    pub by_input: String,
+    /// First scan keeps at most this many results
+    pub max_results: usize,
+    /// Byte order of the values in the target's memory
+    pub endianness: Endianness,
+    /// Restrict the first scan to private read/write heap regions
+    pub heap_only: bool,
+    /// Fail the first scan after this many seconds (0 = no limit)
+    pub timeout_secs: u64,
 }
 
 impl Default for ScanView {
@@ -16,6 +29,13 @@ impl Default for ScanView {
             selected_value_type: ValueType::I32,
             selected_scan_type: ScanType::Exact,
             alignment: 4,
+            region_types: RegionTypeFlags::default(),
+            percent_change: 10.0,
+            by_input: String::new(),
+            max_results: 1_000_000,
+            endianness: Endianness::Little,
+            heap_only: false,
+            timeout_secs: 0,
         }
     }
 }
@@ -23,14 +43,44 @@ impl Default for ScanView {
 impl ScanView {
     pub fn reset(&mut self) {
         self.value_input.clear();
+        self.by_input.clear();
         self.selected_scan_type = ScanType::Exact;
     }
 
+    /// Whether the selected scan type takes its amount from the "By:" field
+    fn is_by_value_scan(&self) -> bool {
+        matches!(
+            self.selected_scan_type,
+            ScanType::IncreasedByExactly(_) | ScanType::DecreasedByExactly(_)
+        )
+    }
+
+    /// Parses the "By:" input as the selected value type (see `ScanValue::parse`)
+    pub fn by_amount(&self) -> Result<f64, String> {
+        if let ValueType::ByteArray(_) = self.selected_value_type {
+            return Err("Byte arrays cannot change by a value".to_string());
+        }
+        Ok(ScanValue::parse(&self.by_input, self.selected_value_type)?.as_f64().abs())
+    }
+
     pub fn ui(&mut self, ui: &mut egui::Ui) {
-        // Value input
+        // Value input (not needed for scans that compare against the previous value)
         ui.horizontal(|ui| {
             ui.label("Value:");
-            ui.text_edit_singleline(&mut self.value_input);
+            ui.add_enabled(
+                self.selected_scan_type.requires_value(),
+                egui::TextEdit::singleline(&mut self.value_input),
+            );
+            if self.selected_value_type.is_integer()
+                && (self.value_input.starts_with("0x") || self.value_input.starts_with("0X"))
+            {
+                ui.label(egui::RichText::new("(hex)").color(egui::Color32::LIGHT_GRAY));
+            }
+
+            if self.is_by_value_scan() {
+                ui.label("By:");
+                ui.text_edit_singleline(&mut self.by_input);
+            }
         });
 
         // Value type selector
@@ -102,15 +152,135 @@ impl ScanView {
                         ScanType::Unchanged,
                         "Unchanged",
                     );
+                    if ui
+                        .selectable_label(
+                            matches!(self.selected_scan_type, ScanType::IncreasedByPercentage(_)),
+                            "Increased by %",
+                        )
+                        .clicked()
+                    {
+                        self.selected_scan_type = ScanType::IncreasedByPercentage(self.percent_change);
+                    }
+                    if ui
+                        .selectable_label(
+                            matches!(self.selected_scan_type, ScanType::DecreasedByPercentage(_)),
+                            "Decreased by %",
+                        )
+                        .clicked()
+                    {
+                        self.selected_scan_type = ScanType::DecreasedByPercentage(self.percent_change);
+                    }
+                    if ui
+                        .selectable_label(
+                            matches!(self.selected_scan_type, ScanType::IncreasedByExactly(_)),
+                            "Increased by Value",
+                        )
+                        .clicked()
+                    {
+                        self.selected_scan_type = ScanType::IncreasedByExactly(0.0);
+                    }
+                    if ui
+                        .selectable_label(
+                            matches!(self.selected_scan_type, ScanType::DecreasedByExactly(_)),
+                            "Decreased by Value",
+                        )
+                        .clicked()
+                    {
+                        self.selected_scan_type = ScanType::DecreasedByExactly(0.0);
+                    }
                 });
         });
 
+        // Keep the "by value" amount in sync with the By: field
+        if self.is_by_value_scan() {
+            match self.by_amount() {
+                Ok(amount) => {
+                    self.selected_scan_type = match self.selected_scan_type {
+                        ScanType::IncreasedByExactly(_) => ScanType::IncreasedByExactly(amount),
+                        _ => ScanType::DecreasedByExactly(amount),
+                    };
+                }
+                Err(_) if !self.by_input.is_empty() => {
+                    ui.colored_label(egui::Color32::RED, "Invalid amount");
+                }
+                Err(_) => {}
+            }
+        }
+
+        // Percentage input for the "by %" scan types
+        if matches!(
+            self.selected_scan_type,
+            ScanType::IncreasedByPercentage(_) | ScanType::DecreasedByPercentage(_)
+        ) {
+            ui.horizontal(|ui| {
+                ui.label("% Change:");
+                ui.add(
+                    egui::DragValue::new(&mut self.percent_change)
+                        .range(0.0..=100.0)
+                        .speed(0.5)
+                        .suffix("%"),
+                );
+            });
+
+            self.selected_scan_type = match self.selected_scan_type {
+                ScanType::IncreasedByPercentage(_) => ScanType::IncreasedByPercentage(self.percent_change),
+                _ => ScanType::DecreasedByPercentage(self.percent_change),
+            };
+        }
+
         // Alignment option (advanced)
         ui.collapsing("Advanced", |ui| {
             ui.horizontal(|ui| {
                 ui.label("Alignment:");
                 ui.add(egui::DragValue::new(&mut self.alignment).range(1..=16));
             });
+
+            ui.horizontal(|ui| {
+                ui.label("Max results:");
+                ui.add(
+                    egui::DragValue::new(&mut self.max_results)
+                        .range(1000..=100_000_000)
+                        .speed(1000),
+                );
+            });
+
+            ui.horizontal(|ui| {
+                ui.label("Timeout (s):");
+                ui.add(egui::DragValue::new(&mut self.timeout_secs).range(0..=3600))
+                    .on_hover_text("0 = no limit");
+            });
+
+            ui.horizontal(|ui| {
+                ui.label("Endianness:");
+                egui::ComboBox::new("endianness", "")
+                    .selected_text(self.endianness.display_name())
+                    .show_ui(ui, |ui| {
+                        for endianness in [Endianness::Little, Endianness::Big] {
+                            ui.selectable_value(
+                                &mut self.endianness,
+                                endianness,
+                                endianness.display_name(),
+                            );
+                        }
+                    });
+            });
+
+            ui.horizontal(|ui| {
+                ui.label("Regions:");
+                for (flag, label) in [
+                    (RegionTypeFlags::PRIVATE, "Heap"),
+                    (RegionTypeFlags::IMAGE, "Module"),
+                    (RegionTypeFlags::MAPPED, "Mapped"),
+                ] {
+                    let mut enabled = self.region_types.contains(flag);
+                    if ui.checkbox(&mut enabled, label).changed() {
+                        self.region_types.set(flag, enabled);
+                    }
+                }
+            });
+
+            ui.checkbox(&mut self.heap_only, "Heap only (private read/write)")
+                .on_hover_text("Skips modules, mappings and read-only memory; game state usually lives in the heap");
         });
     }
 }
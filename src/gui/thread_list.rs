@@ -0,0 +1,75 @@
+use crate::platform::thread::{list_threads, resume_thread, suspend_thread, ThreadInfo};
+use eframe::egui;
+
+/// UI component for displaying the threads of the selected process
+#[derive(Default)]
+pub struct ThreadListView {
+    threads: Vec<ThreadInfo>,
+    pid: Option<u32>,
+    error: Option<String>,
+}
+
+impl ThreadListView {
+    /// Refreshes the thread list for the given process
+    fn refresh(&mut self, pid: u32) {
+        self.pid = Some(pid);
+        match list_threads(pid) {
+            Ok(threads) => {
+                self.threads = threads;
+                self.error = None;
+            }
+            Err(e) => {
+                self.threads.clear();
+                self.error = Some(format!("Failed to list threads: {}", e));
+            }
+        }
+    }
+
+    /// Renders the thread list UI
+    pub fn ui(&mut self, ui: &mut egui::Ui, pid: u32) {
+        // Auto-refresh when the selected process changes
+        if self.pid != Some(pid) {
+            self.refresh(pid);
+        }
+
+        ui.horizontal(|ui| {
+            if ui.button("Refresh").clicked() {
+                self.refresh(pid);
+            }
+            ui.label(format!("{} threads", self.threads.len()));
+        });
+
+        if let Some(ref error) = self.error {
+            ui.colored_label(egui::Color32::RED, error);
+        }
+
+        let mut action_error = None;
+
+        egui::ScrollArea::vertical()
+            .id_salt("thread_list_scroll")
+            .max_height(150.0)
+            .show(ui, |ui| {
+                for thread in &self.threads {
+                    ui.horizontal(|ui| {
+                        ui.monospace(format!("TID {:<8}", thread.tid));
+                        ui.label(format!("Priority {}", thread.base_priority));
+
+                        if ui.small_button("Suspend").clicked() {
+                            if let Err(e) = suspend_thread(thread.tid) {
+                                action_error = Some(e.to_string());
+                            }
+                        }
+                        if ui.small_button("Resume").clicked() {
+                            if let Err(e) = resume_thread(thread.tid) {
+                                action_error = Some(e.to_string());
+                            }
+                        }
+                    });
+                }
+            });
+
+        if action_error.is_some() {
+            self.error = action_error;
+        }
+    }
+}
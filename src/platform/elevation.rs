@@ -1,7 +1,12 @@
 use anyhow::Result;
 use std::ptr;
-use windows::Win32::Foundation::HWND;
-use windows::Win32::Security::{GetTokenInformation, TokenElevation, TOKEN_ELEVATION, TOKEN_QUERY};
+use windows::Win32::Foundation::{CloseHandle, GetLastError, BOOL, ERROR_NOT_ALL_ASSIGNED, HANDLE, HWND, LUID};
+use windows::Win32::Security::{
+    AdjustTokenPrivileges, GetSidSubAuthority, GetSidSubAuthorityCount, GetTokenInformation,
+    LookupPrivilegeValueW, PrivilegeCheck, TokenElevation, TokenIntegrityLevel, LUID_AND_ATTRIBUTES,
+    PRIVILEGE_SET, SE_DEBUG_NAME, SE_PRIVILEGE_ENABLED, TOKEN_ADJUST_PRIVILEGES, TOKEN_ELEVATION,
+    TOKEN_MANDATORY_LABEL, TOKEN_PRIVILEGES, TOKEN_QUERY,
+};
 use windows::Win32::System::Threading::{GetCurrentProcess, OpenProcessToken};
 use windows::Win32::UI::Shell::ShellExecuteW;
 use windows::Win32::UI::WindowsAndMessaging::SW_SHOWNORMAL;
@@ -31,6 +36,163 @@ pub fn is_elevated() -> Result<bool> {
     }
 }
 
+/// Mandatory integrity level of a process token
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum IntegrityLevel {
+    Untrusted,
+    Low,
+    Medium,
+    High,
+    System,
+}
+
+impl IntegrityLevel {
+    /// Maps a mandatory label RID (SECURITY_MANDATORY_*_RID) to a level
+    ///
+    /// RIDs between two levels (e.g. medium-plus) round down; protected
+    /// process RIDs count as System.
+    pub fn from_rid(rid: u32) -> Self {
+        match rid {
+            0..0x1000 => IntegrityLevel::Untrusted,
+            0x1000..0x2000 => IntegrityLevel::Low,
+            0x2000..0x3000 => IntegrityLevel::Medium,
+            0x3000..0x4000 => IntegrityLevel::High,
+            _ => IntegrityLevel::System,
+        }
+    }
+
+    /// Display name of the level
+    pub fn label(&self) -> &'static str {
+        match self {
+            IntegrityLevel::Untrusted => "Untrusted",
+            IntegrityLevel::Low => "Low",
+            IntegrityLevel::Medium => "Medium",
+            IntegrityLevel::High => "High",
+            IntegrityLevel::System => "System",
+        }
+    }
+}
+
+impl std::fmt::Display for IntegrityLevel {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.label())
+    }
+}
+
+/// Returns the integrity level of the current process
+pub fn get_integrity_level() -> Result<IntegrityLevel> {
+    unsafe { get_process_integrity_level(GetCurrentProcess()) }
+}
+
+/// Returns the integrity level of a process
+///
+/// The handle needs PROCESS_QUERY_LIMITED_INFORMATION access.
+///
+/// # Safety
+/// `handle` must be a valid process handle
+pub unsafe fn get_process_integrity_level(handle: HANDLE) -> Result<IntegrityLevel> {
+    let mut token = HANDLE::default();
+    OpenProcessToken(handle, TOKEN_QUERY, &mut token)?;
+
+    let result = token_integrity_level(token);
+    let _ = CloseHandle(token);
+    result
+}
+
+/// # Safety
+/// `token` must be a valid token handle opened with TOKEN_QUERY
+unsafe fn token_integrity_level(token: HANDLE) -> Result<IntegrityLevel> {
+    // The label is followed by its variable-length SID, so query the size first
+    let mut length = 0u32;
+    let _ = GetTokenInformation(token, TokenIntegrityLevel, None, 0, &mut length);
+    if length == 0 {
+        return Err(anyhow::anyhow!("Failed to query integrity level size"));
+    }
+
+    // u64 storage keeps the buffer aligned for TOKEN_MANDATORY_LABEL
+    let mut buffer = vec![0u64; (length as usize).div_ceil(8)];
+    GetTokenInformation(
+        token,
+        TokenIntegrityLevel,
+        Some(buffer.as_mut_ptr() as *mut _),
+        length,
+        &mut length,
+    )?;
+
+    let label = &*(buffer.as_ptr() as *const TOKEN_MANDATORY_LABEL);
+    let sid = label.Label.Sid;
+
+    // The RID is the last sub-authority of the label SID
+    let count = *GetSidSubAuthorityCount(sid);
+    if count == 0 {
+        return Err(anyhow::anyhow!("Integrity label SID has no sub-authorities"));
+    }
+    let rid = *GetSidSubAuthority(sid, count as u32 - 1);
+
+    Ok(IntegrityLevel::from_rid(rid))
+}
+
+/// PRIVILEGE_SET control flag: all listed privileges must be enabled
+const PRIVILEGE_SET_ALL_NECESSARY: u32 = 1;
+
+/// Enables SeDebugPrivilege on the current process token
+///
+/// Lets the scanner open protected or other-user processes. Requires an
+/// elevated process; returns Ok immediately if the privilege is already enabled.
+pub fn request_debug_privilege() -> Result<()> {
+    unsafe {
+        let mut token = HANDLE::default();
+        OpenProcessToken(
+            GetCurrentProcess(),
+            TOKEN_ADJUST_PRIVILEGES | TOKEN_QUERY,
+            &mut token,
+        )?;
+
+        let result = enable_debug_privilege(token);
+        let _ = CloseHandle(token);
+        result
+    }
+}
+
+/// # Safety
+/// `token` must be a valid token handle opened with TOKEN_ADJUST_PRIVILEGES | TOKEN_QUERY
+unsafe fn enable_debug_privilege(token: HANDLE) -> Result<()> {
+    let mut luid = LUID::default();
+    LookupPrivilegeValueW(PCWSTR::null(), SE_DEBUG_NAME, &mut luid)?;
+
+    // Skip the adjustment if the privilege is already enabled
+    let mut required = PRIVILEGE_SET {
+        PrivilegeCount: 1,
+        Control: PRIVILEGE_SET_ALL_NECESSARY,
+        Privilege: [LUID_AND_ATTRIBUTES {
+            Luid: luid,
+            Attributes: Default::default(),
+        }],
+    };
+    let mut enabled = BOOL::default();
+    if PrivilegeCheck(token, &mut required, &mut enabled).is_ok() && enabled.as_bool() {
+        return Ok(());
+    }
+
+    let privileges = TOKEN_PRIVILEGES {
+        PrivilegeCount: 1,
+        Privileges: [LUID_AND_ATTRIBUTES {
+            Luid: luid,
+            Attributes: SE_PRIVILEGE_ENABLED,
+        }],
+    };
+    AdjustTokenPrivileges(token, false, Some(&privileges), 0, None, None)?;
+
+    // AdjustTokenPrivileges succeeds even when the token does not hold the privilege
+    if GetLastError() == ERROR_NOT_ALL_ASSIGNED {
+        return Err(anyhow::anyhow!(
+            "SeDebugPrivilege is not held by this token (run as administrator)"
+        ));
+    }
+
+    Ok(())
+}
+
 /// Restarts the current application with administrator privileges
 /// This will show the Windows UAC dialog
 pub fn restart_as_admin() -> Result<()> {
@@ -73,4 +235,26 @@ mod tests {
         // Just test that it doesn't crash
         let _ = is_elevated();
     }
+
+    #[test]
+    fn test_get_integrity_level() {
+        // A normal test run is at least medium integrity
+        let level = get_integrity_level().expect("Failed to query integrity level");
+        assert!(level >= IntegrityLevel::Medium);
+    }
+
+    #[test]
+    fn test_integrity_level_from_rid() {
+        assert_eq!(IntegrityLevel::from_rid(0x0000), IntegrityLevel::Untrusted);
+        assert_eq!(IntegrityLevel::from_rid(0x1000), IntegrityLevel::Low);
+        assert_eq!(IntegrityLevel::from_rid(0x2100), IntegrityLevel::Medium);
+        assert_eq!(IntegrityLevel::from_rid(0x3000), IntegrityLevel::High);
+        assert_eq!(IntegrityLevel::from_rid(0x5000), IntegrityLevel::System);
+    }
+
+    #[test]
+    fn test_request_debug_privilege() {
+        // Fails without elevation; only check that it doesn't crash
+        let _ = request_debug_privilege();
+    }
 }
@@ -7,5 +7,8 @@ pub mod elevation;
 #[cfg(target_os = "windows")]
 pub mod module;
 
+#[cfg(target_os = "windows")]
+pub mod thread;
+
 #[cfg(target_os = "windows")]
 pub use windows::*;
@@ -1,19 +1,36 @@
 /// Module enumeration for process
 
+use super::windows::{query_process_base_address, read_process_memory, read_until_null};
 use anyhow::Result;
-use windows::Win32::Foundation::HANDLE;
+use windows::core::PWSTR;
+use windows::Win32::Foundation::{CloseHandle, HANDLE};
+use windows::Win32::System::Threading::{
+    OpenProcess, QueryFullProcessImageNameW, PROCESS_NAME_WIN32, PROCESS_QUERY_LIMITED_INFORMATION,
+    PROCESS_VM_READ,
+};
 use windows::Win32::System::Diagnostics::ToolHelp::{
     CreateToolhelp32Snapshot, Module32FirstW, Module32NextW, MODULEENTRY32W, TH32CS_SNAPMODULE,
     TH32CS_SNAPMODULE32,
 };
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Default)]
 pub struct ModuleInfo {
     pub name: String,
     pub base_address: usize,
     pub size: usize,
 }
 
+/// PE セクション情報
+#[derive(Clone, Debug)]
+pub struct SectionInfo {
+    pub name: String,
+    /// セクションの絶対アドレス（module_base + VirtualAddress）
+    pub address: usize,
+    pub size: usize,
+    /// IMAGE_SCN_MEM_EXECUTE が立っているか
+    pub executable: bool,
+}
+
 /// プロセスのモジュール一覧を取得
 pub fn list_modules(process_id: u32) -> Result<Vec<ModuleInfo>> {
     let snapshot = unsafe {
@@ -54,10 +71,380 @@ pub fn list_modules(process_id: u32) -> Result<Vec<ModuleInfo>> {
 }
 
 /// メインモジュール（実行ファイル）を取得
+///
+/// スナップショットが失敗する場合（保護プロセスなど）は PEB から取得する
 pub fn get_main_module(process_id: u32) -> Result<ModuleInfo> {
+    match list_modules(process_id) {
+        Ok(modules) if !modules.is_empty() => Ok(modules.into_iter().next().unwrap()),
+        result => {
+            let reason = result.err().map_or_else(|| "no modules".to_string(), |e| e.to_string());
+            tracing::warn!("list_modules failed for PID {} ({}), falling back to PEB", process_id, reason);
+            main_module_from_peb(process_id)
+        }
+    }
+}
+
+/// PEB の ImageBaseAddress と PE ヘッダーからメインモジュール情報を組み立てる
+fn main_module_from_peb(process_id: u32) -> Result<ModuleInfo> {
+    let handle = unsafe {
+        OpenProcess(PROCESS_QUERY_LIMITED_INFORMATION | PROCESS_VM_READ, false, process_id)?
+    };
+
+    let result = (|| {
+        let base_address = query_process_base_address(handle)?;
+        Ok(ModuleInfo {
+            name: query_image_name(handle).unwrap_or_else(|| "<main>".to_string()),
+            base_address,
+            size: read_size_of_image(handle, base_address)?,
+        })
+    })();
+
+    unsafe {
+        let _ = CloseHandle(handle);
+    }
+    result
+}
+
+/// 実行ファイルのパスからファイル名部分を取得
+fn query_image_name(handle: HANDLE) -> Option<String> {
+    let mut buffer = [0u16; 1024];
+    let mut size = buffer.len() as u32;
+    unsafe {
+        QueryFullProcessImageNameW(handle, PROCESS_NAME_WIN32, PWSTR(buffer.as_mut_ptr()), &mut size).ok()?;
+    }
+    let path = String::from_utf16_lossy(&buffer[..size as usize]);
+    path.rsplit(['\\', '/']).next().map(str::to_string)
+}
+
+/// PE Optional Header の SizeOfImage を読み取る
+fn read_size_of_image(handle: HANDLE, module_base: usize) -> Result<usize> {
+    let dos = read_process_memory(handle, module_base, 0x40)?;
+    if dos[0..2] != *b"MZ" {
+        return Err(anyhow::anyhow!("Invalid DOS signature at 0x{:X}", module_base));
+    }
+    let e_lfanew = u32::from_le_bytes(dos[0x3C..0x40].try_into().unwrap()) as usize;
+
+    // SizeOfImage は Optional Header + 0x38 (PE32 / PE32+ 共通)
+    let nt = read_process_memory(handle, module_base + e_lfanew, 0x18 + 0x3C)?;
+    if nt[0..4] != *b"PE\0\0" {
+        return Err(anyhow::anyhow!("Invalid PE signature at 0x{:X}", module_base + e_lfanew));
+    }
+    Ok(u32::from_le_bytes(nt[0x18 + 0x38..0x18 + 0x3C].try_into().unwrap()) as usize)
+}
+
+/// 指定した名前のモジュールを検索（大文字小文字は区別しない）
+pub fn find_module(process_id: u32, names: &[&str]) -> Result<ModuleInfo> {
     let modules = list_modules(process_id)?;
     modules
         .into_iter()
-        .next()
-        .ok_or_else(|| anyhow::anyhow!("No modules found"))
+        .find(|m| names.iter().any(|n| m.name.eq_ignore_ascii_case(n)))
+        .ok_or_else(|| anyhow::anyhow!("Module not found: {}", names.join(", ")))
+}
+
+/// 指定アドレスを含むモジュールを取得
+pub fn find_module_containing(process_id: u32, address: usize) -> Result<ModuleInfo> {
+    list_modules(process_id)?
+        .into_iter()
+        .find(|m| address >= m.base_address && address < m.base_address + m.size)
+        .ok_or_else(|| anyhow::anyhow!("No module contains address 0x{:X}", address))
+}
+
+/// リモートプロセスのモジュールの Export Table を読み取る
+///
+/// 名前付きエクスポートのみを (名前, 絶対アドレス) として返す。
+/// フォワーダ（別 DLL への転送）は除外する
+pub fn list_exports(handle: HANDLE, module_base: usize) -> Result<Vec<(String, usize)>> {
+    let Some(directory) = ExportDirectory::read(handle, module_base)? else {
+        return Ok(Vec::new());
+    };
+
+    let mut exports = Vec::with_capacity(directory.number_of_names);
+    for i in 0..directory.number_of_names {
+        let (Some(name), Some(function_rva)) = (directory.name(i), directory.function_rva(i)) else {
+            continue;
+        };
+        if directory.is_forwarder(function_rva) {
+            continue;
+        }
+
+        exports.push((String::from_utf8_lossy(name).into_owned(), module_base + function_rva));
+    }
+
+    Ok(exports)
+}
+
+/// リモートプロセスのモジュールから 1 つのエクスポートのアドレスを解決する
+///
+/// GetProcAddress と違い自プロセスにロードされていないモジュールにも使える。
+/// 名前テーブルはソート済みなので二分探索し、見つからなければ線形探索する
+pub fn resolve_remote_export(handle: HANDLE, module: &ModuleInfo, export_name: &str) -> Result<usize> {
+    let directory = ExportDirectory::read(handle, module.base_address)?
+        .ok_or_else(|| anyhow::anyhow!("{} has no export table", module.name))?;
+
+    let target = export_name.as_bytes();
+    let index = directory
+        .binary_search_name(target)
+        .or_else(|| (0..directory.number_of_names).find(|&i| directory.name(i) == Some(target)))
+        .ok_or_else(|| anyhow::anyhow!("Export not found in {}: {}", module.name, export_name))?;
+
+    let function_rva = directory
+        .function_rva(index)
+        .ok_or_else(|| anyhow::anyhow!("Invalid export table entry for {}", export_name))?;
+    if directory.is_forwarder(function_rva) {
+        return Err(anyhow::anyhow!("Export {} in {} is forwarded", export_name, module.name));
+    }
+    if module.size != 0 && function_rva >= module.size {
+        return Err(anyhow::anyhow!(
+            "Export {} RVA 0x{:X} is outside {}",
+            export_name, function_rva, module.name
+        ));
+    }
+
+    Ok(module.base_address + function_rva)
+}
+
+/// 読み取り済みの IMAGE_EXPORT_DIRECTORY
+struct ExportDirectory {
+    /// Export セクション全体（名前・テーブルは通常この範囲内にある）
+    block: Vec<u8>,
+    export_rva: usize,
+    export_size: usize,
+    number_of_functions: usize,
+    number_of_names: usize,
+    address_of_functions: usize,
+    address_of_names: usize,
+    address_of_name_ordinals: usize,
+}
+
+impl ExportDirectory {
+    /// Export Table が無いモジュールでは None を返す
+    fn read(handle: HANDLE, module_base: usize) -> Result<Option<Self>> {
+        // IMAGE_DIRECTORY_ENTRY_EXPORT (index 0)
+        let (export_rva, export_size, _) = read_data_directory(handle, module_base, 0)?;
+        if export_rva == 0 || export_size < 0x28 {
+            return Ok(None);
+        }
+
+        let mut directory = Self {
+            block: read_process_memory(handle, module_base + export_rva, export_size)?,
+            export_rva,
+            export_size,
+            number_of_functions: 0,
+            number_of_names: 0,
+            address_of_functions: 0,
+            address_of_names: 0,
+            address_of_name_ordinals: 0,
+        };
+        directory.number_of_functions = directory.read_u32(export_rva + 0x14).unwrap_or(0) as usize;
+        directory.number_of_names = directory.read_u32(export_rva + 0x18).unwrap_or(0) as usize;
+        directory.address_of_functions = directory.read_u32(export_rva + 0x1C).unwrap_or(0) as usize;
+        directory.address_of_names = directory.read_u32(export_rva + 0x20).unwrap_or(0) as usize;
+        directory.address_of_name_ordinals = directory.read_u32(export_rva + 0x24).unwrap_or(0) as usize;
+
+        Ok(Some(directory))
+    }
+
+    fn read_u32(&self, rva: usize) -> Option<u32> {
+        let offset = rva.checked_sub(self.export_rva)?;
+        let bytes = self.block.get(offset..offset + 4)?;
+        Some(u32::from_le_bytes(bytes.try_into().unwrap()))
+    }
+
+    fn read_u16(&self, rva: usize) -> Option<u16> {
+        let offset = rva.checked_sub(self.export_rva)?;
+        let bytes = self.block.get(offset..offset + 2)?;
+        Some(u16::from_le_bytes(bytes.try_into().unwrap()))
+    }
+
+    /// 名前テーブルの index 番目の名前（NULL 終端を除く）
+    fn name(&self, index: usize) -> Option<&[u8]> {
+        let name_rva = self.read_u32(self.address_of_names + index * 4)? as usize;
+        let name_bytes = self.block.get(name_rva.checked_sub(self.export_rva)?..)?;
+        let len = name_bytes.iter().position(|&b| b == 0).unwrap_or(name_bytes.len());
+        Some(&name_bytes[..len])
+    }
+
+    /// 名前テーブルの index 番目に対応する関数の RVA
+    fn function_rva(&self, index: usize) -> Option<usize> {
+        let ordinal = self.read_u16(self.address_of_name_ordinals + index * 2)? as usize;
+        if ordinal >= self.number_of_functions {
+            return None;
+        }
+        Some(self.read_u32(self.address_of_functions + ordinal * 4)? as usize)
+    }
+
+    /// フォワーダは Export セクション内の文字列を指す
+    fn is_forwarder(&self, function_rva: usize) -> bool {
+        (self.export_rva..self.export_rva + self.export_size).contains(&function_rva)
+    }
+
+    /// ソート済みの名前テーブルを二分探索する
+    fn binary_search_name(&self, target: &[u8]) -> Option<usize> {
+        let (mut low, mut high) = (0, self.number_of_names);
+        while low < high {
+            let mid = low + (high - low) / 2;
+            match self.name(mid)?.cmp(target) {
+                std::cmp::Ordering::Equal => return Some(mid),
+                std::cmp::Ordering::Less => low = mid + 1,
+                std::cmp::Ordering::Greater => high = mid,
+            }
+        }
+        None
+    }
+}
+
+/// PE ヘッダーから指定したデータディレクトリを読み取る
+///
+/// (RVA, サイズ, PE32+ かどうか) を返す
+fn read_data_directory(handle: HANDLE, module_base: usize, index: usize) -> Result<(usize, usize, bool)> {
+    // DOS ヘッダー -> e_lfanew
+    let dos = read_process_memory(handle, module_base, 0x40)?;
+    if dos[0..2] != *b"MZ" {
+        return Err(anyhow::anyhow!("Invalid DOS signature at 0x{:X}", module_base));
+    }
+    let e_lfanew = u32::from_le_bytes(dos[0x3C..0x40].try_into().unwrap()) as usize;
+
+    // PE ヘッダー
+    let nt = read_process_memory(handle, module_base + e_lfanew, 0x108)?;
+    if nt[0..4] != *b"PE\0\0" {
+        return Err(anyhow::anyhow!("Invalid PE signature at 0x{:X}", module_base + e_lfanew));
+    }
+
+    // Optional Header の Magic で PE32 / PE32+ を判定
+    let optional = &nt[0x18..];
+    let (data_dir_offset, is_pe32_plus) = match u16::from_le_bytes([optional[0], optional[1]]) {
+        0x10B => (0x60, false), // PE32
+        0x20B => (0x70, true),  // PE32+
+        magic => return Err(anyhow::anyhow!("Unknown optional header magic: 0x{:X}", magic)),
+    };
+
+    let entry = &optional[data_dir_offset + index * 8..data_dir_offset + index * 8 + 8];
+    let rva = u32::from_le_bytes(entry[0..4].try_into().unwrap()) as usize;
+    let size = u32::from_le_bytes(entry[4..8].try_into().unwrap()) as usize;
+    Ok((rva, size, is_pe32_plus))
+}
+
+/// リモートプロセスのモジュールから指定した名前のエクスポートを検索
+///
+/// モジュールサイズが不明な場合用。分かっている場合は resolve_remote_export を使う
+pub fn find_export(handle: HANDLE, module_base: usize, name: &str) -> Result<usize> {
+    let module = ModuleInfo {
+        name: format!("module at 0x{:X}", module_base),
+        base_address: module_base,
+        size: 0,
+    };
+    resolve_remote_export(handle, &module, name)
+}
+
+/// リモートプロセスのモジュールのセクションヘッダーを読み取る
+pub fn list_sections(handle: HANDLE, module_base: usize) -> Result<Vec<SectionInfo>> {
+    let dos = read_process_memory(handle, module_base, 0x40)?;
+    if dos[0..2] != *b"MZ" {
+        return Err(anyhow::anyhow!("Invalid DOS signature at 0x{:X}", module_base));
+    }
+    let e_lfanew = u32::from_le_bytes(dos[0x3C..0x40].try_into().unwrap()) as usize;
+
+    // PE シグネチャ + IMAGE_FILE_HEADER
+    let nt = read_process_memory(handle, module_base + e_lfanew, 0x18)?;
+    if nt[0..4] != *b"PE\0\0" {
+        return Err(anyhow::anyhow!("Invalid PE signature at 0x{:X}", module_base + e_lfanew));
+    }
+    let number_of_sections = u16::from_le_bytes([nt[6], nt[7]]) as usize;
+    let size_of_optional_header = u16::from_le_bytes([nt[20], nt[21]]) as usize;
+
+    // IMAGE_SECTION_HEADER は 40 バイト (Characteristics は末尾 4 バイト)
+    const IMAGE_SCN_MEM_EXECUTE: u32 = 0x2000_0000;
+    let section_table = module_base + e_lfanew + 0x18 + size_of_optional_header;
+    let data = read_process_memory(handle, section_table, number_of_sections * 40)?;
+
+    Ok(data
+        .chunks_exact(40)
+        .map(|header| {
+            let len = header[..8].iter().position(|&b| b == 0).unwrap_or(8);
+            SectionInfo {
+                name: String::from_utf8_lossy(&header[..len]).into_owned(),
+                address: module_base + u32::from_le_bytes(header[12..16].try_into().unwrap()) as usize,
+                size: u32::from_le_bytes(header[8..12].try_into().unwrap()) as usize,
+                executable: u32::from_le_bytes(header[36..40].try_into().unwrap()) & IMAGE_SCN_MEM_EXECUTE != 0,
+            }
+        })
+        .collect())
+}
+
+/// インポート情報
+#[derive(Clone, Debug)]
+pub struct ImportInfo {
+    pub dll_name: String,
+    pub function_name: String,
+    /// IAT エントリの絶対アドレス
+    pub iat_address: usize,
+}
+
+/// リモートプロセスのモジュールの Import Table を読み取る
+///
+/// 名前でインポートされた関数のみを返す（序数インポートは除外）
+pub fn list_imports(handle: HANDLE, module_base: usize) -> Result<Vec<ImportInfo>> {
+    // IMAGE_DIRECTORY_ENTRY_IMPORT (index 1)
+    let (import_rva, import_size, is_pe32_plus) = read_data_directory(handle, module_base, 1)?;
+    if import_rva == 0 || import_size == 0 {
+        return Ok(Vec::new());
+    }
+
+    let thunk_size = if is_pe32_plus { 8 } else { 4 };
+    let ordinal_flag = if is_pe32_plus { 1u64 << 63 } else { 1u64 << 31 };
+
+    let mut imports = Vec::new();
+
+    // IMAGE_IMPORT_DESCRIPTOR (20 バイト) の配列。全ゼロのエントリで終端
+    for index in 0..1024 {
+        let descriptor = read_process_memory(handle, module_base + import_rva + index * 20, 20)?;
+        let original_first_thunk = u32::from_le_bytes(descriptor[0..4].try_into().unwrap()) as usize;
+        let name_rva = u32::from_le_bytes(descriptor[12..16].try_into().unwrap()) as usize;
+        let first_thunk = u32::from_le_bytes(descriptor[16..20].try_into().unwrap()) as usize;
+
+        if name_rva == 0 && first_thunk == 0 {
+            break;
+        }
+
+        let Ok(dll_name) = read_until_null(handle, module_base + name_rva, 256, 1) else {
+            continue;
+        };
+        let dll_name = String::from_utf8_lossy(&dll_name).into_owned();
+
+        // 名前は INT (OriginalFirstThunk) から読む。無い場合は IAT 自体を使う
+        let lookup_rva = if original_first_thunk != 0 { original_first_thunk } else { first_thunk };
+
+        for i in 0..0x10000 {
+            let Ok(thunk) = read_process_memory(handle, module_base + lookup_rva + i * thunk_size, thunk_size)
+            else {
+                break;
+            };
+            let value = if is_pe32_plus {
+                u64::from_le_bytes(thunk[..8].try_into().unwrap())
+            } else {
+                u32::from_le_bytes(thunk[..4].try_into().unwrap()) as u64
+            };
+
+            if value == 0 {
+                break;
+            }
+            if value & ordinal_flag != 0 {
+                continue;
+            }
+
+            // IMAGE_IMPORT_BY_NAME { Hint: u16, Name: [u8] }
+            let hint_name_rva = (value & 0x7FFF_FFFF) as usize;
+            let Ok(function_name) = read_until_null(handle, module_base + hint_name_rva + 2, 256, 1) else {
+                continue;
+            };
+
+            imports.push(ImportInfo {
+                dll_name: dll_name.clone(),
+                function_name: String::from_utf8_lossy(&function_name).into_owned(),
+                iat_address: module_base + first_thunk + i * thunk_size,
+            });
+        }
+    }
+
+    Ok(imports)
 }
@@ -0,0 +1,130 @@
+use anyhow::{anyhow, Result};
+use std::mem;
+use windows::Win32::Foundation::{CloseHandle, INVALID_HANDLE_VALUE};
+use windows::Win32::System::Diagnostics::ToolHelp::{
+    CreateToolhelp32Snapshot, Thread32First, Thread32Next, TH32CS_SNAPTHREAD, THREADENTRY32,
+};
+use windows::Win32::System::Threading::{OpenThread, ResumeThread, SuspendThread, THREAD_SUSPEND_RESUME};
+
+/// Information about a thread
+#[derive(Clone, Debug)]
+pub struct ThreadInfo {
+    pub tid: u32,
+    pub owner_pid: u32,
+    pub base_priority: i32,
+}
+
+/// Lists all threads owned by the given process
+pub fn list_threads(pid: u32) -> Result<Vec<ThreadInfo>> {
+    unsafe {
+        let snapshot = CreateToolhelp32Snapshot(TH32CS_SNAPTHREAD, 0)?;
+        if snapshot == INVALID_HANDLE_VALUE {
+            return Err(anyhow!("Failed to create thread snapshot"));
+        }
+
+        let mut threads = Vec::new();
+        let mut entry: THREADENTRY32 = mem::zeroed();
+        entry.dwSize = mem::size_of::<THREADENTRY32>() as u32;
+
+        // The snapshot always contains every thread in the system
+        if Thread32First(snapshot, &mut entry).is_ok() {
+            loop {
+                if entry.th32OwnerProcessID == pid {
+                    threads.push(ThreadInfo {
+                        tid: entry.th32ThreadID,
+                        owner_pid: entry.th32OwnerProcessID,
+                        base_priority: entry.tpBasePri,
+                    });
+                }
+
+                if Thread32Next(snapshot, &mut entry).is_err() {
+                    break;
+                }
+            }
+        }
+
+        CloseHandle(snapshot)?;
+        Ok(threads)
+    }
+}
+
+/// Suspends a thread
+pub fn suspend_thread(tid: u32) -> Result<()> {
+    unsafe {
+        let thread = OpenThread(THREAD_SUSPEND_RESUME, false, tid)?;
+        let result = SuspendThread(thread);
+        let _ = CloseHandle(thread);
+
+        if result == u32::MAX {
+            return Err(anyhow!("Failed to suspend thread {}", tid));
+        }
+        Ok(())
+    }
+}
+
+/// Resumes a thread
+pub fn resume_thread(tid: u32) -> Result<()> {
+    unsafe {
+        let thread = OpenThread(THREAD_SUSPEND_RESUME, false, tid)?;
+        let result = ResumeThread(thread);
+        let _ = CloseHandle(thread);
+
+        if result == u32::MAX {
+            return Err(anyhow!("Failed to resume thread {}", tid));
+        }
+        Ok(())
+    }
+}
+
+/// Suspends a set of threads and resumes them when dropped
+///
+/// Threads that could not be suspended are skipped and not resumed.
+pub struct SuspendedThreads {
+    suspended: Vec<u32>,
+}
+
+impl SuspendedThreads {
+    /// Suspends all given threads
+    pub fn new(tids: &[u32]) -> Self {
+        let suspended = tids
+            .iter()
+            .copied()
+            .filter(|&tid| match suspend_thread(tid) {
+                Ok(()) => true,
+                Err(e) => {
+                    tracing::warn!("{}", e);
+                    false
+                }
+            })
+            .collect();
+
+        Self { suspended }
+    }
+
+    /// Returns the IDs of the threads that were actually suspended
+    pub fn thread_ids(&self) -> &[u32] {
+        &self.suspended
+    }
+}
+
+impl Drop for SuspendedThreads {
+    fn drop(&mut self) {
+        for &tid in &self.suspended {
+            if let Err(e) = resume_thread(tid) {
+                tracing::error!("{}", e);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_list_threads() {
+        let threads = list_threads(std::process::id()).expect("Failed to list threads");
+        assert!(!threads.is_empty(), "Current process should have at least one thread");
+        assert!(threads.iter().all(|t| t.owner_pid == std::process::id()));
+    }
+}
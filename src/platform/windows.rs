@@ -1,7 +1,26 @@
+use crate::platform::elevation::{get_process_integrity_level, IntegrityLevel};
 use anyhow::{anyhow, Result};
+use std::collections::HashMap;
+use std::ffi::c_void;
 use std::mem;
 pub use windows::Win32::Foundation::HANDLE;
-use windows::Win32::Foundation::{CloseHandle, INVALID_HANDLE_VALUE};
+use windows::Win32::Foundation::{
+    CloseHandle, DuplicateHandle, BOOL, DUPLICATE_SAME_ACCESS, ERROR_ACCESS_DENIED, ERROR_NOACCESS, HWND,
+    INVALID_HANDLE_VALUE, LPARAM, STILL_ACTIVE, WAIT_OBJECT_0,
+};
+use windows::Wdk::System::Threading::{
+    NtQueryInformationProcess, ProcessBasicInformation, ProcessCommandLineInformation,
+};
+use windows::core::{w, BSTR, VARIANT};
+use windows::Win32::System::Com::{
+    CoCreateInstance, CoInitializeEx, CoSetProxyBlanket, CoUninitialize, CLSCTX_INPROC_SERVER,
+    COINIT_MULTITHREADED, EOAC_NONE, RPC_C_AUTHN_LEVEL_CALL, RPC_C_IMP_LEVEL_IMPERSONATE,
+};
+use windows::Win32::System::Wmi::{
+    IWbemLocator, WbemLocator, WBEM_FLAG_FORWARD_ONLY, WBEM_FLAG_RETURN_IMMEDIATELY, WBEM_INFINITE,
+};
+use windows::Win32::System::ProcessStatus::{GetProcessMemoryInfo, PROCESS_MEMORY_COUNTERS};
+use windows::Win32::System::SystemInformation::{GetLocalTime, IMAGE_FILE_MACHINE_UNKNOWN};
 use windows::Win32::System::Diagnostics::Debug::{
     ReadProcessMemory, WriteProcessMemory,
 };
@@ -10,20 +29,53 @@ use windows::Win32::System::Diagnostics::ToolHelp::{
     TH32CS_SNAPPROCESS,
 };
 use windows::Win32::System::Memory::{
-    VirtualQueryEx, MEMORY_BASIC_INFORMATION, MEM_COMMIT, PAGE_EXECUTE_READ,
-    PAGE_EXECUTE_READWRITE, PAGE_EXECUTE_WRITECOPY, PAGE_READONLY, PAGE_READWRITE,
-    PAGE_WRITECOPY,
+    VirtualAllocEx, VirtualFreeEx, VirtualProtectEx, VirtualQueryEx, MEMORY_BASIC_INFORMATION,
+    MEM_COMMIT, MEM_IMAGE, MEM_MAPPED, MEM_RELEASE, MEM_RESERVE, PAGE_EXECUTE_READ, PAGE_EXECUTE_READWRITE, PAGE_GUARD,
+    PAGE_EXECUTE_WRITECOPY, PAGE_PROTECTION_FLAGS, PAGE_READONLY, PAGE_READWRITE, PAGE_WRITECOPY,
 };
 use windows::Win32::System::Threading::{
-    OpenProcess, PROCESS_QUERY_INFORMATION, PROCESS_VM_OPERATION, PROCESS_VM_READ,
-    PROCESS_VM_WRITE,
+    CreateRemoteThread, GetCurrentProcess, GetExitCodeProcess, IsWow64Process, IsWow64Process2, OpenProcess, WaitForSingleObject,
+    INFINITE, PROCESS_QUERY_INFORMATION, PROCESS_QUERY_LIMITED_INFORMATION, PROCESS_VM_OPERATION,
+    PROCESS_VM_READ, PROCESS_VM_WRITE,
+};
+use windows::Win32::UI::WindowsAndMessaging::{
+    EnumWindows, GetWindowTextW, GetWindowThreadProcessId, IsWindowVisible,
 };
 
+/// Architecture of a process
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ProcessArch {
+    X86,
+    X64,
+    /// The process could not be opened to query its architecture
+    Unknown,
+}
+
+impl ProcessArch {
+    /// Short label for display (e.g. "[32-bit]")
+    pub fn label(&self) -> &'static str {
+        match self {
+            ProcessArch::X86 => "[32-bit]",
+            ProcessArch::X64 => "[64-bit]",
+            ProcessArch::Unknown => "[?]",
+        }
+    }
+}
+
 /// Information about a running process
 #[derive(Clone, Debug)]
 pub struct ProcessInfo {
     pub pid: u32,
     pub name: String,
+    pub arch: ProcessArch,
+    /// None if the process token could not be queried
+    pub integrity: Option<IntegrityLevel>,
+    /// Working set size in bytes (0 if the process could not be queried)
+    pub memory_usage: u64,
+    /// Title of the first visible window owned by the process
+    pub window_title: Option<String>,
+    /// Full command line, to tell apart processes with the same executable name
+    pub command_line: Option<String>,
 }
 
 /// Lists all running processes
@@ -34,6 +86,7 @@ pub fn list_processes() -> Result<Vec<ProcessInfo>> {
             return Err(anyhow!("Failed to create process snapshot"));
         }
 
+        let mut window_titles = collect_window_titles();
         let mut processes = Vec::new();
         let mut entry: PROCESSENTRY32W = mem::zeroed();
         entry.dwSize = mem::size_of::<PROCESSENTRY32W>() as u32;
@@ -48,10 +101,7 @@ pub fn list_processes() -> Result<Vec<ProcessInfo>> {
                         .unwrap_or(entry.szExeFile.len())],
                 );
 
-                processes.push(ProcessInfo {
-                    pid: entry.th32ProcessID,
-                    name,
-                });
+                processes.push(build_process_info(entry.th32ProcessID, name, &mut window_titles));
 
                 if Process32NextW(snapshot, &mut entry).is_err() {
                     break;
@@ -64,6 +114,351 @@ pub fn list_processes() -> Result<Vec<ProcessInfo>> {
     }
 }
 
+/// RPC_C_AUTHN_WINNT (NTLM) and RPC_C_AUTHZ_NONE for CoSetProxyBlanket
+const RPC_C_AUTHN_WINNT: u32 = 10;
+const RPC_C_AUTHZ_NONE: u32 = 0;
+
+/// Balances a successful CoInitializeEx on the current thread
+struct ComGuard;
+
+impl Drop for ComGuard {
+    fn drop(&mut self) {
+        unsafe { CoUninitialize() };
+    }
+}
+
+/// Lists all running processes through WMI (`Win32_Process`)
+///
+/// Much slower than the Toolhelp snapshot, so it is only used as a fallback
+/// when the snapshot cannot be created. Produces the same `ProcessInfo` as
+/// `list_processes`.
+pub fn list_processes_wmi() -> Result<Vec<ProcessInfo>> {
+    unsafe {
+        // S_FALSE (already initialized) also needs a matching CoUninitialize; a thread
+        // that is already in another apartment mode can still make the calls below
+        let _com = CoInitializeEx(None, COINIT_MULTITHREADED).is_ok().then_some(ComGuard);
+
+        let locator: IWbemLocator = CoCreateInstance(&WbemLocator, None, CLSCTX_INPROC_SERVER)
+            .map_err(|e| anyhow!("Failed to create WbemLocator: {}", e))?;
+        let services = locator
+            .ConnectServer(
+                &BSTR::from("ROOT\\CIMV2"),
+                &BSTR::new(),
+                &BSTR::new(),
+                &BSTR::new(),
+                0,
+                &BSTR::new(),
+                None,
+            )
+            .map_err(|e| anyhow!("Failed to connect to WMI: {}", e))?;
+        CoSetProxyBlanket(
+            &services,
+            RPC_C_AUTHN_WINNT,
+            RPC_C_AUTHZ_NONE,
+            None,
+            RPC_C_AUTHN_LEVEL_CALL,
+            RPC_C_IMP_LEVEL_IMPERSONATE,
+            None,
+            EOAC_NONE,
+        )?;
+
+        let rows = services
+            .ExecQuery(
+                &BSTR::from("WQL"),
+                &BSTR::from("SELECT ProcessId, Name FROM Win32_Process"),
+                WBEM_FLAG_FORWARD_ONLY | WBEM_FLAG_RETURN_IMMEDIATELY,
+                None,
+            )
+            .map_err(|e| anyhow!("WMI query failed: {}", e))?;
+
+        let mut window_titles = collect_window_titles();
+        let mut processes = Vec::new();
+
+        loop {
+            let mut row = [None];
+            let mut returned = 0u32;
+            rows.Next(WBEM_INFINITE, &mut row, &mut returned).ok()?;
+            let Some(object) = row[0].take().filter(|_| returned > 0) else {
+                break;
+            };
+
+            let mut pid = VARIANT::default();
+            let mut name = VARIANT::default();
+            object.Get(w!("ProcessId"), 0, &mut pid, None, None)?;
+            object.Get(w!("Name"), 0, &mut name, None, None)?;
+
+            let pid = u32::try_from(&pid)?;
+            let name = BSTR::try_from(&name).map(|s| s.to_string()).unwrap_or_default();
+            processes.push(build_process_info(pid, name, &mut window_titles));
+        }
+
+        Ok(processes)
+    }
+}
+
+/// Fills in the details of one process list entry
+fn build_process_info(pid: u32, name: String, window_titles: &mut HashMap<u32, String>) -> ProcessInfo {
+    let details = query_process_details(pid);
+    ProcessInfo {
+        pid,
+        name,
+        arch: details.arch,
+        integrity: details.integrity,
+        memory_usage: details.memory_usage,
+        window_title: window_titles.remove(&pid),
+        command_line: details.command_line,
+    }
+}
+
+/// Maps each PID to the title of its first visible, titled top-level window
+fn collect_window_titles() -> HashMap<u32, String> {
+    unsafe extern "system" fn callback(hwnd: HWND, lparam: LPARAM) -> BOOL {
+        let titles = &mut *(lparam.0 as *mut HashMap<u32, String>);
+
+        if !IsWindowVisible(hwnd).as_bool() {
+            return BOOL(1);
+        }
+
+        let mut pid = 0u32;
+        GetWindowThreadProcessId(hwnd, Some(&mut pid));
+        if pid == 0 || titles.contains_key(&pid) {
+            return BOOL(1);
+        }
+
+        let mut buffer = [0u16; 256];
+        let len = GetWindowTextW(hwnd, &mut buffer);
+        if len > 0 {
+            titles.insert(pid, String::from_utf16_lossy(&buffer[..len as usize]));
+        }
+
+        BOOL(1)
+    }
+
+    let mut titles: HashMap<u32, String> = HashMap::new();
+    unsafe {
+        if let Err(e) = EnumWindows(Some(callback), LPARAM(&mut titles as *mut _ as isize)) {
+            tracing::debug!("EnumWindows failed: {}", e);
+        }
+    }
+    titles
+}
+
+/// Details gathered for each entry of the process list
+struct ProcessDetails {
+    arch: ProcessArch,
+    integrity: Option<IntegrityLevel>,
+    memory_usage: u64,
+    command_line: Option<String>,
+}
+
+/// Queries architecture, integrity level, memory usage and command line of a process by PID without keeping it open
+fn query_process_details(pid: u32) -> ProcessDetails {
+    unsafe {
+        // GetProcessMemoryInfo also needs PROCESS_VM_READ; retry without it for protected processes
+        let handle = OpenProcess(PROCESS_QUERY_LIMITED_INFORMATION | PROCESS_VM_READ, false, pid)
+            .or_else(|_| OpenProcess(PROCESS_QUERY_LIMITED_INFORMATION, false, pid));
+        let Ok(handle) = handle else {
+            return ProcessDetails {
+                arch: ProcessArch::Unknown,
+                integrity: None,
+                memory_usage: 0,
+                command_line: None,
+            };
+        };
+
+        let arch = match is_process_64bit(handle) {
+            Ok(true) => ProcessArch::X64,
+            Ok(false) => ProcessArch::X86,
+            Err(_) => ProcessArch::Unknown,
+        };
+        let integrity = get_process_integrity_level(handle).ok();
+
+        let mut counters = PROCESS_MEMORY_COUNTERS {
+            cb: mem::size_of::<PROCESS_MEMORY_COUNTERS>() as u32,
+            ..Default::default()
+        };
+        let memory_usage = match GetProcessMemoryInfo(handle, &mut counters, counters.cb) {
+            Ok(()) => counters.WorkingSetSize as u64,
+            Err(_) => 0,
+        };
+        let command_line = get_process_command_line(handle).ok();
+
+        let _ = CloseHandle(handle);
+        ProcessDetails {
+            arch,
+            integrity,
+            memory_usage,
+            command_line,
+        }
+    }
+}
+
+/// Returns true if the target process is a native 64-bit process
+///
+/// Uses IsWow64Process2 (Windows 10+) and falls back to IsWow64Process.
+/// A process running under WOW64 is a 32-bit process.
+pub fn is_process_64bit(handle: HANDLE) -> Result<bool> {
+    unsafe {
+        let mut process_machine = IMAGE_FILE_MACHINE_UNKNOWN;
+        if IsWow64Process2(handle, &mut process_machine, None).is_ok() {
+            // IMAGE_FILE_MACHINE_UNKNOWN means the process is not running under WOW64
+            return Ok(process_machine == IMAGE_FILE_MACHINE_UNKNOWN);
+        }
+
+        let mut is_wow64 = BOOL(0);
+        IsWow64Process(handle, &mut is_wow64)
+            .map_err(|e| anyhow!("Failed to query process architecture: {}", e))?;
+        Ok(!is_wow64.as_bool())
+    }
+}
+
+/// PROCESS_BASIC_INFORMATION as returned by NtQueryInformationProcess (x64 layout)
+#[repr(C)]
+#[derive(Default)]
+struct ProcessBasicInfo {
+    exit_status: i32,
+    peb_base_address: usize,
+    affinity_mask: usize,
+    base_priority: i32,
+    unique_process_id: usize,
+    inherited_from_unique_process_id: usize,
+}
+
+/// Offset of PEB::ImageBaseAddress on x64
+const PEB_IMAGE_BASE_OFFSET: usize = 0x10;
+
+/// Returns the main image base address of a process by reading its PEB
+///
+/// Only needs PROCESS_QUERY_LIMITED_INFORMATION | PROCESS_VM_READ, so it works
+/// for processes where module snapshots fail (e.g. protected processes).
+pub fn query_process_base_address(handle: HANDLE) -> Result<usize> {
+    let mut info = ProcessBasicInfo::default();
+    let mut return_length = 0u32;
+
+    unsafe {
+        NtQueryInformationProcess(
+            handle,
+            ProcessBasicInformation,
+            &mut info as *mut _ as *mut c_void,
+            mem::size_of::<ProcessBasicInfo>() as u32,
+            &mut return_length,
+        )
+        .ok()
+        .map_err(|e| anyhow!("NtQueryInformationProcess failed: {}", e))?;
+    }
+
+    if info.peb_base_address == 0 {
+        return Err(anyhow!("Process has no PEB"));
+    }
+
+    let data = read_process_memory(handle, info.peb_base_address + PEB_IMAGE_BASE_OFFSET, 8)?;
+    let base = usize::from_le_bytes(data[..8].try_into().unwrap());
+    if base == 0 {
+        return Err(anyhow!("PEB image base address is null"));
+    }
+
+    Ok(base)
+}
+
+/// Offset of PEB::ProcessParameters on x64
+const PEB_PROCESS_PARAMETERS_OFFSET: usize = 0x20;
+
+/// Offset of RTL_USER_PROCESS_PARAMETERS::CommandLine (a UNICODE_STRING) on x64
+const PROCESS_PARAMETERS_COMMAND_LINE_OFFSET: usize = 0x70;
+
+/// Returns the command line the process was started with
+///
+/// Uses ProcessCommandLineInformation (Windows 8.1+), which only needs
+/// PROCESS_QUERY_LIMITED_INFORMATION. Falls back to reading
+/// RTL_USER_PROCESS_PARAMETERS through the PEB, which also needs PROCESS_VM_READ.
+pub fn get_process_command_line(handle: HANDLE) -> Result<String> {
+    query_command_line_information(handle).or_else(|_| read_command_line_from_peb(handle))
+}
+
+/// Queries the command line with NtQueryInformationProcess(ProcessCommandLineInformation)
+fn query_command_line_information(handle: HANDLE) -> Result<String> {
+    unsafe {
+        // The first call fails with STATUS_INFO_LENGTH_MISMATCH and reports the needed size
+        let mut return_length = 0u32;
+        let _ = NtQueryInformationProcess(
+            handle,
+            ProcessCommandLineInformation,
+            std::ptr::null_mut(),
+            0,
+            &mut return_length,
+        );
+        if return_length == 0 {
+            return Err(anyhow!("ProcessCommandLineInformation is not supported"));
+        }
+
+        // u64 elements keep the UNICODE_STRING header aligned
+        let mut buffer = vec![0u64; (return_length as usize).div_ceil(8)];
+        NtQueryInformationProcess(
+            handle,
+            ProcessCommandLineInformation,
+            buffer.as_mut_ptr() as *mut c_void,
+            (buffer.len() * 8) as u32,
+            &mut return_length,
+        )
+        .ok()
+        .map_err(|e| anyhow!("NtQueryInformationProcess failed: {}", e))?;
+
+        // UNICODE_STRING { Length: u16, MaximumLength: u16, Buffer: *const u16 }; the
+        // string data is stored in the same buffer right after the header
+        let length = (buffer[0] & 0xFFFF) as usize / 2;
+        let data = buffer[1] as *const u16;
+        let start = buffer.as_ptr() as *const u16;
+        let end = start.add(buffer.len() * 4);
+        if length == 0 || data < start || data.add(length) > end {
+            return Err(anyhow!("Invalid command line buffer"));
+        }
+
+        Ok(String::from_utf16_lossy(std::slice::from_raw_parts(data, length)))
+    }
+}
+
+/// Reads RTL_USER_PROCESS_PARAMETERS::CommandLine from the target's memory
+fn read_command_line_from_peb(handle: HANDLE) -> Result<String> {
+    let mut info = ProcessBasicInfo::default();
+    let mut return_length = 0u32;
+
+    unsafe {
+        NtQueryInformationProcess(
+            handle,
+            ProcessBasicInformation,
+            &mut info as *mut _ as *mut c_void,
+            mem::size_of::<ProcessBasicInfo>() as u32,
+            &mut return_length,
+        )
+        .ok()
+        .map_err(|e| anyhow!("NtQueryInformationProcess failed: {}", e))?;
+    }
+
+    if info.peb_base_address == 0 {
+        return Err(anyhow!("Process has no PEB"));
+    }
+
+    let data = read_process_memory(handle, info.peb_base_address + PEB_PROCESS_PARAMETERS_OFFSET, 8)?;
+    let parameters = usize::from_le_bytes(data[..8].try_into().unwrap());
+    if parameters == 0 {
+        return Err(anyhow!("PEB process parameters are null"));
+    }
+
+    let header = read_process_memory(handle, parameters + PROCESS_PARAMETERS_COMMAND_LINE_OFFSET, 16)?;
+    let length = u16::from_le_bytes([header[0], header[1]]) as usize;
+    let buffer = usize::from_le_bytes(header[8..16].try_into().unwrap());
+    if length == 0 || buffer == 0 {
+        return Err(anyhow!("Process has no command line"));
+    }
+
+    let bytes = read_process_memory(handle, buffer, length)?;
+    let wide: Vec<u16> = bytes
+        .chunks_exact(2)
+        .map(|c| u16::from_le_bytes([c[0], c[1]]))
+        .collect();
+    Ok(String::from_utf16_lossy(&wide))
+}
+
 /// Opens a process with necessary permissions for memory scanning
 pub fn open_process(pid: u32) -> Result<HANDLE> {
     unsafe {
@@ -81,6 +476,30 @@ pub fn open_process(pid: u32) -> Result<HANDLE> {
     }
 }
 
+/// Whether the process behind `handle` has not exited yet
+///
+/// The handle stays valid after the process exits, so this is how a crashed
+/// target is noticed. Needs PROCESS_QUERY_(LIMITED_)INFORMATION.
+pub fn is_process_running(handle: HANDLE) -> bool {
+    let mut exit_code = 0u32;
+    unsafe { GetExitCodeProcess(handle, &mut exit_code) }.is_ok() && exit_code == STILL_ACTIVE.0 as u32
+}
+
+/// Duplicates a process handle with the same access rights
+///
+/// The copy refers to the same process object, so it cannot end up pointing
+/// at a different process even if the PID is reused, and must be closed on
+/// its own with `close_process`.
+pub fn duplicate_handle(handle: HANDLE) -> Result<HANDLE> {
+    let mut duplicate = HANDLE::default();
+    unsafe {
+        let current = GetCurrentProcess();
+        DuplicateHandle(current, handle, current, &mut duplicate, 0, false, DUPLICATE_SAME_ACCESS)
+            .map_err(|e| anyhow!("Failed to duplicate process handle: {}", e))?;
+    }
+    Ok(duplicate)
+}
+
 /// Closes a process handle
 pub fn close_process(handle: HANDLE) -> Result<()> {
     unsafe {
@@ -89,6 +508,35 @@ pub fn close_process(handle: HANDLE) -> Result<()> {
     }
 }
 
+/// Lowest address of the typical user-mode heap range
+pub const HEAP_RANGE_START: usize = 0x0001_0000;
+
+/// Highest address of the typical user-mode heap range (x64 user space end)
+pub const HEAP_RANGE_END: usize = 0x7FFF_FFFF_FFFF;
+
+/// Kind of pages in a region (`MEMORY_BASIC_INFORMATION::Type`)
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RegionType {
+    /// MEM_PRIVATE: heap, stack and other private allocations
+    Private,
+    /// MEM_IMAGE: mapped executable images (module code and data)
+    Image,
+    /// MEM_MAPPED: file-backed or shared section mappings
+    Mapped,
+}
+
+impl RegionType {
+    fn from_mem_type(mem_type: u32) -> Self {
+        if mem_type == MEM_IMAGE.0 {
+            RegionType::Image
+        } else if mem_type == MEM_MAPPED.0 {
+            RegionType::Mapped
+        } else {
+            RegionType::Private
+        }
+    }
+}
+
 /// Information about a memory region
 #[derive(Clone, Debug)]
 pub struct MemoryRegion {
@@ -96,12 +544,40 @@ pub struct MemoryRegion {
     pub size: usize,
     pub protection: u32,
     pub state: u32,
+    pub region_type: RegionType,
     pub is_readable: bool,
     pub is_writable: bool,
     pub is_executable: bool,
+    /// PAGE_GUARD is set; touching the region raises a guard page exception in the target
+    pub is_guard: bool,
 }
 
 impl MemoryRegion {
+    /// Private allocations (heap, stack)
+    pub fn is_private(&self) -> bool {
+        self.region_type == RegionType::Private
+    }
+
+    /// Mapped executable images (module code and data)
+    pub fn is_image(&self) -> bool {
+        self.region_type == RegionType::Image
+    }
+
+    /// File-backed or shared section mappings
+    pub fn is_mapped(&self) -> bool {
+        self.region_type == RegionType::Mapped
+    }
+
+    /// Private read/write memory inside the typical heap range
+    ///
+    /// Game state (health, currency, ...) is almost always allocated here.
+    pub fn is_heap_like(&self) -> bool {
+        self.is_private()
+            && self.protection == PAGE_READWRITE.0
+            && self.base_address >= HEAP_RANGE_START
+            && self.base_address + self.size <= HEAP_RANGE_END
+    }
+
     /// Creates MemoryRegion from MEMORY_BASIC_INFORMATION
     fn from_mbi(mbi: &MEMORY_BASIC_INFORMATION) -> Self {
         let protection = mbi.Protect.0;
@@ -122,15 +598,18 @@ impl MemoryRegion {
             mbi.Protect,
             PAGE_EXECUTE_READ | PAGE_EXECUTE_READWRITE | PAGE_EXECUTE_WRITECOPY
         );
+        let is_guard = mbi.Protect.contains(PAGE_GUARD);
 
         Self {
             base_address: mbi.BaseAddress as usize,
             size: mbi.RegionSize,
             protection,
             state: mbi.State.0,
+            region_type: RegionType::from_mem_type(mbi.Type.0),
             is_readable,
             is_writable,
             is_executable,
+            is_guard,
         }
     }
 }
@@ -197,6 +676,59 @@ pub fn read_process_memory(handle: HANDLE, address: usize, size: usize) -> Resul
     }
 }
 
+/// Chunk size used by `read_until_null`
+const NULL_SCAN_CHUNK: usize = 256;
+
+/// Page size used to keep `read_until_null` chunks from crossing into the next page
+const PAGE_SIZE: usize = 0x1000;
+
+/// Reads characters of `char_size` bytes (1 for narrow, 2 for wide strings)
+/// until an all-zero character, reading at most `max_len` bytes
+///
+/// Returns the bytes before the terminator, or the first `max_len` bytes
+/// (rounded down to whole characters) when no terminator is found. Chunks
+/// never span a page boundary, so a string ending just before an unreadable
+/// page still succeeds.
+pub fn read_until_null(handle: HANDLE, address: usize, max_len: usize, char_size: usize) -> Result<Vec<u8>> {
+    let mut bytes = Vec::new();
+    // Bytes already checked for a terminator, always a multiple of char_size
+    let mut scanned = 0;
+
+    while bytes.len() < max_len {
+        let current = address + bytes.len();
+        let size = NULL_SCAN_CHUNK
+            .min(PAGE_SIZE - current % PAGE_SIZE)
+            .min(max_len - bytes.len());
+        bytes.extend(read_process_memory(handle, current, size)?);
+
+        while scanned + char_size <= bytes.len() {
+            if bytes[scanned..scanned + char_size].iter().all(|&b| b == 0) {
+                bytes.truncate(scanned);
+                return Ok(bytes);
+            }
+            scanned += char_size;
+        }
+    }
+
+    bytes.truncate(scanned);
+    Ok(bytes)
+}
+
+/// Decoding of game fields that anti-cheat systems keep obfuscated in memory
+pub struct GuardedValue;
+
+impl GuardedValue {
+    /// Reads `size` bytes at `address` and at `address + key_offset` and XORs them
+    ///
+    /// For values stored as `value ^ key` with the key at a fixed offset from
+    /// the stored value.
+    pub fn decode_xor(handle: HANDLE, address: usize, key_offset: i32, size: usize) -> Result<Vec<u8>> {
+        let stored = read_process_memory(handle, address, size)?;
+        let key = read_process_memory(handle, address.wrapping_add_signed(key_offset as isize), size)?;
+        Ok(stored.iter().zip(&key).map(|(s, k)| s ^ k).collect())
+    }
+}
+
 /// Writes memory to a process
 pub fn write_process_memory(handle: HANDLE, address: usize, data: &[u8]) -> Result<()> {
     unsafe {
@@ -210,7 +742,11 @@ pub fn write_process_memory(handle: HANDLE, address: usize, data: &[u8]) -> Resu
             Some(&mut bytes_written),
         );
 
-        if success.is_err() || bytes_written != data.len() {
+        // Keep the underlying Win32 error so callers can inspect the error code
+        if let Err(e) = success {
+            return Err(anyhow::Error::new(e).context(format!("Failed to write memory at 0x{:X}", address)));
+        }
+        if bytes_written != data.len() {
             return Err(anyhow!("Failed to write memory at 0x{:X}", address));
         }
 
@@ -218,10 +754,291 @@ pub fn write_process_memory(handle: HANDLE, address: usize, data: &[u8]) -> Resu
     }
 }
 
+/// Returns true if the error was caused by a Win32 access violation
+/// (ERROR_ACCESS_DENIED or ERROR_NOACCESS)
+pub fn is_access_denied(error: &anyhow::Error) -> bool {
+    error
+        .downcast_ref::<windows::core::Error>()
+        .map(|e| e.code() == ERROR_ACCESS_DENIED.to_hresult() || e.code() == ERROR_NOACCESS.to_hresult())
+        .unwrap_or(false)
+}
+
+/// Writes memory after temporarily making the pages writable
+///
+/// Changes the protection to PAGE_EXECUTE_READWRITE, writes the bytes and
+/// restores the original protection (even if the write fails).
+pub fn write_with_protection_change(handle: HANDLE, address: usize, data: &[u8]) -> Result<()> {
+    unsafe {
+        let mut old_protection = PAGE_PROTECTION_FLAGS(0);
+
+        VirtualProtectEx(
+            handle,
+            address as *const _,
+            data.len(),
+            PAGE_EXECUTE_READWRITE,
+            &mut old_protection,
+        )
+        .map_err(|e| anyhow!("Failed to change protection at 0x{:X}: {}", address, e))?;
+
+        let result = write_process_memory(handle, address, data);
+
+        let mut restored = PAGE_PROTECTION_FLAGS(0);
+        if let Err(e) = VirtualProtectEx(handle, address as *const _, data.len(), old_protection, &mut restored) {
+            tracing::warn!("Failed to restore protection at 0x{:X}: {}", address, e);
+        }
+
+        result
+    }
+}
+
+/// Returns the current local wall-clock time as seconds since midnight
+pub fn local_seconds_of_day() -> u32 {
+    let time = unsafe { GetLocalTime() };
+    time.wHour as u32 * 3600 + time.wMinute as u32 * 60 + time.wSecond as u32
+}
+
+/// Calls a function in the target process on a new remote thread
+///
+/// Uses the x64 calling convention: up to four integer/pointer arguments are
+/// passed in RCX, RDX, R8 and R9. Returns the value left in RAX.
+pub fn call_remote_function(handle: HANDLE, function: usize, args: &[usize]) -> Result<usize> {
+    call_remote_functions(handle, &[(function, args)])
+}
+
+/// Calls several functions in order on one new remote thread
+///
+/// For runtimes that need per-thread setup first, e.g. attaching the thread
+/// to a managed domain. Returns the value the last function left in RAX.
+pub fn call_remote_functions(handle: HANDLE, calls: &[(usize, &[usize])]) -> Result<usize> {
+    let (stub, thread) = spawn_remote_calls(handle, calls)?;
+    thread.wait();
+    read_remote_call_result(handle, &stub)
+}
+
+/// Like `call_remote_functions`, but waits at most `timeout` for the remote thread
+///
+/// Returns `Ok(None)` if the thread is still running when the timeout
+/// elapses. The stub is then left allocated in the target, because the
+/// thread may still be executing it.
+pub fn call_remote_functions_timeout(
+    handle: HANDLE,
+    calls: &[(usize, &[usize])],
+    timeout: std::time::Duration,
+) -> Result<Option<usize>> {
+    let (stub, thread) = spawn_remote_calls(handle, calls)?;
+    if !thread.wait_timeout(timeout) {
+        tracing::warn!(
+            "Remote call did not finish within {:?}; leaking {} bytes in the target",
+            timeout,
+            stub.size()
+        );
+        std::mem::forget(stub);
+        return Ok(None);
+    }
+    read_remote_call_result(handle, &stub).map(Some)
+}
+
+/// Writes a stub that makes `calls` in order and starts it on a remote thread
+fn spawn_remote_calls(handle: HANDLE, calls: &[(usize, &[usize])]) -> Result<(RemoteAlloc, RemoteThread)> {
+    if let Some((_, args)) = calls.iter().find(|(_, args)| args.len() > 4) {
+        return Err(anyhow!(
+            "Remote calls support at most 4 arguments (got {})",
+            args.len()
+        ));
+    }
+
+    // Layout: [0..8] return value slot, [16..] shellcode
+    const CODE_OFFSET: usize = 16;
+    const CALL_SIZE: usize = 4 * 10 + 12;
+    const ARG_REGISTERS: [[u8; 2]; 4] = [
+        [0x48, 0xB9], // mov rcx, imm64
+        [0x48, 0xBA], // mov rdx, imm64
+        [0x49, 0xB8], // mov r8, imm64
+        [0x49, 0xB9], // mov r9, imm64
+    ];
+
+    let stub = RemoteAlloc::new(handle, 0x100 + calls.len() * CALL_SIZE, PAGE_EXECUTE_READWRITE)?;
+    let base_addr = stub.address();
+
+    let mut code = Vec::with_capacity(0x60);
+    // sub rsp, 0x28 (shadow space)
+    code.extend_from_slice(&[0x48, 0x83, 0xEC, 0x28]);
+    for (function, args) in calls {
+        for (register, arg) in ARG_REGISTERS.iter().zip(args.iter()) {
+            code.extend_from_slice(register);
+            code.extend_from_slice(&arg.to_le_bytes());
+        }
+        // mov rax, function; call rax
+        code.extend_from_slice(&[0x48, 0xB8]);
+        code.extend_from_slice(&function.to_le_bytes());
+        code.extend_from_slice(&[0xFF, 0xD0]);
+    }
+    // mov rcx, result_slot; mov [rcx], rax
+    code.extend_from_slice(&[0x48, 0xB9]);
+    code.extend_from_slice(&base_addr.to_le_bytes());
+    code.extend_from_slice(&[0x48, 0x89, 0x01]);
+    // add rsp, 0x28; ret
+    code.extend_from_slice(&[0x48, 0x83, 0xC4, 0x28, 0xC3]);
+
+    write_process_memory(handle, base_addr + CODE_OFFSET, &code)?;
+
+    // Safety: the stub was just written to executable memory in the target
+    let thread = unsafe { RemoteThread::spawn(handle, base_addr + CODE_OFFSET)? };
+    Ok((stub, thread))
+}
+
+/// Reads the RAX value the stub stored in its return value slot
+fn read_remote_call_result(handle: HANDLE, stub: &RemoteAlloc) -> Result<usize> {
+    let data = read_process_memory(handle, stub.address(), 8)?;
+    Ok(usize::from_le_bytes(data[..8].try_into().unwrap()))
+}
+
+/// Memory allocated in a target process with VirtualAllocEx
+///
+/// The allocation is released with VirtualFreeEx when the guard is dropped,
+/// so early returns cannot leak it.
+pub struct RemoteAlloc {
+    ptr: *mut c_void,
+    process: HANDLE,
+    size: usize,
+}
+
+impl RemoteAlloc {
+    /// Reserves and commits `size` bytes in the target process
+    pub fn new(process: HANDLE, size: usize, protection: PAGE_PROTECTION_FLAGS) -> Result<Self> {
+        let ptr = unsafe { VirtualAllocEx(process, None, size, MEM_COMMIT | MEM_RESERVE, protection) };
+        if ptr.is_null() {
+            return Err(anyhow!("Failed to allocate {} bytes in target process", size));
+        }
+        Ok(Self { ptr, process, size })
+    }
+
+    /// Allocates memory and copies `data` into it
+    pub fn with_data(process: HANDLE, data: &[u8], protection: PAGE_PROTECTION_FLAGS) -> Result<Self> {
+        let alloc = Self::new(process, data.len().max(1), protection)?;
+        if !data.is_empty() {
+            write_process_memory(process, alloc.address(), data)?;
+        }
+        Ok(alloc)
+    }
+
+    /// Base address of the allocation in the target process
+    pub fn address(&self) -> usize {
+        self.ptr as usize
+    }
+
+    /// Requested size of the allocation in bytes
+    pub fn size(&self) -> usize {
+        self.size
+    }
+}
+
+impl Drop for RemoteAlloc {
+    fn drop(&mut self) {
+        unsafe {
+            if let Err(e) = VirtualFreeEx(self.process, self.ptr, 0, MEM_RELEASE) {
+                tracing::warn!("Failed to free remote allocation at 0x{:X}: {}", self.address(), e);
+            }
+        }
+    }
+}
+
+/// Thread created in a target process with CreateRemoteThread
+///
+/// The thread handle is closed when the guard is dropped. Dropping does not
+/// wait for or terminate the thread.
+pub struct RemoteThread {
+    handle: HANDLE,
+    process: HANDLE,
+}
+
+impl RemoteThread {
+    /// Starts a thread at `start_address` in the target process
+    ///
+    /// # Safety
+    /// `start_address` must point to executable code in the target process
+    /// that is valid as a thread entry point.
+    pub unsafe fn spawn(process: HANDLE, start_address: usize) -> Result<Self> {
+        let handle = CreateRemoteThread(
+            process,
+            None,
+            0,
+            Some(mem::transmute::<usize, unsafe extern "system" fn(*mut c_void) -> u32>(
+                start_address,
+            )),
+            None,
+            0,
+            None,
+        )
+        .map_err(|e| anyhow!("Failed to create remote thread: {}", e))?;
+
+        Ok(Self { handle, process })
+    }
+
+    /// Blocks until the remote thread exits
+    pub fn wait(&self) {
+        unsafe {
+            WaitForSingleObject(self.handle, INFINITE);
+        }
+    }
+
+    /// Waits up to `timeout` for the remote thread to exit
+    ///
+    /// Returns false if the thread is still running when the timeout elapses.
+    pub fn wait_timeout(&self, timeout: std::time::Duration) -> bool {
+        let millis = u32::try_from(timeout.as_millis()).unwrap_or(INFINITE - 1);
+        unsafe { WaitForSingleObject(self.handle, millis) == WAIT_OBJECT_0 }
+    }
+
+    /// Raw thread handle
+    pub fn handle(&self) -> HANDLE {
+        self.handle
+    }
+
+    /// Process the thread was created in
+    pub fn process(&self) -> HANDLE {
+        self.process
+    }
+}
+
+impl Drop for RemoteThread {
+    fn drop(&mut self) {
+        unsafe {
+            let _ = CloseHandle(self.handle);
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_local_seconds_of_day() {
+        assert!(local_seconds_of_day() < 24 * 3600);
+    }
+
+    #[test]
+    fn test_is_heap_like() {
+        let region = |base_address: usize, protection: u32, region_type: RegionType| MemoryRegion {
+            base_address,
+            size: 0x1000,
+            protection,
+            state: 0,
+            region_type,
+            is_readable: true,
+            is_writable: true,
+            is_executable: false,
+            is_guard: false,
+        };
+
+        assert!(region(0x20000, PAGE_READWRITE.0, RegionType::Private).is_heap_like());
+        assert!(!region(0x20000, PAGE_READWRITE.0, RegionType::Image).is_heap_like());
+        assert!(!region(0x20000, PAGE_READONLY.0, RegionType::Private).is_heap_like());
+        assert!(!region(0x1000, PAGE_READWRITE.0, RegionType::Private).is_heap_like());
+        assert!(!region(HEAP_RANGE_END, PAGE_READWRITE.0, RegionType::Private).is_heap_like());
+    }
+
     #[test]
     fn test_list_processes() {
         let processes = list_processes().expect("Failed to list processes");
@@ -232,4 +1049,98 @@ mod tests {
         let found = processes.iter().any(|p| p.pid == current_pid);
         assert!(found, "Current process should be in the list");
     }
+
+    #[test]
+    fn test_list_processes_wmi() {
+        let processes = list_processes_wmi().expect("Failed to list processes through WMI");
+
+        let current_pid = std::process::id();
+        let current = processes
+            .iter()
+            .find(|p| p.pid == current_pid)
+            .expect("Current process should be in the list");
+        assert!(current.name.to_lowercase().ends_with(".exe"));
+    }
+
+    #[test]
+    fn test_guarded_value_decode_xor() {
+        use windows::Win32::System::Threading::GetCurrentProcess;
+
+        let key = 0x5A5A_1234u32;
+        let fields = [0x10u32 ^ key, 0, key];
+        let address = fields.as_ptr() as usize;
+
+        let handle = unsafe { GetCurrentProcess() };
+        let decoded = GuardedValue::decode_xor(handle, address, 8, 4).expect("Failed to decode");
+        assert_eq!(decoded, 0x10u32.to_le_bytes());
+
+        // The key may also sit before the stored value
+        let decoded = GuardedValue::decode_xor(handle, address + 8, -8, 4).expect("Failed to decode");
+        assert_eq!(decoded, 0x10u32.to_le_bytes());
+    }
+
+    #[test]
+    fn test_write_with_protection_change() {
+        use windows::Win32::System::Threading::GetCurrentProcess;
+
+        unsafe {
+            let handle = GetCurrentProcess();
+            let page = VirtualAllocEx(handle, None, 0x1000, MEM_COMMIT | MEM_RESERVE, PAGE_READONLY);
+            assert!(!page.is_null(), "Failed to allocate read-only page");
+
+            write_with_protection_change(handle, page as usize, &[1, 2, 3, 4])
+                .expect("Write with protection change should succeed");
+            let data = read_process_memory(handle, page as usize, 4).expect("Failed to read back");
+            assert_eq!(data, vec![1, 2, 3, 4]);
+
+            let _ = VirtualFreeEx(handle, page, 0, MEM_RELEASE);
+        }
+    }
+
+    #[test]
+    fn test_query_process_base_address() {
+        use windows::Win32::System::Threading::GetCurrentProcess;
+
+        let base = query_process_base_address(unsafe { GetCurrentProcess() })
+            .expect("Failed to query base address");
+        let main_module = crate::platform::module::list_modules(std::process::id())
+            .expect("Failed to list modules")
+            .into_iter()
+            .next()
+            .expect("No modules found");
+        assert_eq!(base, main_module.base_address);
+    }
+
+    #[test]
+    fn test_get_process_command_line() {
+        use windows::Win32::System::Threading::GetCurrentProcess;
+
+        let handle = unsafe { GetCurrentProcess() };
+        let command_line = get_process_command_line(handle).expect("Failed to query command line");
+        assert!(!command_line.is_empty());
+
+        // Both sources describe the same string
+        let from_peb = read_command_line_from_peb(handle).expect("Failed to read command line from PEB");
+        assert_eq!(command_line, from_peb);
+    }
+
+    #[test]
+    fn test_is_process_running() {
+        use windows::Win32::System::Threading::GetCurrentProcess;
+
+        assert!(is_process_running(unsafe { GetCurrentProcess() }));
+    }
+
+    #[test]
+    fn test_remote_alloc_roundtrip() {
+        use windows::Win32::System::Threading::GetCurrentProcess;
+
+        let handle = unsafe { GetCurrentProcess() };
+        let alloc = RemoteAlloc::with_data(handle, &[9, 8, 7, 6], PAGE_READWRITE)
+            .expect("Failed to allocate");
+        assert_eq!(alloc.size(), 4);
+
+        let data = read_process_memory(handle, alloc.address(), 4).expect("Failed to read back");
+        assert_eq!(data, vec![9, 8, 7, 6]);
+    }
 }
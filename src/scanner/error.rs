@@ -0,0 +1,11 @@
+//! Scanner error types
+
+use std::time::Duration;
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum ScanError {
+    /// The scan ran past `ScanOptions::timeout`; the matches found before it are kept
+    #[error("Scan timed out after {}s — game may be frozen", .elapsed.as_secs())]
+    Timeout { elapsed: Duration, results_found: usize },
+}
@@ -39,9 +39,53 @@ impl FilterEngine {
                     false
                 }
             }
+            ScanType::IncreasedByPercentage(pct) => previous
+                .map(|prev| Self::percent_change(current.as_f64(), prev.as_f64()) >= pct)
+                .unwrap_or(false),
+            ScanType::DecreasedByPercentage(pct) => previous
+                .map(|prev| -Self::percent_change(current.as_f64(), prev.as_f64()) >= pct)
+                .unwrap_or(false),
+            ScanType::IncreasedByExactly(amount) => previous
+                .map(|prev| Self::changed_by(current, prev, amount))
+                .unwrap_or(false),
+            ScanType::DecreasedByExactly(amount) => previous
+                .map(|prev| Self::changed_by(current, prev, -amount))
+                .unwrap_or(false),
             ScanType::Unknown => true,
         }
     }
+
+    /// Checks whether current - previous equals delta
+    ///
+    /// Floats are compared with a small relative tolerance since the
+    /// difference of two stored values rarely matches the input exactly.
+    fn changed_by(current: &ScanValue, previous: &ScanValue, delta: f64) -> bool {
+        let actual = current.as_f64() - previous.as_f64();
+        match current {
+            ScanValue::F32(_) | ScanValue::F64(_) => {
+                (actual - delta).abs() <= f32::EPSILON as f64 * delta.abs().max(1.0)
+            }
+            _ => actual == delta,
+        }
+    }
+
+    /// Relative change from previous to current in percent
+    ///
+    /// A change away from zero is treated as infinitely large, so any
+    /// increase (or decrease) from 0 matches the percentage filters.
+    fn percent_change(current: f64, previous: f64) -> f64 {
+        if previous == 0.0 {
+            if current > 0.0 {
+                f64::INFINITY
+            } else if current < 0.0 {
+                f64::NEG_INFINITY
+            } else {
+                0.0
+            }
+        } else {
+            (current - previous) / previous.abs() * 100.0
+        }
+    }
 }
 
 #[cfg(test)]
@@ -74,6 +118,36 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn test_changed_by_exactly() {
+        let previous = ScanValue::I32(100);
+
+        assert!(FilterEngine::matches(
+            &ScanValue::I32(125),
+            Some(&previous),
+            None,
+            ScanType::IncreasedByExactly(25.0)
+        ));
+        assert!(!FilterEngine::matches(
+            &ScanValue::I32(126),
+            Some(&previous),
+            None,
+            ScanType::IncreasedByExactly(25.0)
+        ));
+        assert!(FilterEngine::matches(
+            &ScanValue::I32(90),
+            Some(&previous),
+            None,
+            ScanType::DecreasedByExactly(10.0)
+        ));
+        assert!(FilterEngine::matches(
+            &ScanValue::F32(1.3),
+            Some(&ScanValue::F32(1.2)),
+            None,
+            ScanType::IncreasedByExactly(0.1)
+        ));
+    }
+
     #[test]
     fn test_decreased() {
         let current = ScanValue::I32(50);
@@ -86,4 +160,44 @@ mod tests {
             ScanType::Decreased
         ));
     }
+
+    #[test]
+    fn test_changed_by_percentage() {
+        let previous = ScanValue::F32(100.0);
+
+        assert!(FilterEngine::matches(
+            &ScanValue::F32(111.0),
+            Some(&previous),
+            None,
+            ScanType::IncreasedByPercentage(10.0)
+        ));
+        assert!(!FilterEngine::matches(
+            &ScanValue::F32(105.0),
+            Some(&previous),
+            None,
+            ScanType::IncreasedByPercentage(10.0)
+        ));
+        assert!(FilterEngine::matches(
+            &ScanValue::F32(80.0),
+            Some(&previous),
+            None,
+            ScanType::DecreasedByPercentage(10.0)
+        ));
+
+        // Negative previous values use the magnitude as the base
+        assert!(FilterEngine::matches(
+            &ScanValue::I32(-90),
+            Some(&ScanValue::I32(-100)),
+            None,
+            ScanType::IncreasedByPercentage(10.0)
+        ));
+
+        // Any increase from zero counts
+        assert!(FilterEngine::matches(
+            &ScanValue::I32(1),
+            Some(&ScanValue::I32(0)),
+            None,
+            ScanType::IncreasedByPercentage(50.0)
+        ));
+    }
 }
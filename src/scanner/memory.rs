@@ -1,5 +1,6 @@
 use crate::platform::{self, MemoryRegion};
 use crate::scanner::Process;
+use crate::types::RegionTypeFlags;
 use anyhow::Result;
 
 /// Chunk size for reading memory (1 MB)
@@ -27,13 +28,40 @@ impl<'a> MemoryScanner<'a> {
         readable_only: bool,
         writable_only: bool,
         executable_only: bool,
+        region_types: RegionTypeFlags,
     ) -> Vec<MemoryRegion> {
         regions
             .into_iter()
             .filter(|region| {
-                (!readable_only || region.is_readable)
+                // Guard pages (stack growth, anti-tamper traps) are never scanned
+                !region.is_guard
+                    && (!readable_only || region.is_readable)
                     && (!writable_only || region.is_writable)
                     && (!executable_only || region.is_executable)
+                    && ((region_types.contains(RegionTypeFlags::PRIVATE) && region.is_private())
+                        || (region_types.contains(RegionTypeFlags::IMAGE) && region.is_image())
+                        || (region_types.contains(RegionTypeFlags::MAPPED) && region.is_mapped()))
+            })
+            .collect()
+    }
+
+    /// Clips regions to the given address range [start, end)
+    ///
+    /// Regions entirely outside the range are dropped.
+    pub fn clip_regions(&self, regions: Vec<MemoryRegion>, start: usize, end: usize) -> Vec<MemoryRegion> {
+        regions
+            .into_iter()
+            .filter_map(|mut region| {
+                let region_end = region.base_address + region.size;
+                let clipped_start = region.base_address.max(start);
+                let clipped_end = region_end.min(end);
+                if clipped_start >= clipped_end {
+                    return None;
+                }
+
+                region.base_address = clipped_start;
+                region.size = clipped_end - clipped_start;
+                Some(region)
             })
             .collect()
     }
@@ -43,9 +71,31 @@ impl<'a> MemoryScanner<'a> {
         platform::read_process_memory(self.process.handle(), address, size)
     }
 
+    /// Reads a null-terminated narrow string's bytes (without the terminator)
+    ///
+    /// Reads at most `max_len` bytes.
+    pub fn read_until_null(&self, address: usize, max_len: usize) -> Result<Vec<u8>> {
+        platform::read_until_null(self.process.handle(), address, max_len, 1)
+    }
+
+    /// Reads a null-terminated UTF-16 string's bytes, stopping at a zero u16
+    pub fn read_until_null_wide(&self, address: usize, max_len: usize) -> Result<Vec<u8>> {
+        platform::read_until_null(self.process.handle(), address, max_len, 2)
+    }
+
     /// Writes memory at a specific address
+    ///
+    /// Falls back to temporarily changing the page protection when the
+    /// direct write is denied (e.g. PAGE_READONLY pages)
     pub fn write_memory(&self, address: usize, data: &[u8]) -> Result<()> {
-        platform::write_process_memory(self.process.handle(), address, data)
+        let handle = self.process.handle();
+        match platform::write_process_memory(handle, address, data) {
+            Err(e) if platform::is_access_denied(&e) => {
+                tracing::debug!("Write denied at 0x{:X}, retrying with protection change", address);
+                platform::write_with_protection_change(handle, address, data)
+            }
+            result => result,
+        }
     }
 
     /// Reads an entire memory region in chunks
@@ -78,30 +128,74 @@ impl<'a> MemoryScanner<'a> {
     }
 
     /// Iterator over memory region chunks
-    pub fn iter_region_chunks(
+    ///
+    /// The iterator borrows the process rather than the scanner, so it can
+    /// outlive a temporary MemoryScanner.
+    pub fn iter_region_chunks(&self, region: &MemoryRegion) -> RegionChunkIterator<'a> {
+        RegionChunkIterator {
+            process: self.process,
+            base_address: region.base_address,
+            chunks: chunk_layout(region.size).into_iter(),
+        }
+    }
+
+    /// Runs `scan` over each chunk of a region and returns the matching addresses
+    ///
+    /// `scan` receives the chunk data and its address and returns offsets into
+    /// the chunk. Values spanning two chunks are not seen.
+    pub fn scan_region_with<F: Fn(&[u8], usize) -> Vec<usize>>(
         &self,
         region: &MemoryRegion,
-    ) -> impl Iterator<Item = (usize, Vec<u8>)> + '_ {
-        let base = region.base_address;
-        let size = region.size;
-        let handle = self.process.handle();
+        scan: F,
+    ) -> Vec<usize> {
+        self.iter_region_chunks(region)
+            .flat_map(|(address, data)| {
+                scan(&data, address).into_iter().map(move |offset| address + offset)
+            })
+            .collect()
+    }
+}
 
-        (0..size)
-            .step_by(CHUNK_SIZE)
-            .filter_map(move |offset| {
-                let chunk_size = (size - offset).min(CHUNK_SIZE);
-                let address = base + offset;
+/// Lazily reads a region chunk by chunk, skipping unreadable chunks
+///
+/// Yields (chunk address, chunk data).
+pub struct RegionChunkIterator<'a> {
+    process: &'a Process,
+    base_address: usize,
+    /// Remaining (offset, chunk_size) pairs
+    chunks: std::vec::IntoIter<(usize, usize)>,
+}
 
-                platform::read_process_memory(handle, address, chunk_size)
-                    .ok()
-                    .map(|data| (address, data))
-            })
+impl Iterator for RegionChunkIterator<'_> {
+    type Item = (usize, Vec<u8>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        for (offset, chunk_size) in self.chunks.by_ref() {
+            let address = self.base_address + offset;
+            if let Ok(data) = platform::read_process_memory(self.process.handle(), address, chunk_size) {
+                return Some((address, data));
+            }
+        }
+        None
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, Some(self.chunks.len()))
     }
 }
 
+/// Splits a region of `size` bytes into (offset, chunk_size) pairs of at most CHUNK_SIZE
+fn chunk_layout(size: usize) -> Vec<(usize, usize)> {
+    (0..size)
+        .step_by(CHUNK_SIZE)
+        .map(|offset| (offset, (size - offset).min(CHUNK_SIZE)))
+        .collect()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::platform::RegionType;
 
     #[test]
     fn test_query_regions() {
@@ -115,4 +209,119 @@ mod tests {
 
         assert!(!regions.is_empty(), "Should find at least one memory region");
     }
+
+    #[test]
+    fn test_filter_regions_skips_guard_pages() {
+        let current_pid = std::process::id();
+        let process = Process::open(current_pid, "self".to_string())
+            .expect("Failed to open current process");
+        let scanner = MemoryScanner::new(&process);
+
+        let region = |base_address: usize, region_type: RegionType, is_guard: bool| MemoryRegion {
+            base_address,
+            size: 0x1000,
+            protection: 0,
+            state: 0,
+            region_type,
+            is_readable: true,
+            is_writable: true,
+            is_executable: false,
+            is_guard,
+        };
+
+        let regions = vec![
+            region(0x1000, RegionType::Private, false),
+            region(0x2000, RegionType::Private, true),
+            region(0x3000, RegionType::Mapped, false),
+        ];
+        let filtered = scanner.filter_regions(regions, false, false, false, RegionTypeFlags::all());
+        let bases: Vec<usize> = filtered.iter().map(|r| r.base_address).collect();
+        assert_eq!(bases, vec![0x1000, 0x3000]);
+    }
+
+    #[test]
+    fn test_clip_regions() {
+        let current_pid = std::process::id();
+        let process = Process::open(current_pid, "self".to_string())
+            .expect("Failed to open current process");
+        let scanner = MemoryScanner::new(&process);
+
+        let region = |base_address: usize, size: usize| MemoryRegion {
+            base_address,
+            size,
+            protection: 0,
+            state: 0,
+            region_type: RegionType::Private,
+            is_readable: true,
+            is_writable: true,
+            is_executable: false,
+            is_guard: false,
+        };
+
+        let regions = vec![region(0x1000, 0x1000), region(0x3000, 0x2000), region(0x8000, 0x1000)];
+        let clipped = scanner.clip_regions(regions, 0x1800, 0x4000);
+
+        assert_eq!(clipped.len(), 2);
+        assert_eq!((clipped[0].base_address, clipped[0].size), (0x1800, 0x800));
+        assert_eq!((clipped[1].base_address, clipped[1].size), (0x3000, 0x1000));
+    }
+
+    #[test]
+    fn test_chunk_layout() {
+        assert!(chunk_layout(0).is_empty());
+        assert_eq!(chunk_layout(0x10), vec![(0, 0x10)]);
+        assert_eq!(
+            chunk_layout(CHUNK_SIZE * 2 + 0x10),
+            vec![(0, CHUNK_SIZE), (CHUNK_SIZE, CHUNK_SIZE), (CHUNK_SIZE * 2, 0x10)]
+        );
+    }
+
+    #[test]
+    fn test_read_until_null() {
+        let current_pid = std::process::id();
+        let process = Process::open(current_pid, "self".to_string())
+            .expect("Failed to open current process");
+        let scanner = MemoryScanner::new(&process);
+
+        // Longer than one 256-byte chunk
+        let mut narrow = vec![b'a'; 300];
+        narrow.push(0);
+        let address = narrow.as_ptr() as usize;
+        assert_eq!(scanner.read_until_null(address, 1024).unwrap(), vec![b'a'; 300]);
+        assert_eq!(scanner.read_until_null(address, 10).unwrap(), vec![b'a'; 10]);
+
+        // "hi" in UTF-16; the 0x00 high bytes must not end the string
+        let wide = Box::new([b'h', 0, b'i', 0, 0, 0, b'x', 0]);
+        let bytes = scanner.read_until_null_wide(wide.as_ptr() as usize, 64).unwrap();
+        assert_eq!(bytes, vec![b'h', 0, b'i', 0]);
+    }
+
+    #[test]
+    fn test_scan_region_with() {
+        let current_pid = std::process::id();
+        let process = Process::open(current_pid, "self".to_string())
+            .expect("Failed to open current process");
+        let scanner = MemoryScanner::new(&process);
+
+        let mut buffer = Box::new([0u8; 64]);
+        buffer[10] = 0xAB;
+        buffer[42] = 0xAB;
+        let region = MemoryRegion {
+            base_address: buffer.as_ptr() as usize,
+            size: buffer.len(),
+            protection: 0,
+            state: 0,
+            region_type: RegionType::Private,
+            is_readable: true,
+            is_writable: true,
+            is_executable: false,
+            is_guard: false,
+        };
+
+        let found = scanner.scan_region_with(&region, |data, _| {
+            data.iter().enumerate().filter(|(_, &b)| b == 0xAB).map(|(i, _)| i).collect()
+        });
+
+        assert_eq!(found, vec![region.base_address + 10, region.base_address + 42]);
+    }
 }
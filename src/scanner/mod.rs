@@ -3,8 +3,14 @@ pub mod memory;
 pub mod scan;
 pub mod filter;
 pub mod simd;
+pub mod session;
+pub mod pointer;
+pub mod error;
 
 pub use process::Process;
-pub use memory::MemoryScanner;
-pub use scan::Scanner;
+pub use memory::{MemoryScanner, RegionChunkIterator};
+pub use scan::{FreezeHandle, Scanner, WatchlistEntry};
 pub use filter::FilterEngine;
+pub use pointer::{PointerChain, PointerScanner};
+pub use session::{load_session, save_session, ScanSession};
+pub use error::ScanError;
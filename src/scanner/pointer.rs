@@ -0,0 +1,261 @@
+use crate::platform::module::{list_modules, ModuleInfo};
+use crate::platform::MemoryRegion;
+use crate::scanner::{MemoryScanner, Process};
+use crate::types::RegionTypeFlags;
+use anyhow::Result;
+use std::fmt;
+
+/// Size of a pointer in the target process (x64 only)
+const POINTER_SIZE: usize = 8;
+
+/// Upper bound on visited nodes so wide pointer graphs cannot run forever
+const MAX_VISITED: usize = 1_000_000;
+
+/// A pointer path from a module-relative base to the target address
+///
+/// `offsets[0]` is the base offset from the module start; each following
+/// offset is added after dereferencing the previous address.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PointerChain {
+    pub module: String,
+    pub offsets: Vec<i64>,
+}
+
+impl PointerChain {
+    /// Follows the chain in the target process and returns the final address
+    pub fn resolve(&self, process: &Process) -> Result<usize> {
+        let module = list_modules(process.pid)?
+            .into_iter()
+            .find(|m| m.name.eq_ignore_ascii_case(&self.module))
+            .ok_or_else(|| anyhow::anyhow!("Module {} not loaded", self.module))?;
+
+        let memory = MemoryScanner::new(process);
+        let (base, rest) = self
+            .offsets
+            .split_first()
+            .ok_or_else(|| anyhow::anyhow!("Empty pointer chain"))?;
+
+        let mut address = module.base_address.wrapping_add(*base as usize);
+        for offset in rest {
+            let bytes = memory.read_memory(address, POINTER_SIZE)?;
+            let pointer = usize::from_le_bytes(bytes[..POINTER_SIZE].try_into().unwrap());
+            address = pointer.wrapping_add(*offset as usize);
+        }
+
+        Ok(address)
+    }
+}
+
+impl fmt::Display for PointerChain {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let Some((base, rest)) = self.offsets.split_first() else {
+            return write!(f, "{}", self.module);
+        };
+
+        write!(f, "\"{}\"+0x{:X}", self.module, base)?;
+        for offset in rest {
+            write!(f, " -> 0x{:X}", offset)?;
+        }
+        Ok(())
+    }
+}
+
+/// Searches for pointer chains that lead to a target address
+pub struct PointerScanner {
+    /// Stop after this many chains have been found
+    pub max_results: usize,
+}
+
+impl Default for PointerScanner {
+    fn default() -> Self {
+        Self { max_results: 10_000 }
+    }
+}
+
+impl PointerScanner {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Finds chains of up to `max_depth` dereferences ending at `target_address`
+    ///
+    /// Each step allows the pointed-to address to be up to `max_offset` bytes
+    /// before the next address in the chain.
+    pub fn scan(
+        &self,
+        target_address: usize,
+        max_depth: u8,
+        max_offset: usize,
+        process: &Process,
+    ) -> Result<Vec<PointerChain>> {
+        let memory = MemoryScanner::new(process);
+
+        let mut readable = memory.filter_regions(
+            memory.query_regions()?,
+            true,
+            false,
+            false,
+            RegionTypeFlags::all(),
+        );
+        readable.sort_by_key(|r| r.base_address);
+
+        let writable: Vec<MemoryRegion> = readable.iter().filter(|r| r.is_writable).cloned().collect();
+
+        tracing::info!("Building pointer map from {} writable regions", writable.len());
+        let map = build_pointer_map(&memory, &writable, &readable);
+        tracing::info!("Pointer map contains {} candidate pointers", map.len());
+
+        let mut modules = list_modules(process.pid)?;
+        modules.sort_by_key(|m| m.base_address);
+
+        let mut search = ChainSearch {
+            map: &map,
+            modules: &modules,
+            max_offset,
+            max_results: self.max_results,
+            visited: 0,
+            path: Vec::new(),
+            chains: Vec::new(),
+        };
+        search.run(target_address, max_depth);
+
+        Ok(search.chains)
+    }
+}
+
+/// Depth-first search state for walking the pointer map backwards
+struct ChainSearch<'a> {
+    map: &'a [(usize, usize)],
+    modules: &'a [ModuleInfo],
+    max_offset: usize,
+    max_results: usize,
+    visited: usize,
+    /// Offsets collected so far, target side first
+    path: Vec<i64>,
+    chains: Vec<PointerChain>,
+}
+
+impl ChainSearch<'_> {
+    fn run(&mut self, target: usize, depth_left: u8) {
+        if depth_left == 0 {
+            return;
+        }
+
+        for (address, offset) in find_pointers_to(self.map, target, self.max_offset) {
+            if self.chains.len() >= self.max_results || self.visited >= MAX_VISITED {
+                return;
+            }
+            self.visited += 1;
+
+            self.path.push(offset as i64);
+
+            if let Some(module) = find_module(self.modules, address) {
+                // Static address: the chain is rooted here
+                let mut offsets = vec![(address - module.base_address) as i64];
+                offsets.extend(self.path.iter().rev());
+                self.chains.push(PointerChain {
+                    module: module.name.clone(),
+                    offsets,
+                });
+            } else {
+                self.run(address, depth_left - 1);
+            }
+
+            self.path.pop();
+        }
+    }
+}
+
+/// Collects every aligned value in `regions` that points into readable memory
+///
+/// Returns (pointer value, address of the pointer) sorted by value.
+fn build_pointer_map(
+    memory: &MemoryScanner,
+    regions: &[MemoryRegion],
+    readable: &[MemoryRegion],
+) -> Vec<(usize, usize)> {
+    let mut map = Vec::new();
+
+    for region in regions {
+        let Ok(data) = memory.read_region(region) else {
+            continue;
+        };
+
+        for (index, chunk) in data.chunks_exact(POINTER_SIZE).enumerate() {
+            let value = usize::from_le_bytes(chunk.try_into().unwrap());
+            if is_readable_address(readable, value) {
+                map.push((value, region.base_address + index * POINTER_SIZE));
+            }
+        }
+    }
+
+    map.sort_unstable();
+    map
+}
+
+/// Returns (pointer address, offset) for pointers whose value is in [target - max_offset, target]
+fn find_pointers_to(
+    map: &[(usize, usize)],
+    target: usize,
+    max_offset: usize,
+) -> impl Iterator<Item = (usize, usize)> + '_ {
+    let low = target.saturating_sub(max_offset);
+    let start = map.partition_point(|&(value, _)| value < low);
+
+    map[start..]
+        .iter()
+        .take_while(move |&&(value, _)| value <= target)
+        .map(move |&(value, address)| (address, target - value))
+}
+
+/// Checks whether an address falls inside one of the (sorted) readable regions
+fn is_readable_address(regions: &[MemoryRegion], address: usize) -> bool {
+    let index = regions.partition_point(|r| r.base_address <= address);
+    index > 0 && address < regions[index - 1].base_address + regions[index - 1].size
+}
+
+/// Finds the module (sorted by base) containing an address
+fn find_module(modules: &[ModuleInfo], address: usize) -> Option<&ModuleInfo> {
+    let index = modules.partition_point(|m| m.base_address <= address);
+    let module = modules.get(index.checked_sub(1)?)?;
+    (address < module.base_address + module.size).then_some(module)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    #[test]
+    fn test_find_pointers_to() {
+        let map = vec![(0x1000, 0xA0), (0x1008, 0xB0), (0x1100, 0xC0), (0x2000, 0xD0)];
+
+        let found: Vec<_> = find_pointers_to(&map, 0x1010, 0x20).collect();
+        assert_eq!(found, vec![(0xA0, 0x10), (0xB0, 0x8)]);
+
+        assert_eq!(find_pointers_to(&map, 0x10, 0x100).count(), 0);
+    }
+
+    #[test]
+    fn test_scan_finds_static_chain() {
+        static ROOT: AtomicUsize = AtomicUsize::new(0);
+
+        let current_pid = std::process::id();
+        let process = Process::open(current_pid, "self".to_string())
+            .expect("Failed to open current process");
+
+        let object = Box::new([0u64; 4]);
+        let target = &object[2] as *const u64 as usize;
+        ROOT.store(object.as_ptr() as usize, Ordering::SeqCst);
+
+        let chains = PointerScanner::new()
+            .scan(target, 1, 0x100, &process)
+            .expect("Pointer scan failed");
+
+        let chain = chains
+            .iter()
+            .find(|c| c.offsets.len() == 2 && c.offsets[1] == 0x10)
+            .expect("Chain through the static root not found");
+        assert_eq!(chain.resolve(&process).expect("Failed to resolve chain"), target);
+    }
+}
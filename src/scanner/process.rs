@@ -11,8 +11,13 @@ pub struct Process {
 
 impl Process {
     /// Lists all running processes
+    ///
+    /// Falls back to WMI when the Toolhelp snapshot fails (e.g. snapshot quota exceeded).
     pub fn list_all() -> Result<Vec<ProcessInfo>> {
-        platform::list_processes()
+        platform::list_processes().or_else(|e| {
+            tracing::warn!("Process snapshot failed ({}), falling back to WMI", e);
+            platform::list_processes_wmi()
+        })
     }
 
     /// Opens a process by PID
@@ -26,17 +31,72 @@ impl Process {
         Self::open(info.pid, info.name.clone())
     }
 
+    /// Opens the first running process with the given name
+    ///
+    /// Matching is case-insensitive and the ".exe" suffix is optional
+    /// ("game", "Game.exe" and "GAME.EXE" all match game.exe).
+    pub fn try_from_name(name: &str) -> Result<Self> {
+        let info = Self::list_all()?
+            .into_iter()
+            .find(|p| name_matches(&p.name, name))
+            .ok_or_else(|| anyhow::anyhow!("No running process named {}", name))?;
+        Self::from_info(&info)
+    }
+
+    /// Opens every running process with the given name (see try_from_name)
+    ///
+    /// Instances that cannot be opened (e.g. higher integrity) are skipped.
+    pub fn all_with_name(name: &str) -> Result<Vec<Self>> {
+        Ok(Self::list_all()?
+            .iter()
+            .filter(|p| name_matches(&p.name, name))
+            .filter_map(|p| match Self::from_info(p) {
+                Ok(process) => Some(process),
+                Err(e) => {
+                    tracing::debug!("Skipping {} (PID {}): {}", p.name, p.pid, e);
+                    None
+                }
+            })
+            .collect())
+    }
+
+    /// Whether the process is still running (false once it exited or crashed)
+    pub fn is_running(&self) -> bool {
+        platform::is_process_running(self.handle)
+    }
+
     /// Gets the process handle
     pub fn handle(&self) -> HANDLE {
         self.handle
     }
 
+    /// Opens a second, independently owned handle to the same process
+    pub fn try_clone(&self) -> Result<Self> {
+        Ok(Self {
+            pid: self.pid,
+            name: self.name.clone(),
+            handle: platform::duplicate_handle(self.handle)?,
+        })
+    }
+
     /// Gets the process handle as usize (for engine abstraction)
     pub fn handle_as_usize(&self) -> usize {
         unsafe { std::mem::transmute(self.handle) }
     }
 }
 
+/// Compares process names case-insensitively, ignoring a trailing ".exe"
+fn name_matches(process_name: &str, name: &str) -> bool {
+    let strip = |s: &str| {
+        let lower = s.to_lowercase();
+        match lower.strip_suffix(".exe") {
+            Some(stem) => stem.to_string(),
+            None => lower,
+        }
+    };
+    strip(process_name) == strip(name)
+}
+
 impl Drop for Process {
     fn drop(&mut self) {
         let _ = platform::close_process(self.handle);
@@ -1,11 +1,36 @@
-use crate::scanner::{MemoryScanner, Process};
-use crate::types::{ScanOptions, ScanResult, ScanResults, ScanType, ScanValue, ValueType};
+use crate::engine::{ClassHandle, GameEngine};
+use crate::platform;
+use crate::scanner::{simd, FilterEngine, MemoryScanner, Process, ScanError};
+use crate::types::{
+    Endianness, RegionTypeFlags, ScanOptions, ScanProgress, ScanResult, ScanResults, ScanType, ScanValue,
+    ValueType,
+};
 use anyhow::Result;
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::thread::JoinHandle;
+use std::time::{Duration, Instant};
+
+/// Interval at which a single value locked with `Scanner::freeze_value` is re-written
+const FREEZE_VALUE_INTERVAL: Duration = Duration::from_millis(10);
+
+/// Minimum number of results in one region for `Scanner::next_scan_batch` to read it at once
+const BATCH_MIN_RESULTS: usize = 10;
+
+/// Largest span `Scanner::next_scan_batch` reads in one call
+const BATCH_MAX_SPAN: usize = 1024 * 1024;
+
+/// First scans read regions in pieces of this size, checking the timeout between pieces
+const FIRST_SCAN_PIECE_SIZE: usize = 16 * 1024 * 1024;
 
 /// Main scanner for performing memory scans
 pub struct Scanner {
     process: Process,
     results: ScanResults,
+    /// Region filter applied by next scans, derived from the last first scan
+    next_scan_options: ScanOptions,
 }
 
 impl Scanner {
@@ -14,9 +39,17 @@ impl Scanner {
         Self {
             process,
             results: ScanResults::new(ValueType::I32), // Default type
+            next_scan_options: Self::restored_next_scan_options(),
         }
     }
 
+    /// Next scan options for results that did not come from `first_scan`
+    ///
+    /// Results restored from elsewhere may come from any region type.
+    fn restored_next_scan_options() -> ScanOptions {
+        ScanOptions::for_next_scan(&ScanOptions::new(ValueType::I32).with_region_types(RegionTypeFlags::all()))
+    }
+
     /// Gets a reference to the process
     pub fn process(&self) -> &Process {
         &self.process
@@ -28,19 +61,61 @@ impl Scanner {
     }
 
     /// Gets a mutable reference to the current scan results
+    ///
+    /// The results may be replaced through it (sessions, imports), so next
+    /// scans stop assuming the region filter of the last first scan.
     pub fn results_mut(&mut self) -> &mut ScanResults {
+        self.next_scan_options = Self::restored_next_scan_options();
         &mut self.results
     }
 
+    /// Replaces the results with those of a first scan made with `options` by another scanner
+    pub fn set_first_scan_results(&mut self, results: ScanResults, options: &ScanOptions) {
+        self.results = results;
+        self.next_scan_options = ScanOptions::for_next_scan(options);
+    }
+
+    /// Consumes the scanner and returns its results
+    pub fn into_results(self) -> ScanResults {
+        self.results
+    }
+
     /// Performs a first scan for the given value
+    ///
+    /// If the scan times out, the matches found so far are kept as the
+    /// results and `ScanError::Timeout` is returned.
     pub fn first_scan(
         &mut self,
         value: &ScanValue,
         scan_type: ScanType,
         options: &ScanOptions,
+    ) -> Result<usize> {
+        let mut results = Vec::new();
+        let scanned = self.first_scan_stream(value, scan_type, options, |result| results.push(result));
+
+        self.results.results = results;
+        self.results.deduplicate();
+        scanned.map(|_| self.results.len())
+    }
+
+    /// Performs a first scan, passing each match to `callback` instead of storing it
+    ///
+    /// The scanner's own results are cleared; callers that want to keep
+    /// matches for a next scan must put them back via `set_first_scan_results`
+    /// with the same `options`, so the next scan applies the same filters.
+    /// Returns the total number of matches, or `ScanError::Timeout` once
+    /// `options.timeout` has passed (matches passed to `callback` until then stand).
+    pub fn first_scan_stream<F: FnMut(ScanResult)>(
+        &mut self,
+        value: &ScanValue,
+        scan_type: ScanType,
+        options: &ScanOptions,
+        mut callback: F,
     ) -> Result<usize> {
         // Reset previous results
         self.results = ScanResults::new(options.value_type);
+        self.results.endianness = options.endianness;
+        self.next_scan_options = ScanOptions::for_next_scan(options);
 
         let memory = MemoryScanner::new(&self.process);
 
@@ -48,37 +123,126 @@ impl Scanner {
         let regions = memory.query_regions()?;
 
         // Filter regions based on options
-        let regions = memory.filter_regions(
+        let mut regions = memory.filter_regions(
             regions,
             options.readable_only,
             options.writable_only,
             options.executable_only,
+            options.region_types,
         );
 
+        if options.heap_only {
+            regions.retain(|region| region.is_heap_like());
+        }
+
+        // Restrict to the requested address range
+        let regions = match options.address_range {
+            Some((start, end)) => memory.clip_regions(regions, start, end),
+            None => regions,
+        };
+
         tracing::info!("Scanning {} memory regions", regions.len());
 
         // Scan regions sequentially (HANDLE is not thread-safe)
-        let mut results = Vec::new();
-        for region in &regions {
-            let region_results = scan_region_first(region, value, scan_type, options, &memory);
-            results.extend(region_results);
-        }
+        let started = Instant::now();
+        let deadline = options.timeout.map(|timeout| started + timeout);
+        let mut found = 0;
+        let mut bytes_scanned = 0u64;
+        let mut timed_out = false;
+        for (index, region) in regions.iter().enumerate() {
+            if options.cancellation_token.load(Ordering::Relaxed) {
+                tracing::info!("Scan cancelled after {} of {} regions", index, regions.len());
+                break;
+            }
+
+            let completed = scan_region_first(region, value, scan_type, options, &memory, deadline, &mut |result| {
+                found += 1;
+                callback(result);
+            });
+            if !completed {
+                tracing::warn!("Scan timed out after {} of {} regions", index, regions.len());
+                timed_out = true;
+                break;
+            }
+            bytes_scanned += region.size as u64;
 
-        for result in results {
-            self.results.add_result(result);
+            if let Some(ref callback) = options.progress_callback {
+                callback(ScanProgress {
+                    regions_done: index + 1,
+                    regions_total: regions.len(),
+                    results_found: found,
+                    bytes_scanned,
+                });
+            }
         }
 
         self.results.increment_scan_count();
+        if timed_out {
+            return Err(ScanError::Timeout { elapsed: started.elapsed(), results_found: found }.into());
+        }
+        Ok(found)
+    }
+
+    /// Performs a first scan over one field of every instance of a class
+    ///
+    /// Instead of reading all memory, the engine supplies the instances and
+    /// the field offset, so only `instance + offset` is checked for each
+    /// instance. The matches replace the current results like `first_scan`.
+    pub fn scan_for_field(
+        &mut self,
+        engine: &dyn GameEngine,
+        class: ClassHandle,
+        field_name: &str,
+        value: &ScanValue,
+        scan_type: ScanType,
+    ) -> Result<usize> {
+        let field = engine.get_field_info(engine.find_field(class, field_name)?)?;
+        let instances = engine.get_instances(class)?;
+
+        let value_type = value.value_type();
+        let memory = MemoryScanner::new(&self.process);
+        let mut results = ScanResults::new(value_type);
+
+        for instance in instances {
+            let address = instance.0 + field.offset;
+            let Ok(bytes) = memory.read_memory(address, value_type.size()) else {
+                continue;
+            };
+            let Some(current) = ScanValue::from_bytes(&bytes, value_type) else {
+                continue;
+            };
+
+            if FilterEngine::matches(&current, None, Some(value), scan_type) {
+                results.add_result(ScanResult::new(address, bytes));
+            }
+        }
+
+        tracing::info!(
+            "Field scan of {} at +0x{:X}: {} matches",
+            field_name,
+            field.offset,
+            results.len()
+        );
+
+        results.increment_scan_count();
+        // Clear filters left over from an earlier first scan
+        self.set_first_scan_results(results, &ScanOptions::new(value_type));
         Ok(self.results.len())
     }
 
     /// Performs a subsequent scan to filter previous results
+    ///
+    /// Results in pages that `ScanOptions::for_next_scan` excludes (read-only
+    /// pages, by default) are dropped without being re-read.
     pub fn next_scan(&mut self, value: &ScanValue, scan_type: ScanType) -> Result<usize> {
         if self.results.is_empty() {
             return Ok(0);
         }
 
         let memory = MemoryScanner::new(&self.process);
+        retain_scannable_results(&mut self.results, &self.next_scan_options, &memory)?;
+        let value_type = self.results.value_type;
+        let endianness = self.results.endianness;
 
         // Re-read values at known addresses (sequential for thread safety)
         let filtered: Vec<ScanResult> = self
@@ -86,7 +250,7 @@ impl Scanner {
             .results
             .iter()
             .filter_map(|result| {
-                rescan_address(result, value, scan_type, self.results.value_type, &memory)
+                rescan_address(result, value, scan_type, value_type, endianness, &memory)
             })
             .collect();
 
@@ -96,6 +260,113 @@ impl Scanner {
         Ok(self.results.len())
     }
 
+    /// Performs a subsequent scan like `next_scan`, reading dense results in batches
+    ///
+    /// Results are grouped by memory region and each group is read with one
+    /// `read_region` call (spans of at most 1 MB) and filtered in memory, using
+    /// the SIMD routines where they apply. Regions with fewer than 10 results,
+    /// and groups whose read fails, fall back to reading address by address.
+    /// The remaining results are sorted by address.
+    pub fn next_scan_batch(&mut self, value: &ScanValue, scan_type: ScanType) -> Result<usize> {
+        if self.results.is_empty() {
+            return Ok(0);
+        }
+
+        let memory = MemoryScanner::new(&self.process);
+        let value_type = self.results.value_type;
+        let endianness = self.results.endianness;
+        let size = value_type.size();
+
+        let regions = retain_scannable_results(&mut self.results, &self.next_scan_options, &memory)?;
+
+        let mut previous = std::mem::take(&mut self.results.results);
+        previous.sort_by_key(|r| r.address);
+
+        // Region fully containing the value at `address`, if any
+        let region_of = |address: usize| {
+            let index = regions.partition_point(|r| r.base_address <= address).checked_sub(1)?;
+            let region = &regions[index];
+            (address + size <= region.base_address + region.size).then_some(region)
+        };
+
+        let rescan = BatchRescan {
+            value,
+            scan_type,
+            value_type,
+            endianness,
+            memory: &memory,
+        };
+        let mut filtered = Vec::with_capacity(previous.len());
+        let mut start = 0;
+        while start < previous.len() {
+            let region = region_of(previous[start].address);
+            let base = previous[start].address;
+
+            // Extend the batch while results stay in the same region and span
+            let mut end = start + 1;
+            while end < previous.len() {
+                let address = previous[end].address;
+                let same_region = match region {
+                    Some(region) => address + size <= region.base_address + region.size,
+                    None => region_of(address).is_none(),
+                };
+                if !same_region || address + size - base > BATCH_MAX_SPAN {
+                    break;
+                }
+                end += 1;
+            }
+
+            rescan.batch(&previous[start..end], region, &mut filtered);
+            start = end;
+        }
+
+        self.results.results = filtered;
+        self.results.increment_scan_count();
+
+        Ok(self.results.len())
+    }
+
+    /// Removes results holding the most common values, e.g. zero-filled memory
+    /// after an "Unknown Initial Value" scan
+    ///
+    /// A value is pruned when more than `percentile` percent of the results hold
+    /// it (1.0 prunes values found at more than 1% of the addresses). Values are
+    /// compared as recorded by the last scan rather than re-read, so pruning
+    /// hundreds of millions of results stays cheap. Returns the number of
+    /// removed results.
+    pub fn prune_unknown_results(&mut self, percentile: f64) -> usize {
+        let total = self.results.len();
+        if total == 0 {
+            return 0;
+        }
+
+        let mut counts: HashMap<&[u8], usize> = HashMap::new();
+        for result in &self.results.results {
+            *counts.entry(result.get_current_value()).or_default() += 1;
+        }
+
+        let threshold = total as f64 * percentile / 100.0;
+        let common: HashSet<Vec<u8>> = counts
+            .into_iter()
+            .filter(|&(_, count)| count as f64 > threshold)
+            .map(|(value, _)| value.to_vec())
+            .collect();
+        if common.is_empty() {
+            return 0;
+        }
+
+        self.results
+            .results
+            .retain(|result| !common.contains(result.get_current_value()));
+        let removed = total - self.results.len();
+        tracing::info!(
+            "Pruned {} results holding {} common value(s)",
+            removed,
+            common.len()
+        );
+        removed
+    }
+
     /// Resets the scanner
     pub fn reset(&mut self) {
         self.results.clear();
@@ -103,35 +374,285 @@ impl Scanner {
 
     /// Writes a value to a specific address
     pub fn write_value(&self, address: usize, value: &ScanValue) -> Result<()> {
+        self.write_value_endian(address, value, Endianness::Little)
+    }
+
+    /// Writes a value to a specific address in the given byte order
+    pub fn write_value_endian(
+        &self,
+        address: usize,
+        value: &ScanValue,
+        endianness: Endianness,
+    ) -> Result<()> {
         let memory = MemoryScanner::new(&self.process);
-        memory.write_memory(address, &value.to_bytes())
+        memory.write_memory(address, &value.to_bytes_endian(endianness))
     }
 
     /// Reads the current value at an address
     pub fn read_value(&self, address: usize, value_type: ValueType) -> Result<ScanValue> {
+        self.read_value_endian(address, value_type, Endianness::Little)
+    }
+
+    /// Reads the current value at an address in the given byte order
+    pub fn read_value_endian(
+        &self,
+        address: usize,
+        value_type: ValueType,
+        endianness: Endianness,
+    ) -> Result<ScanValue> {
         let memory = MemoryScanner::new(&self.process);
         let bytes = memory.read_memory(address, value_type.size())?;
-        ScanValue::from_bytes(&bytes, value_type)
+        ScanValue::from_bytes_endian(&bytes, value_type, endianness)
             .ok_or_else(|| anyhow::anyhow!("Failed to parse value"))
     }
+
+    /// Reads a value stored XORed with a key at `address + key_offset`, in the given byte order
+    pub fn read_guarded_value(
+        &self,
+        address: usize,
+        key_offset: i32,
+        value_type: ValueType,
+        endianness: Endianness,
+    ) -> Result<ScanValue> {
+        let bytes = platform::GuardedValue::decode_xor(
+            self.process.handle(),
+            address,
+            key_offset,
+            value_type.size(),
+        )?;
+        ScanValue::from_bytes_endian(&bytes, value_type, endianness)
+            .ok_or_else(|| anyhow::anyhow!("Failed to parse value"))
+    }
+
+    /// Locks a single address to a value on a dedicated background thread
+    ///
+    /// The value is re-written at a short interval, so values the game resets
+    /// quickly stay locked. The lock is released when the returned handle is
+    /// dropped. The handle owns a duplicate of the process handle, so it stays
+    /// valid even if it outlives the scanner.
+    pub fn freeze_value(&self, address: usize, value: ScanValue) -> Result<FreezeHandle> {
+        self.freeze_value_endian(address, value, Endianness::Little)
+    }
+
+    /// Locks a single address to a value written in the given byte order
+    ///
+    /// Writes the same bytes as `write_value_endian`.
+    pub fn freeze_value_endian(
+        &self,
+        address: usize,
+        value: ScanValue,
+        endianness: Endianness,
+    ) -> Result<FreezeHandle> {
+        let stop = Arc::new(AtomicBool::new(false));
+        let stop_flag = stop.clone();
+
+        let process = self.process.try_clone()?;
+        // HANDLE is not Send, so pass it across the thread boundary as usize
+        let handle_value = process.handle_as_usize();
+        let bytes = value.to_bytes_endian(endianness);
+
+        let thread = std::thread::spawn(move || {
+            let handle = unsafe { std::mem::transmute::<usize, platform::HANDLE>(handle_value) };
+
+            while !stop_flag.load(Ordering::Relaxed) {
+                if let Err(e) = platform::write_process_memory(handle, address, &bytes) {
+                    tracing::debug!("Failed to write frozen value at 0x{:X}: {}", address, e);
+                }
+                std::thread::sleep(FREEZE_VALUE_INTERVAL);
+            }
+        });
+
+        Ok(FreezeHandle {
+            address,
+            value,
+            endianness,
+            stop,
+            thread: Some(thread),
+            _process: process,
+        })
+    }
+}
+
+/// A value locked by `Scanner::freeze_value`
+///
+/// Dropping the handle stops the thread and waits for it to exit before the
+/// process handle it writes through is closed.
+pub struct FreezeHandle {
+    address: usize,
+    value: ScanValue,
+    endianness: Endianness,
+    stop: Arc<AtomicBool>,
+    thread: Option<JoinHandle<()>>,
+    _process: Process,
+}
+
+impl FreezeHandle {
+    /// The locked address
+    pub fn address(&self) -> usize {
+        self.address
+    }
+
+    /// The value written to the address
+    pub fn value(&self) -> &ScanValue {
+        &self.value
+    }
+
+    /// The byte order the value is written in
+    pub fn endianness(&self) -> Endianness {
+        self.endianness
+    }
+}
+
+impl Drop for FreezeHandle {
+    fn drop(&mut self) {
+        self.stop.store(true, Ordering::Relaxed);
+        if let Some(thread) = self.thread.take() {
+            let _ = thread.join();
+        }
+    }
 }
 
-/// Scans a single memory region for the first time
+/// An address tracked in the watchlist
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct WatchlistEntry {
+    pub address: usize,
+    pub value_type: ValueType,
+    /// Value to keep writing while frozen (None = not frozen)
+    pub frozen_value: Option<ScanValue>,
+    pub label: String,
+    /// Byte order used to read and freeze the value
+    #[serde(default)]
+    pub endianness: Endianness,
+}
+
+impl WatchlistEntry {
+    pub fn new(address: usize, value_type: ValueType) -> Self {
+        Self {
+            address,
+            value_type,
+            frozen_value: None,
+            label: String::new(),
+            endianness: Endianness::Little,
+        }
+    }
+
+    pub fn is_frozen(&self) -> bool {
+        self.frozen_value.is_some()
+    }
+}
+
+/// Scans a single memory region for the first time, reporting each match to `on_result`
+/// Returns false if `deadline` passed before the whole region was scanned
 fn scan_region_first(
     region: &crate::platform::MemoryRegion,
     value: &ScanValue,
     scan_type: ScanType,
     options: &ScanOptions,
     memory: &MemoryScanner,
-) -> Vec<ScanResult> {
-    let mut results = Vec::new();
+    deadline: Option<Instant>,
+    on_result: &mut impl FnMut(ScanResult),
+) -> bool {
+    let value_size = options.value_type.size();
+    let mut offset = 0;
+    while offset < region.size {
+        if deadline.is_some_and(|deadline| Instant::now() >= deadline) {
+            return false;
+        }
 
-    // Read the entire region
+        // Each piece overlaps the next by value_size - 1 bytes so values straddling
+        // the boundary are still read; they are reported by the piece they start in
+        let piece_size = FIRST_SCAN_PIECE_SIZE.min(region.size - offset);
+        let piece = crate::platform::MemoryRegion {
+            base_address: region.base_address + offset,
+            size: (piece_size + value_size - 1).min(region.size - offset),
+            ..region.clone()
+        };
+        let piece_end = piece.base_address + piece_size;
+        scan_piece_first(&piece, value, scan_type, options, memory, &mut |result| {
+            if result.address < piece_end {
+                on_result(result);
+            }
+        });
+        offset += piece_size;
+    }
+    true
+}
+
+fn scan_piece_first(
+    region: &crate::platform::MemoryRegion,
+    value: &ScanValue,
+    scan_type: ScanType,
+    options: &ScanOptions,
+    memory: &MemoryScanner,
+    on_result: &mut impl FnMut(ScanResult),
+) {
+    // Read the entire piece
     let data = match memory.read_region(region) {
         Ok(data) => data,
-        Err(_) => return results,
+        Err(_) => return,
     };
 
+    match simd_scan_offsets(&data, region.base_address, value, scan_type, options) {
+        Some(offsets) => {
+            let value_size = options.value_type.size();
+            for offset in offsets {
+                on_result(ScanResult::new(
+                    region.base_address + offset,
+                    data[offset..offset + value_size].to_vec(),
+                ));
+            }
+        }
+        None => scalar_scan_buffer(&data, region.base_address, value, scan_type, options, on_result),
+    }
+}
+
+/// Dispatches a region buffer to the SIMD routines, returning the matching offsets
+///
+/// Covers exact scans of 4- and 8-byte integers and f32 (signedness does not
+/// matter for equality, so unsigned targets reuse the signed lanes), and
+/// i32/f32 greater/less than. Returns `None` when no routine applies; the
+/// 4-byte lanes need an alignment that is a multiple of 4, and every lane
+/// compares native (little-endian) byte order.
+fn simd_scan_offsets(
+    data: &[u8],
+    base_address: usize,
+    value: &ScanValue,
+    scan_type: ScanType,
+    options: &ScanOptions,
+) -> Option<Vec<usize>> {
+    let alignment = options.alignment;
+    if options.endianness != Endianness::Little || !base_address.is_multiple_of(alignment) {
+        return None;
+    }
+    let lanes_aligned = alignment.is_multiple_of(4);
+
+    match (scan_type, value) {
+        (ScanType::Exact, ScanValue::I32(target)) if lanes_aligned => Some(simd::scan_i32(data, *target, alignment)),
+        (ScanType::Exact, ScanValue::U32(target)) if lanes_aligned => {
+            Some(simd::scan_i32(data, *target as i32, alignment))
+        }
+        (ScanType::Exact, ScanValue::F32(target)) if lanes_aligned => Some(simd::scan_f32(data, *target, alignment)),
+        (ScanType::Exact, ScanValue::U64(target)) => Some(simd::scan_u64(data, *target, alignment)),
+        (ScanType::Exact, ScanValue::I64(target)) => Some(simd::scan_u64(data, *target as u64, alignment)),
+        (ScanType::GreaterThan | ScanType::LessThan, ScanValue::I32(target)) if lanes_aligned => {
+            Some(simd::scan_i32_with_type(data, *target, scan_type, alignment))
+        }
+        (ScanType::GreaterThan | ScanType::LessThan, ScanValue::F32(target)) if lanes_aligned => {
+            Some(simd::scan_f32_with_type(data, *target, scan_type, alignment))
+        }
+        _ => None,
+    }
+}
+
+/// Compares every aligned offset of a region buffer through `ScanValue`
+fn scalar_scan_buffer(
+    data: &[u8],
+    base_address: usize,
+    value: &ScanValue,
+    scan_type: ScanType,
+    options: &ScanOptions,
+    on_result: &mut impl FnMut(ScanResult),
+) {
     let value_size = options.value_type.size();
     let alignment = options.alignment;
 
@@ -139,19 +660,22 @@ fn scan_region_first(
     let mut offset = 0;
     while offset + value_size <= data.len() {
         // Check alignment
-        if (region.base_address + offset) % alignment == 0 {
+        if (base_address + offset) % alignment == 0 {
             let chunk = &data[offset..offset + value_size];
 
-            if let Some(found_value) = ScanValue::from_bytes(chunk, options.value_type) {
+            if let Some(found_value) =
+                ScanValue::from_bytes_endian(chunk, options.value_type, options.endianness)
+            {
                 let matches = match scan_type {
                     ScanType::Unknown => true, // Match everything on unknown scan
                     _ => found_value.compare(value, scan_type),
                 };
 
                 if matches {
-                    results.push(ScanResult::new(
-                        region.base_address + offset,
-                        chunk.to_vec(),
+                    // Results always hold little-endian bytes
+                    on_result(ScanResult::new(
+                        base_address + offset,
+                        found_value.to_bytes(),
                     ));
                 }
             }
@@ -159,8 +683,104 @@ fn scan_region_first(
 
         offset += alignment;
     }
+}
+
+/// Drops results outside the regions selected by `options`
+///
+/// With options from `ScanOptions::for_next_scan` only writable pages are kept,
+/// so values in read-only pages are not re-read. Returns the selected regions
+/// sorted by base address.
+fn retain_scannable_results(
+    results: &mut ScanResults,
+    options: &ScanOptions,
+    memory: &MemoryScanner,
+) -> Result<Vec<platform::MemoryRegion>> {
+    let mut regions = memory.filter_regions(
+        memory.query_regions()?,
+        options.readable_only,
+        options.writable_only,
+        options.executable_only,
+        options.region_types,
+    );
+    regions.sort_by_key(|r| r.base_address);
+
+    let size = results.value_type.size();
+    results.results.retain(|result| {
+        let index = regions.partition_point(|r| r.base_address <= result.address);
+        index.checked_sub(1).is_some_and(|index| {
+            let region = &regions[index];
+            result.address + size <= region.base_address + region.size
+        })
+    });
+
+    Ok(regions)
+}
+
+/// Settings of one `Scanner::next_scan_batch` pass
+struct BatchRescan<'a> {
+    value: &'a ScanValue,
+    scan_type: ScanType,
+    value_type: ValueType,
+    endianness: Endianness,
+    memory: &'a MemoryScanner<'a>,
+}
+
+impl BatchRescan<'_> {
+    /// Filters a batch of results sorted by address that lie in `region`
+    fn batch(&self, batch: &[ScanResult], region: Option<&crate::platform::MemoryRegion>, out: &mut Vec<ScanResult>) {
+        let size = self.value_type.size();
+
+        let data = match region {
+            Some(region) if batch.len() >= BATCH_MIN_RESULTS => {
+                let base = batch[0].address;
+                let span = crate::platform::MemoryRegion {
+                    base_address: base,
+                    size: batch[batch.len() - 1].address + size - base,
+                    ..region.clone()
+                };
+                self.memory.read_region(&span).ok()
+            }
+            _ => None,
+        };
+        let Some(data) = data else {
+            out.extend(batch.iter().filter_map(|result| {
+                rescan_address(result, self.value, self.scan_type, self.value_type, self.endianness, self.memory)
+            }));
+            return;
+        };
+
+        let base = batch[0].address;
+        let bytes_at = |result: &ScanResult| &data[result.address - base..result.address - base + size];
 
-    results
+        // SIMD pass over the whole span when every result sits on a lane boundary
+        let simd_offsets = if self.scan_type.requires_value() && batch.iter().all(|r| r.address.is_multiple_of(size)) {
+            let options = ScanOptions::new(self.value_type)
+                .with_alignment(size)
+                .with_endianness(self.endianness);
+            simd_scan_offsets(&data, base, self.value, self.scan_type, &options)
+        } else {
+            None
+        };
+
+        match simd_offsets {
+            Some(offsets) => {
+                // Both lists are sorted, so walk them together
+                let mut offsets = offsets.into_iter().peekable();
+                for result in batch {
+                    let offset = result.address - base;
+                    while offsets.next_if(|&o| o < offset).is_some() {}
+                    if offsets.next_if_eq(&offset).is_some() {
+                        let mut result = result.clone();
+                        result.update_value(bytes_at(&result).to_vec());
+                        out.push(result);
+                    }
+                }
+            }
+            None => out.extend(batch.iter().filter_map(|result| {
+                rescan_bytes(result, bytes_at(result), self.value, self.scan_type, self.value_type, self.endianness)
+            })),
+        }
+    }
 }
 
 /// Re-scans a specific address with filter criteria
@@ -169,13 +789,24 @@ fn rescan_address(
     value: &ScanValue,
     scan_type: ScanType,
     value_type: ValueType,
+    endianness: Endianness,
     memory: &MemoryScanner,
 ) -> Option<ScanResult> {
-    let size = value_type.size();
-
     // Read current value
-    let current_bytes = memory.read_memory(previous.address, size).ok()?;
-    let current_value = ScanValue::from_bytes(&current_bytes, value_type)?;
+    let current_bytes = memory.read_memory(previous.address, value_type.size()).ok()?;
+    rescan_bytes(previous, &current_bytes, value, scan_type, value_type, endianness)
+}
+
+/// Applies the filter criteria to the current bytes of a result
+fn rescan_bytes(
+    previous: &ScanResult,
+    current_bytes: &[u8],
+    value: &ScanValue,
+    scan_type: ScanType,
+    value_type: ValueType,
+    endianness: Endianness,
+) -> Option<ScanResult> {
+    let current_value = ScanValue::from_bytes_endian(current_bytes, value_type, endianness)?;
     let previous_value = ScanValue::from_bytes(previous.get_current_value(), value_type)?;
 
     let matches = match scan_type {
@@ -186,12 +817,18 @@ fn rescan_address(
         ScanType::Decreased => current_value.as_f64() < previous_value.as_f64(),
         ScanType::Changed => current_value != previous_value,
         ScanType::Unchanged => current_value == previous_value,
+        ScanType::IncreasedByPercentage(_)
+        | ScanType::DecreasedByPercentage(_)
+        | ScanType::IncreasedByExactly(_)
+        | ScanType::DecreasedByExactly(_) => {
+            FilterEngine::matches(&current_value, Some(&previous_value), None, scan_type)
+        }
         ScanType::Unknown => true,
     };
 
     if matches {
         let mut result = previous.clone();
-        result.update_value(current_bytes);
+        result.update_value(current_value.to_bytes());
         Some(result)
     } else {
         None
@@ -201,6 +838,8 @@ fn rescan_address(
 #[cfg(test)]
 mod tests {
     use super::*;
+    use std::sync::atomic::AtomicI32;
+    use std::sync::Mutex;
 
     #[test]
     fn test_scanner_creation() {
@@ -211,4 +850,280 @@ mod tests {
         let scanner = Scanner::new(process);
         assert_eq!(scanner.results().len(), 0);
     }
+
+    #[test]
+    fn test_first_scan_cancelled_before_start() {
+        let current_pid = std::process::id();
+        let process = Process::open(current_pid, "self".to_string())
+            .expect("Failed to open current process");
+        let mut scanner = Scanner::new(process);
+
+        let progress_calls = Arc::new(Mutex::new(0usize));
+        let calls = progress_calls.clone();
+        let options = ScanOptions::new(ValueType::I32)
+            .with_progress_callback(Box::new(move |_| *calls.lock().unwrap() += 1));
+        options.cancellation_token.store(true, Ordering::Relaxed);
+
+        let count = scanner
+            .first_scan(&ScanValue::I32(0), ScanType::Exact, &options)
+            .expect("Scan failed");
+
+        assert_eq!(count, 0);
+        assert_eq!(*progress_calls.lock().unwrap(), 0);
+    }
+
+    #[test]
+    fn test_first_scan_stream_reports_matches() {
+        let current_pid = std::process::id();
+        let process = Process::open(current_pid, "self".to_string())
+            .expect("Failed to open current process");
+        let mut scanner = Scanner::new(process);
+
+        let target = Box::new(0x5EED_1234i32);
+        let address = &*target as *const i32 as usize;
+        let options = ScanOptions::new(ValueType::I32).with_address_range(address, address + 4);
+
+        let mut streamed = Vec::new();
+        let count = scanner
+            .first_scan_stream(&ScanValue::I32(*target), ScanType::Exact, &options, |r| {
+                streamed.push(r.address)
+            })
+            .expect("Scan failed");
+
+        assert_eq!(count, 1);
+        assert_eq!(streamed, vec![address]);
+        assert!(scanner.results().is_empty());
+    }
+
+    #[test]
+    fn test_first_scan_times_out() {
+        let current_pid = std::process::id();
+        let process = Process::open(current_pid, "self".to_string())
+            .expect("Failed to open current process");
+        let mut scanner = Scanner::new(process);
+
+        let options = ScanOptions::new(ValueType::I32).with_timeout(Duration::ZERO);
+        let error = scanner
+            .first_scan(&ScanValue::I32(0), ScanType::Exact, &options)
+            .expect_err("Scan should time out");
+
+        assert!(matches!(error.downcast_ref::<ScanError>(), Some(ScanError::Timeout { results_found: 0, .. })));
+        assert!(scanner.results().is_empty());
+    }
+
+    #[test]
+    fn test_next_scan_batch_matches_next_scan() {
+        let current_pid = std::process::id();
+        let process = Process::open(current_pid, "self".to_string())
+            .expect("Failed to open current process");
+        let mut scanner = Scanner::new(process);
+        let mut batch_scanner = Scanner::new(
+            Process::open(current_pid, "self".to_string()).expect("Failed to open current process"),
+        );
+
+        // Dense results (batched) plus a few sparse ones elsewhere (per address)
+        let mut values = vec![7i32; 64];
+        let sparse = Box::new([7i32; 3]);
+        let mut results = ScanResults::new(ValueType::I32);
+        for value in values.iter().chain(sparse.iter()) {
+            results.add_result(ScanResult::new(value as *const i32 as usize, 7i32.to_le_bytes().to_vec()));
+        }
+        for index in [3, 10, 40] {
+            values[index] = 8;
+        }
+        // The writes are only observed through ReadProcessMemory
+        std::hint::black_box(&mut values);
+
+        for (value, scan_type) in [
+            (ScanValue::I32(7), ScanType::Exact),
+            (ScanValue::I32(7), ScanType::GreaterThan),
+            (ScanValue::I32(0), ScanType::Changed),
+            (ScanValue::I32(0), ScanType::Unchanged),
+        ] {
+            *scanner.results_mut() = results.clone();
+            *batch_scanner.results_mut() = results.clone();
+            scanner.next_scan(&value, scan_type).expect("Scan failed");
+            batch_scanner.next_scan_batch(&value, scan_type).expect("Batch scan failed");
+
+            let mut expected: Vec<usize> = scanner.results().results.iter().map(|r| r.address).collect();
+            expected.sort_unstable();
+            let actual: Vec<usize> = batch_scanner.results().results.iter().map(|r| r.address).collect();
+            assert_eq!(actual, expected, "{:?}", scan_type);
+        }
+        assert_eq!(batch_scanner.results().len(), 64);
+        drop(values);
+    }
+
+    #[test]
+    fn test_replaced_results_reset_next_scan_options() {
+        let current_pid = std::process::id();
+        let process = Process::open(current_pid, "self".to_string())
+            .expect("Failed to open current process");
+        let mut scanner = Scanner::new(process);
+
+        let options = ScanOptions::new(ValueType::I32).heap_only();
+        scanner.set_first_scan_results(ScanResults::new(ValueType::I32), &options);
+        assert!(scanner.next_scan_options.heap_only);
+
+        // Results restored from a session may come from any region
+        *scanner.results_mut() = ScanResults::new(ValueType::I32);
+        assert!(!scanner.next_scan_options.heap_only);
+        assert_eq!(scanner.next_scan_options.region_types, RegionTypeFlags::all());
+    }
+
+    #[test]
+    fn test_next_scan_skips_read_only_pages() {
+        static READ_ONLY_VALUE: i32 = 7;
+
+        let current_pid = std::process::id();
+        let process = Process::open(current_pid, "self".to_string())
+            .expect("Failed to open current process");
+        let mut scanner = Scanner::new(process);
+
+        let writable = Box::new(7i32);
+        let read_only = std::hint::black_box(&READ_ONLY_VALUE) as *const i32 as usize;
+        let results = &mut scanner.results_mut().results;
+        results.push(ScanResult::new(&*writable as *const i32 as usize, 7i32.to_le_bytes().to_vec()));
+        results.push(ScanResult::new(read_only, 7i32.to_le_bytes().to_vec()));
+
+        scanner
+            .next_scan(&ScanValue::I32(0), ScanType::Unchanged)
+            .expect("Scan failed");
+
+        let addresses: Vec<usize> = scanner.results().results.iter().map(|r| r.address).collect();
+        assert_eq!(addresses, vec![&*writable as *const i32 as usize]);
+    }
+
+    #[test]
+    fn test_prune_unknown_results() {
+        let current_pid = std::process::id();
+        let process = Process::open(current_pid, "self".to_string())
+            .expect("Failed to open current process");
+        let mut scanner = Scanner::new(process);
+
+        // 95 zero-fills and 5 distinct values
+        let results = &mut scanner.results_mut().results;
+        for address in 0..95 {
+            results.push(ScanResult::new(address * 4, 0i32.to_le_bytes().to_vec()));
+        }
+        for value in 1..=5i32 {
+            results.push(ScanResult::new(0x1000 + value as usize * 4, value.to_le_bytes().to_vec()));
+        }
+
+        assert_eq!(scanner.prune_unknown_results(1.0), 95);
+        assert_eq!(scanner.results().len(), 5);
+        assert!(scanner
+            .results()
+            .results
+            .iter()
+            .all(|r| r.parse_value(ValueType::I32) != Some(ScanValue::I32(0))));
+
+        // Every remaining value is held by 20% of the results
+        assert_eq!(scanner.prune_unknown_results(50.0), 0);
+    }
+
+    #[test]
+    fn test_simd_dispatch_matches_scalar_path() {
+        // Pseudo-random bytes with planted targets at aligned and unaligned offsets
+        let mut data: Vec<u8> = (0..4096u32).map(|i| (i.wrapping_mul(2654435761) >> 13) as u8).collect();
+        for offset in [0, 64, 1000, 2052, 4088] {
+            data[offset..offset + 4].copy_from_slice(&1234567i32.to_le_bytes());
+        }
+        data[3000..3008].copy_from_slice(&0xDEAD_BEEF_CAFEu64.to_le_bytes());
+        data[3100..3104].copy_from_slice(&1.5f32.to_le_bytes());
+
+        let cases = [
+            (ScanValue::I32(1234567), ScanType::Exact, 4),
+            (ScanValue::I32(1234567), ScanType::Exact, 8),
+            (ScanValue::U32(1234567), ScanType::Exact, 4),
+            (ScanValue::F32(1.5), ScanType::Exact, 4),
+            (ScanValue::U64(0xDEAD_BEEF_CAFE), ScanType::Exact, 4),
+            (ScanValue::I64(0xDEAD_BEEF_CAFE), ScanType::Exact, 8),
+            (ScanValue::I32(0), ScanType::GreaterThan, 4),
+            (ScanValue::F32(0.0), ScanType::LessThan, 4),
+        ];
+
+        for (value, scan_type, alignment) in cases {
+            let options = ScanOptions::new(value.value_type()).with_alignment(alignment);
+
+            let simd_offsets = simd_scan_offsets(&data, 0, &value, scan_type, &options)
+                .unwrap_or_else(|| panic!("No SIMD path for {:?} {:?}", value, scan_type));
+            let mut scalar_offsets = Vec::new();
+            scalar_scan_buffer(&data, 0, &value, scan_type, &options, &mut |r| {
+                scalar_offsets.push(r.address)
+            });
+
+            assert_eq!(simd_offsets, scalar_offsets, "{:?} {:?} alignment {}", value, scan_type, alignment);
+        }
+    }
+
+    #[test]
+    fn test_first_scan_big_endian() {
+        let current_pid = std::process::id();
+        let process = Process::open(current_pid, "self".to_string())
+            .expect("Failed to open current process");
+        let mut scanner = Scanner::new(process);
+
+        // 100 stored big-endian
+        let target = Box::new([0x00u8, 0x00, 0x00, 0x64]);
+        let address = target.as_ptr() as usize;
+        let options = ScanOptions::new(ValueType::I32)
+            .with_alignment(1)
+            .with_address_range(address, address + 4)
+            .with_endianness(Endianness::Big);
+
+        let count = scanner
+            .first_scan(&ScanValue::I32(100), ScanType::Exact, &options)
+            .expect("Scan failed");
+
+        assert_eq!(count, 1);
+        let result = &scanner.results().results[0];
+        assert_eq!(result.address, address);
+        assert_eq!(result.parse_value(ValueType::I32), Some(ScanValue::I32(100)));
+    }
+
+    #[test]
+    fn test_freeze_value_until_dropped() {
+        let current_pid = std::process::id();
+        let process = Process::open(current_pid, "self".to_string())
+            .expect("Failed to open current process");
+        let scanner = Scanner::new(process);
+
+        let target = Box::new(AtomicI32::new(0));
+        let address = target.as_ptr() as usize;
+
+        let handle = scanner
+            .freeze_value(address, ScanValue::I32(7))
+            .expect("Failed to freeze value");
+        // The lock owns its own process handle and keeps working without the scanner
+        drop(scanner);
+        target.store(0, Ordering::SeqCst);
+        std::thread::sleep(FREEZE_VALUE_INTERVAL * 4);
+        assert_eq!(target.load(Ordering::SeqCst), 7);
+        assert_eq!(handle.value(), &ScanValue::I32(7));
+
+        // Once the handle is dropped nothing writes the value back
+        drop(handle);
+        target.store(1, Ordering::SeqCst);
+        std::thread::sleep(FREEZE_VALUE_INTERVAL * 4);
+        assert_eq!(target.load(Ordering::SeqCst), 1);
+    }
+
+    #[test]
+    fn test_freeze_value_big_endian() {
+        let current_pid = std::process::id();
+        let process = Process::open(current_pid, "self".to_string())
+            .expect("Failed to open current process");
+        let scanner = Scanner::new(process);
+
+        let target = Box::new(AtomicI32::new(0));
+        let address = target.as_ptr() as usize;
+
+        let handle = scanner
+            .freeze_value_endian(address, ScanValue::I32(0x01020304), Endianness::Big)
+            .expect("Failed to freeze value");
+        std::thread::sleep(FREEZE_VALUE_INTERVAL * 4);
+        assert_eq!(target.load(Ordering::SeqCst).to_ne_bytes(), [0x01, 0x02, 0x03, 0x04]);
+        drop(handle);
+    }
 }
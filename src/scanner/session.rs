@@ -0,0 +1,79 @@
+use crate::scanner::WatchlistEntry;
+use crate::types::ScanResults;
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+
+/// A saved scan session (results + watchlist)
+///
+/// The process is not restored because its PID may differ between runs.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct ScanSession {
+    pub scan_results: ScanResults,
+    pub watchlist: Vec<WatchlistEntry>,
+    pub process_name: String,
+    /// Seconds since the UNIX epoch when the session was saved
+    pub timestamp: u64,
+}
+
+impl ScanSession {
+    pub fn new(scan_results: ScanResults, watchlist: Vec<WatchlistEntry>, process_name: String) -> Self {
+        let timestamp = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map(|d| d.as_secs())
+            .unwrap_or(0);
+
+        Self {
+            scan_results,
+            watchlist,
+            process_name,
+            timestamp,
+        }
+    }
+}
+
+/// Saves a session as JSON
+pub fn save_session(session: &ScanSession, path: &Path) -> Result<()> {
+    let json = serde_json::to_string_pretty(session)?;
+    std::fs::write(path, json)?;
+    Ok(())
+}
+
+/// Loads a session from a JSON file
+pub fn load_session(path: &Path) -> Result<ScanSession> {
+    let json = std::fs::read_to_string(path)?;
+    Ok(serde_json::from_str(&json)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{ScanResult, ScanValue, ValueType};
+
+    #[test]
+    fn test_session_roundtrip() {
+        let mut results = ScanResults::new(ValueType::I32);
+        let mut result = ScanResult::new(0x1000, 100i32.to_le_bytes().to_vec());
+        result.label = Some("Gold".to_string());
+        results.add_result(result);
+
+        let mut entry = WatchlistEntry::new(0x1000, ValueType::I32);
+        entry.label = "Health".to_string();
+        entry.frozen_value = Some(ScanValue::I32(100));
+
+        let session = ScanSession::new(results, vec![entry], "game.exe".to_string());
+
+        let path = std::env::temp_dir().join(format!("lightscan_session_{}.json", std::process::id()));
+        save_session(&session, &path).expect("Failed to save session");
+        let loaded = load_session(&path).expect("Failed to load session");
+        let _ = std::fs::remove_file(&path);
+
+        assert_eq!(loaded.process_name, "game.exe");
+        assert_eq!(loaded.scan_results.len(), 1);
+        assert_eq!(loaded.scan_results.results[0].address, 0x1000);
+        assert_eq!(loaded.scan_results.results[0].label.as_deref(), Some("Gold"));
+        assert_eq!(loaded.watchlist.len(), 1);
+        assert_eq!(loaded.watchlist[0].label, "Health");
+        assert_eq!(loaded.watchlist[0].frozen_value, Some(ScanValue::I32(100)));
+    }
+}
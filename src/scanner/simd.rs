@@ -4,6 +4,8 @@
 #[cfg(target_arch = "x86_64")]
 use std::arch::x86_64::*;
 
+use crate::types::{ScanType, ScanValue};
+
 /// SIMD-accelerated scan for i32 values
 #[cfg(target_arch = "x86_64")]
 #[target_feature(enable = "avx2")]
@@ -121,6 +123,300 @@ pub unsafe fn simd_scan_f32_avx2(data: &[u8], target: f32, alignment: usize) ->
     results
 }
 
+/// SSE4.1 scan for i32 values (for CPUs without AVX2)
+///
+/// # Safety
+/// The CPU must support SSE4.1 (check with `is_x86_feature_detected!("sse4.1")`).
+#[cfg(target_arch = "x86_64")]
+#[target_feature(enable = "sse4.1")]
+pub unsafe fn sse41_scan_i32(data: &[u8], target: i32, alignment: usize) -> Vec<usize> {
+    let mut results = Vec::new();
+
+    if data.len() < 16 {
+        return scalar_scan_i32(data, target, alignment);
+    }
+
+    // Load target into all lanes of SSE register (4 x i32)
+    let target_vec = _mm_set1_epi32(target);
+
+    let chunks = data.len() / 16;
+
+    for chunk_idx in 0..chunks {
+        let chunk_offset = chunk_idx * 16;
+
+        let data_vec = _mm_loadu_si128(data.as_ptr().add(chunk_offset) as *const __m128i);
+
+        let cmp_result = _mm_cmpeq_epi32(data_vec, target_vec);
+
+        let mask = _mm_movemask_epi8(cmp_result);
+
+        if mask != 0 {
+            for i in 0..4 {
+                let bit_pos = i * 4;
+                if (mask & (0xF << bit_pos)) != 0 {
+                    let addr = chunk_offset + i * 4;
+                    if addr % alignment == 0 {
+                        results.push(addr);
+                    }
+                }
+            }
+        }
+    }
+
+    // Handle remaining bytes with scalar code
+    let mut offset = chunks * 16;
+    while offset + 4 <= data.len() {
+        if offset.is_multiple_of(alignment) {
+            let value = i32::from_le_bytes([
+                data[offset],
+                data[offset + 1],
+                data[offset + 2],
+                data[offset + 3],
+            ]);
+            if value == target {
+                results.push(offset);
+            }
+        }
+        offset += alignment;
+    }
+
+    results
+}
+
+/// SSE4.1 scan for f32 values (for CPUs without AVX2)
+///
+/// # Safety
+/// The CPU must support SSE4.1 (check with `is_x86_feature_detected!("sse4.1")`).
+#[cfg(target_arch = "x86_64")]
+#[target_feature(enable = "sse4.1")]
+pub unsafe fn sse41_scan_f32(data: &[u8], target: f32, alignment: usize) -> Vec<usize> {
+    let mut results = Vec::new();
+
+    if data.len() < 16 {
+        return scalar_scan_f32(data, target, alignment);
+    }
+
+    let target_vec = _mm_set1_ps(target);
+
+    let chunks = data.len() / 16;
+
+    for chunk_idx in 0..chunks {
+        let chunk_offset = chunk_idx * 16;
+
+        let data_vec = _mm_loadu_ps(data.as_ptr().add(chunk_offset) as *const f32);
+
+        let cmp_result = _mm_cmpeq_ps(data_vec, target_vec);
+
+        let mask = _mm_movemask_ps(cmp_result);
+
+        if mask != 0 {
+            for i in 0..4 {
+                if (mask & (1 << i)) != 0 {
+                    let addr = chunk_offset + i * 4;
+                    if addr % alignment == 0 {
+                        results.push(addr);
+                    }
+                }
+            }
+        }
+    }
+
+    // Handle remaining bytes
+    let mut offset = chunks * 16;
+    while offset + 4 <= data.len() {
+        if offset.is_multiple_of(alignment) {
+            let value = f32::from_le_bytes([
+                data[offset],
+                data[offset + 1],
+                data[offset + 2],
+                data[offset + 3],
+            ]);
+            if value == target {
+                results.push(offset);
+            }
+        }
+        offset += alignment;
+    }
+
+    results
+}
+
+/// SIMD-accelerated greater-than scan for i32 values
+///
+/// # Safety
+/// The CPU must support AVX2 (check with `is_x86_feature_detected!("avx2")`).
+#[cfg(target_arch = "x86_64")]
+#[target_feature(enable = "avx2")]
+pub unsafe fn simd_scan_i32_gt_avx2(data: &[u8], target: i32, alignment: usize) -> Vec<usize> {
+    let mut results = Vec::new();
+    let target_vec = _mm256_set1_epi32(target);
+    let chunks = data.len() / 32;
+
+    for chunk_idx in 0..chunks {
+        let chunk_offset = chunk_idx * 32;
+        let data_vec = _mm256_loadu_si256(data.as_ptr().add(chunk_offset) as *const __m256i);
+        let mask = _mm256_movemask_epi8(_mm256_cmpgt_epi32(data_vec, target_vec));
+
+        if mask != 0 {
+            for i in 0..8 {
+                let addr = chunk_offset + i * 4;
+                if (mask & (0xF << (i * 4))) != 0 && addr.is_multiple_of(alignment) {
+                    results.push(addr);
+                }
+            }
+        }
+    }
+
+    // Handle remaining bytes with scalar code
+    results.extend(scalar_scan_i32_by(data, chunks * 32, alignment, |v| v > target));
+    results
+}
+
+/// SIMD-accelerated less-than scan for i32 values
+///
+/// # Safety
+/// The CPU must support AVX2 (check with `is_x86_feature_detected!("avx2")`).
+#[cfg(target_arch = "x86_64")]
+#[target_feature(enable = "avx2")]
+pub unsafe fn simd_scan_i32_lt_avx2(data: &[u8], target: i32, alignment: usize) -> Vec<usize> {
+    let mut results = Vec::new();
+    let target_vec = _mm256_set1_epi32(target);
+    let chunks = data.len() / 32;
+
+    for chunk_idx in 0..chunks {
+        let chunk_offset = chunk_idx * 32;
+        let data_vec = _mm256_loadu_si256(data.as_ptr().add(chunk_offset) as *const __m256i);
+        let mask = _mm256_movemask_epi8(_mm256_cmpgt_epi32(target_vec, data_vec));
+
+        if mask != 0 {
+            for i in 0..8 {
+                let addr = chunk_offset + i * 4;
+                if (mask & (0xF << (i * 4))) != 0 && addr.is_multiple_of(alignment) {
+                    results.push(addr);
+                }
+            }
+        }
+    }
+
+    // Handle remaining bytes with scalar code
+    results.extend(scalar_scan_i32_by(data, chunks * 32, alignment, |v| v < target));
+    results
+}
+
+/// SIMD-accelerated greater-than scan for f32 values
+///
+/// # Safety
+/// The CPU must support AVX2 (check with `is_x86_feature_detected!("avx2")`).
+#[cfg(target_arch = "x86_64")]
+#[target_feature(enable = "avx2")]
+pub unsafe fn simd_scan_f32_gt_avx2(data: &[u8], target: f32, alignment: usize) -> Vec<usize> {
+    let mut results = Vec::new();
+    let target_vec = _mm256_set1_ps(target);
+    let chunks = data.len() / 32;
+
+    for chunk_idx in 0..chunks {
+        let chunk_offset = chunk_idx * 32;
+        let data_vec = _mm256_loadu_ps(data.as_ptr().add(chunk_offset) as *const f32);
+        let mask = _mm256_movemask_ps(_mm256_cmp_ps(data_vec, target_vec, _CMP_GT_OQ));
+
+        if mask != 0 {
+            for i in 0..8 {
+                let addr = chunk_offset + i * 4;
+                if (mask & (1 << i)) != 0 && addr.is_multiple_of(alignment) {
+                    results.push(addr);
+                }
+            }
+        }
+    }
+
+    // Handle remaining bytes with scalar code
+    results.extend(scalar_scan_f32_by(data, chunks * 32, alignment, |v| v > target));
+    results
+}
+
+/// SIMD-accelerated less-than scan for f32 values
+///
+/// # Safety
+/// The CPU must support AVX2 (check with `is_x86_feature_detected!("avx2")`).
+#[cfg(target_arch = "x86_64")]
+#[target_feature(enable = "avx2")]
+pub unsafe fn simd_scan_f32_lt_avx2(data: &[u8], target: f32, alignment: usize) -> Vec<usize> {
+    let mut results = Vec::new();
+    let target_vec = _mm256_set1_ps(target);
+    let chunks = data.len() / 32;
+
+    for chunk_idx in 0..chunks {
+        let chunk_offset = chunk_idx * 32;
+        let data_vec = _mm256_loadu_ps(data.as_ptr().add(chunk_offset) as *const f32);
+        let mask = _mm256_movemask_ps(_mm256_cmp_ps(data_vec, target_vec, _CMP_LT_OQ));
+
+        if mask != 0 {
+            for i in 0..8 {
+                let addr = chunk_offset + i * 4;
+                if (mask & (1 << i)) != 0 && addr.is_multiple_of(alignment) {
+                    results.push(addr);
+                }
+            }
+        }
+    }
+
+    // Handle remaining bytes with scalar code
+    results.extend(scalar_scan_f32_by(data, chunks * 32, alignment, |v| v < target));
+    results
+}
+
+/// SIMD-accelerated scan for u64 values (4 lanes per iteration)
+///
+/// Only positions that are multiples of 8 are compared in lanes, so
+/// `alignment` should be a multiple of 8; use `scan_u64` for other alignments.
+///
+/// # Safety
+/// The CPU must support AVX2 (check with `is_x86_feature_detected!("avx2")`).
+#[cfg(target_arch = "x86_64")]
+#[target_feature(enable = "avx2")]
+pub unsafe fn simd_scan_u64_avx2(data: &[u8], target: u64, alignment: usize) -> Vec<usize> {
+    let mut results = Vec::new();
+
+    if data.len() < 32 {
+        return scalar_scan_u64(data, target, alignment);
+    }
+
+    // Load target into all lanes of AVX2 register (4 x u64)
+    let target_vec = _mm256_set1_epi64x(target as i64);
+
+    let chunks = data.len() / 32;
+
+    for chunk_idx in 0..chunks {
+        let chunk_offset = chunk_idx * 32;
+
+        let data_vec = _mm256_loadu_si256(data.as_ptr().add(chunk_offset) as *const __m256i);
+        let mask = _mm256_movemask_epi8(_mm256_cmpeq_epi64(data_vec, target_vec));
+
+        if mask != 0 {
+            // Each matching lane sets 8 mask bits
+            for i in 0..4 {
+                if (mask >> (i * 8)) & 0xFF != 0 {
+                    let addr = chunk_offset + i * 8;
+                    if addr.is_multiple_of(alignment) {
+                        results.push(addr);
+                    }
+                }
+            }
+        }
+    }
+
+    // Handle remaining bytes with scalar code
+    let mut offset = (chunks * 32).next_multiple_of(alignment);
+    while offset + 8 <= data.len() {
+        if u64::from_le_bytes(data[offset..offset + 8].try_into().unwrap()) == target {
+            results.push(offset);
+        }
+        offset += alignment;
+    }
+
+    results
+}
+
 /// Scalar fallback for i32 scanning
 pub fn scalar_scan_i32(data: &[u8], target: i32, alignment: usize) -> Vec<usize> {
     let mut results = Vec::new();
@@ -167,13 +463,62 @@ pub fn scalar_scan_f32(data: &[u8], target: f32, alignment: usize) -> Vec<usize>
     results
 }
 
+/// Scalar fallback for u64 scanning
+pub fn scalar_scan_u64(data: &[u8], target: u64, alignment: usize) -> Vec<usize> {
+    let mut results = Vec::new();
+    let mut offset = 0;
+
+    while offset + 8 <= data.len() {
+        if u64::from_le_bytes(data[offset..offset + 8].try_into().unwrap()) == target {
+            results.push(offset);
+        }
+        offset += alignment;
+    }
+
+    results
+}
+
+/// Scalar i32 scan from `start` using an arbitrary predicate
+fn scalar_scan_i32_by(data: &[u8], start: usize, alignment: usize, pred: impl Fn(i32) -> bool) -> Vec<usize> {
+    let mut results = Vec::new();
+    let mut offset = start.next_multiple_of(alignment);
+
+    while offset + 4 <= data.len() {
+        let value = i32::from_le_bytes(data[offset..offset + 4].try_into().unwrap());
+        if pred(value) {
+            results.push(offset);
+        }
+        offset += alignment;
+    }
+
+    results
+}
+
+/// Scalar f32 scan from `start` using an arbitrary predicate
+fn scalar_scan_f32_by(data: &[u8], start: usize, alignment: usize, pred: impl Fn(f32) -> bool) -> Vec<usize> {
+    let mut results = Vec::new();
+    let mut offset = start.next_multiple_of(alignment);
+
+    while offset + 4 <= data.len() {
+        let value = f32::from_le_bytes(data[offset..offset + 4].try_into().unwrap());
+        if pred(value) {
+            results.push(offset);
+        }
+        offset += alignment;
+    }
+
+    results
+}
+
 /// Auto-dispatching SIMD scan for i32
-/// Automatically uses AVX2 if available, falls back to scalar
+/// Automatically uses AVX2 or SSE4.1 if available, falls back to scalar
 pub fn scan_i32(data: &[u8], target: i32, alignment: usize) -> Vec<usize> {
     #[cfg(target_arch = "x86_64")]
     {
         if is_x86_feature_detected!("avx2") {
             unsafe { simd_scan_i32_avx2(data, target, alignment) }
+        } else if is_x86_feature_detected!("sse4.1") {
+            unsafe { sse41_scan_i32(data, target, alignment) }
         } else {
             scalar_scan_i32(data, target, alignment)
         }
@@ -191,6 +536,8 @@ pub fn scan_f32(data: &[u8], target: f32, alignment: usize) -> Vec<usize> {
     {
         if is_x86_feature_detected!("avx2") {
             unsafe { simd_scan_f32_avx2(data, target, alignment) }
+        } else if is_x86_feature_detected!("sse4.1") {
+            unsafe { sse41_scan_f32(data, target, alignment) }
         } else {
             scalar_scan_f32(data, target, alignment)
         }
@@ -202,6 +549,158 @@ pub fn scan_f32(data: &[u8], target: f32, alignment: usize) -> Vec<usize> {
     }
 }
 
+/// Auto-dispatching SIMD scan for u64
+///
+/// Uses AVX2 when available and `alignment` is a multiple of 8, otherwise scalar.
+pub fn scan_u64(data: &[u8], target: u64, alignment: usize) -> Vec<usize> {
+    #[cfg(target_arch = "x86_64")]
+    {
+        if alignment.is_multiple_of(8) && is_x86_feature_detected!("avx2") {
+            return unsafe { simd_scan_u64_avx2(data, target, alignment) };
+        }
+    }
+
+    scalar_scan_u64(data, target, alignment)
+}
+
+/// Dispatching i32 scan for Exact, GreaterThan and LessThan
+///
+/// Other scan types fall back to the scalar path with ScanValue semantics.
+pub fn scan_i32_with_type(data: &[u8], target: i32, scan_type: ScanType, alignment: usize) -> Vec<usize> {
+    #[cfg(target_arch = "x86_64")]
+    {
+        if is_x86_feature_detected!("avx2") {
+            match scan_type {
+                ScanType::GreaterThan => return unsafe { simd_scan_i32_gt_avx2(data, target, alignment) },
+                ScanType::LessThan => return unsafe { simd_scan_i32_lt_avx2(data, target, alignment) },
+                _ => {}
+            }
+        }
+    }
+
+    match scan_type {
+        ScanType::Exact => scan_i32(data, target, alignment),
+        ScanType::GreaterThan => scalar_scan_i32_by(data, 0, alignment, |v| v > target),
+        ScanType::LessThan => scalar_scan_i32_by(data, 0, alignment, |v| v < target),
+        _ => {
+            let target = ScanValue::I32(target);
+            scalar_scan_i32_by(data, 0, alignment, |v| ScanValue::I32(v).compare(&target, scan_type))
+        }
+    }
+}
+
+/// Dispatching f32 scan for Exact, GreaterThan and LessThan
+///
+/// Other scan types fall back to the scalar path with ScanValue semantics.
+pub fn scan_f32_with_type(data: &[u8], target: f32, scan_type: ScanType, alignment: usize) -> Vec<usize> {
+    #[cfg(target_arch = "x86_64")]
+    {
+        if is_x86_feature_detected!("avx2") {
+            match scan_type {
+                ScanType::GreaterThan => return unsafe { simd_scan_f32_gt_avx2(data, target, alignment) },
+                ScanType::LessThan => return unsafe { simd_scan_f32_lt_avx2(data, target, alignment) },
+                _ => {}
+            }
+        }
+    }
+
+    match scan_type {
+        ScanType::Exact => scan_f32(data, target, alignment),
+        ScanType::GreaterThan => scalar_scan_f32_by(data, 0, alignment, |v| v > target),
+        ScanType::LessThan => scalar_scan_f32_by(data, 0, alignment, |v| v < target),
+        _ => {
+            let target = ScanValue::F32(target);
+            scalar_scan_f32_by(data, 0, alignment, |v| ScanValue::F32(v).compare(&target, scan_type))
+        }
+    }
+}
+
+/// AVX2 array-of-bytes scan with a wildcard mask
+///
+/// The first 32 bytes of the pattern are compared in one `__m256i` per position;
+/// longer patterns verify the remaining bytes with scalar code.
+///
+/// # Safety
+/// The CPU must support AVX2 (check with `is_x86_feature_detected!("avx2")`).
+/// `pattern` and `mask` must have the same non-zero length.
+#[cfg(target_arch = "x86_64")]
+#[target_feature(enable = "avx2")]
+pub unsafe fn simd_aob_scan_avx2(data: &[u8], pattern: &[u8], mask: &[u8]) -> Vec<usize> {
+    let mut results = Vec::new();
+    if data.len() < pattern.len() {
+        return results;
+    }
+
+    // Pad pattern and mask to 32 bytes; padded lanes have mask 0x00 and always match
+    let head = pattern.len().min(32);
+    let mut padded_pattern = [0u8; 32];
+    let mut padded_mask = [0u8; 32];
+    for i in 0..head {
+        padded_mask[i] = mask[i];
+        padded_pattern[i] = pattern[i] & mask[i];
+    }
+    let pattern_vec = _mm256_loadu_si256(padded_pattern.as_ptr() as *const __m256i);
+    let mask_vec = _mm256_loadu_si256(padded_mask.as_ptr() as *const __m256i);
+
+    let last = data.len() - pattern.len();
+    let simd_end = if data.len() >= 32 { (data.len() - 32).min(last) + 1 } else { 0 };
+
+    for offset in 0..simd_end {
+        let data_vec = _mm256_loadu_si256(data.as_ptr().add(offset) as *const __m256i);
+        let masked = _mm256_and_si256(data_vec, mask_vec);
+        let eq = _mm256_movemask_epi8(_mm256_cmpeq_epi8(masked, pattern_vec));
+
+        if eq == -1 && aob_matches_at(data, pattern, mask, offset, head) {
+            results.push(offset);
+        }
+    }
+
+    // Positions too close to the end for a full 32-byte load
+    for offset in simd_end..=last {
+        if aob_matches_at(data, pattern, mask, offset, 0) {
+            results.push(offset);
+        }
+    }
+
+    results
+}
+
+/// Scalar fallback for array-of-bytes scanning
+pub fn scalar_aob_scan(data: &[u8], pattern: &[u8], mask: &[u8]) -> Vec<usize> {
+    if pattern.is_empty() || data.len() < pattern.len() {
+        return Vec::new();
+    }
+
+    (0..=data.len() - pattern.len())
+        .filter(|&offset| aob_matches_at(data, pattern, mask, offset, 0))
+        .collect()
+}
+
+/// Compares pattern[from..] against data at offset, skipping wildcard (0x00 mask) bytes
+fn aob_matches_at(data: &[u8], pattern: &[u8], mask: &[u8], offset: usize, from: usize) -> bool {
+    (from..pattern.len()).all(|i| (data[offset + i] ^ pattern[i]) & mask[i] == 0)
+}
+
+/// Auto-dispatching array-of-bytes scan
+///
+/// `mask[i] = 0xFF` means the byte must match, `0x00` means wildcard.
+/// Returns the offsets of every match in `data`.
+pub fn simd_aob_scan(data: &[u8], pattern: &[u8], mask: &[u8]) -> Vec<usize> {
+    assert_eq!(pattern.len(), mask.len(), "pattern and mask length differ");
+    if pattern.is_empty() {
+        return Vec::new();
+    }
+
+    #[cfg(target_arch = "x86_64")]
+    {
+        if is_x86_feature_detected!("avx2") {
+            return unsafe { simd_aob_scan_avx2(data, pattern, mask) };
+        }
+    }
+
+    scalar_aob_scan(data, pattern, mask)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -238,4 +737,84 @@ mod tests {
         assert!(results.contains(&0));
         assert!(results.contains(&256));
     }
+
+    #[test]
+    #[cfg(target_arch = "x86_64")]
+    fn test_sse41_scan_matches_scalar() {
+        if !is_x86_feature_detected!("sse4.1") {
+            return;
+        }
+
+        let mut data = vec![0u8; 1030];
+        for offset in [0, 16, 260, 1024] {
+            data[offset..offset + 4].copy_from_slice(&7i32.to_le_bytes());
+        }
+        data[512..516].copy_from_slice(&1.5f32.to_le_bytes());
+
+        assert_eq!(unsafe { sse41_scan_i32(&data, 7, 4) }, scalar_scan_i32(&data, 7, 4));
+        assert_eq!(unsafe { sse41_scan_f32(&data, 1.5, 4) }, scalar_scan_f32(&data, 1.5, 4));
+    }
+
+    #[test]
+    fn test_scan_u64_dispatcher() {
+        let target = 0x7FF6_1234_5678u64;
+        let mut data = vec![0u8; 1036];
+        for offset in [0, 40, 1024] {
+            data[offset..offset + 8].copy_from_slice(&target.to_le_bytes());
+        }
+        // Only found with 4-byte alignment
+        data[508..516].copy_from_slice(&target.to_le_bytes());
+
+        assert_eq!(scalar_scan_u64(&data, target, 8), vec![0, 40, 1024]);
+        assert_eq!(scan_u64(&data, target, 8), vec![0, 40, 1024]);
+        assert_eq!(scan_u64(&data, target, 4), vec![0, 40, 508, 1024]);
+    }
+
+    #[test]
+    fn test_aob_scan_with_wildcards() {
+        let pattern = [0x48, 0x8B, 0x05, 0x00, 0x00, 0x00, 0x00, 0x48, 0x85, 0xC0];
+        let mask = [0xFF, 0xFF, 0xFF, 0x00, 0x00, 0x00, 0x00, 0xFF, 0xFF, 0xFF];
+
+        let mut data = vec![0x90u8; 300];
+        for (offset, displacement) in [(3usize, 0x11223344u32), (100, 0xDEADBEEF), (290, 0)] {
+            data[offset..offset + 3].copy_from_slice(&pattern[..3]);
+            data[offset + 3..offset + 7].copy_from_slice(&displacement.to_le_bytes());
+            data[offset + 7..offset + 10].copy_from_slice(&pattern[7..]);
+        }
+        // Near miss: last byte differs
+        data[200..210].copy_from_slice(&pattern);
+        data[209] = 0xC1;
+
+        let expected = vec![3, 100, 290];
+        assert_eq!(scalar_aob_scan(&data, &pattern, &mask), expected);
+        assert_eq!(simd_aob_scan(&data, &pattern, &mask), expected);
+
+        // Patterns longer than one vector are verified past the first 32 bytes
+        let long: Vec<u8> = (0..40).collect();
+        let long_mask = vec![0xFF; 40];
+        let mut data = vec![0u8; 128];
+        data[50..90].copy_from_slice(&long);
+        assert_eq!(simd_aob_scan(&data, &long, &long_mask), vec![50]);
+        assert!(simd_aob_scan(&data[..60], &long, &long_mask).is_empty());
+    }
+
+    #[test]
+    fn test_scan_with_type_gt_lt() {
+        let mut data = vec![0u8; 200];
+        for (i, value) in [-5i32, 3, 10, 42, 7].iter().enumerate() {
+            data[i * 36..i * 36 + 4].copy_from_slice(&value.to_le_bytes());
+        }
+
+        let expected_gt = scalar_scan_i32_by(&data, 0, 4, |v| v > 5);
+        let expected_lt = scalar_scan_i32_by(&data, 0, 4, |v| v < 5);
+        assert_eq!(expected_gt, vec![72, 108, 144]);
+        assert_eq!(scan_i32_with_type(&data, 5, ScanType::GreaterThan, 4), expected_gt);
+        assert_eq!(scan_i32_with_type(&data, 5, ScanType::LessThan, 4), expected_lt);
+
+        let mut floats = vec![0u8; 100];
+        floats[8..12].copy_from_slice(&2.5f32.to_le_bytes());
+        floats[64..68].copy_from_slice(&(-1.0f32).to_le_bytes());
+        assert_eq!(scan_f32_with_type(&floats, 1.0, ScanType::GreaterThan, 4), vec![8]);
+        assert_eq!(scan_f32_with_type(&floats, 0.0, ScanType::LessThan, 4), vec![64]);
+    }
 }
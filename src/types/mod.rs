@@ -1,5 +1,6 @@
 pub mod value;
 pub mod scan_result;
 
-pub use value::{ValueType, ScanValue, ScanType};
-pub use scan_result::{ScanResult, ScanResults, ScanOptions};
+pub use value::{Endianness, StringEncoding, ValueType, ScanValue, ScanType};
+pub use scan_result::{
+    ProgressCallback, RegionTypeFlags, ScanOptions, ScanProgress, ScanResult, ScanResults};
@@ -1,5 +1,14 @@
-use super::{ScanValue, ValueType};
+use super::{Endianness, ScanValue, ValueType};
+use bitflags::bitflags;
 use serde::{Deserialize, Serialize};
+use std::collections::{HashSet, VecDeque};
+use std::fmt;
+use std::sync::atomic::AtomicBool;
+use std::sync::Arc;
+use std::time::Duration;
+
+/// Maximum number of past values kept per result
+pub const VALUE_HISTORY_LEN: usize = 8;
 
 /// Represents a single scan result - an address and its value
 #[derive(Clone, Debug, Serialize, Deserialize)]
@@ -7,6 +16,12 @@ pub struct ScanResult {
     pub address: usize,
     pub previous_value: Vec<u8>,
     pub current_value: Option<Vec<u8>>,
+    /// Past values (oldest first), capped at VALUE_HISTORY_LEN
+    #[serde(default)]
+    pub value_history: VecDeque<Vec<u8>>,
+    /// User annotation such as "Health"
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub label: Option<String>,
 }
 
 impl ScanResult {
@@ -15,6 +30,8 @@ impl ScanResult {
             address,
             previous_value: value.clone(),
             current_value: Some(value),
+            value_history: VecDeque::new(),
+            label: None,
         }
     }
 
@@ -24,9 +41,23 @@ impl ScanResult {
 
     pub fn update_value(&mut self, new_value: Vec<u8>) {
         self.previous_value = self.current_value.take().unwrap_or(self.previous_value.clone());
+
+        if self.value_history.len() >= VALUE_HISTORY_LEN {
+            self.value_history.pop_front();
+        }
+        self.value_history.push_back(self.previous_value.clone());
+
         self.current_value = Some(new_value);
     }
 
+    /// Decodes the value history (oldest first)
+    pub fn get_history(&self, value_type: ValueType) -> Vec<ScanValue> {
+        self.value_history
+            .iter()
+            .filter_map(|bytes| ScanValue::from_bytes(bytes, value_type))
+            .collect()
+    }
+
     /// Parses the current value as a ScanValue of the given type
     pub fn parse_value(&self, value_type: ValueType) -> Option<ScanValue> {
         ScanValue::from_bytes(self.get_current_value(), value_type)
@@ -39,6 +70,9 @@ pub struct ScanResults {
     pub results: Vec<ScanResult>,
     pub value_type: ValueType,
     pub scan_count: u32,
+    /// Byte order the values were scanned with; stored bytes are always little-endian
+    #[serde(default)]
+    pub endianness: Endianness,
 }
 
 impl ScanResults {
@@ -47,6 +81,7 @@ impl ScanResults {
             results: Vec::new(),
             value_type,
             scan_count: 0,
+            endianness: Endianness::Little,
         }
     }
 
@@ -71,6 +106,105 @@ impl ScanResults {
         self.scan_count += 1;
     }
 
+    /// Sorts results by address and drops duplicate addresses
+    ///
+    /// Overlapping regions can report the same address twice; the result
+    /// recorded last (with the most recent value) is kept.
+    pub fn deduplicate(&mut self) {
+        // Stable sort keeps duplicates in scan order, so reversing puts the newest first
+        self.results.sort_by_key(|r| r.address);
+        self.results.reverse();
+        self.results.dedup_by_key(|r| r.address);
+        self.results.reverse();
+    }
+
+    /// Removes results whose address is not a multiple of `alignment`
+    ///
+    /// Returns the number of removed results.
+    pub fn filter_by_alignment(&mut self, alignment: usize) -> usize {
+        if alignment <= 1 {
+            return 0;
+        }
+
+        let before = self.results.len();
+        self.results.retain(|r| r.address % alignment == 0);
+        before - self.results.len()
+    }
+
+    /// Results whose address is also present in `other` (AND-combination)
+    ///
+    /// The entries, value type and byte order of `self` are kept even when
+    /// `other` was scanned as a different type.
+    pub fn intersect(&self, other: &ScanResults) -> ScanResults {
+        let addresses: HashSet<usize> = other.results.iter().map(|r| r.address).collect();
+        ScanResults {
+            results: self
+                .results
+                .iter()
+                .filter(|r| addresses.contains(&r.address))
+                .cloned()
+                .collect(),
+            ..self.clone_metadata()
+        }
+    }
+
+    /// Results present in either set (OR-combination)
+    ///
+    /// Entries of `self` win for addresses present in both; the results of
+    /// `other` are appended in their original order. The value type and byte
+    /// order of `self` are kept.
+    pub fn union(&self, other: &ScanResults) -> ScanResults {
+        let addresses: HashSet<usize> = self.results.iter().map(|r| r.address).collect();
+        let mut results = self.results.clone();
+        results.extend(other.results.iter().filter(|r| !addresses.contains(&r.address)).cloned());
+        ScanResults {
+            results,
+            ..self.clone_metadata()
+        }
+    }
+
+    /// An empty set with the value type, scan count and byte order of `self`
+    fn clone_metadata(&self) -> ScanResults {
+        ScanResults {
+            results: Vec::new(),
+            value_type: self.value_type,
+            scan_count: self.scan_count,
+            endianness: self.endianness,
+        }
+    }
+
+    /// Exports results as RFC 4180 CSV with an `address,value,previous_value,label` header
+    ///
+    /// Every field is quoted and records end with CRLF.
+    pub fn to_csv(&self) -> String {
+        let format_value = |bytes: &[u8]| {
+            ScanValue::from_bytes(bytes, self.value_type)
+                .map(|v| v.to_string())
+                .unwrap_or_default()
+        };
+
+        let mut csv = csv_record(&["address", "value", "previous_value", "label"]);
+        for result in &self.results {
+            csv.push_str(&csv_record(&[
+                &format!("0x{:X}", result.address),
+                &format_value(result.get_current_value()),
+                &format_value(&result.previous_value),
+                result.label.as_deref().unwrap_or_default(),
+            ]));
+        }
+        csv
+    }
+
+    /// Exports results as pretty-printed JSON
+    pub fn to_json(&self) -> serde_json::Result<String> {
+        serde_json::to_string_pretty(self)
+    }
+
+    /// Imports results from JSON produced by to_json
+    pub fn from_json(s: &str) -> serde_json::Result<ScanResults> {
+        serde_json::from_str(s)
+    }
+
     /// Get a slice of results for display (pagination support)
     pub fn get_page(&self, offset: usize, limit: usize) -> &[ScanResult] {
         let start = offset.min(self.results.len());
@@ -79,14 +213,85 @@ impl ScanResults {
     }
 }
 
+/// Formats one CSV record, quoting every field and doubling embedded quotes
+fn csv_record(fields: &[&str]) -> String {
+    let quoted: Vec<String> = fields
+        .iter()
+        .map(|field| format!("\"{}\"", field.replace('"', "\"\"")))
+        .collect();
+    format!("{}\r\n", quoted.join(","))
+}
+
+bitflags! {
+    /// Memory region types to include in a scan
+    #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+    pub struct RegionTypeFlags: u32 {
+        /// MEM_PRIVATE (heap, stack)
+        const PRIVATE = 1 << 0;
+        /// MEM_IMAGE (module code and data)
+        const IMAGE = 1 << 1;
+        /// MEM_MAPPED (file mappings)
+        const MAPPED = 1 << 2;
+    }
+}
+
+impl Default for RegionTypeFlags {
+    fn default() -> Self {
+        RegionTypeFlags::PRIVATE | RegionTypeFlags::IMAGE
+    }
+}
+
+/// Progress of a running first scan
+#[derive(Clone, Copy, Debug, Default)]
+pub struct ScanProgress {
+    pub regions_done: usize,
+    pub regions_total: usize,
+    pub results_found: usize,
+    /// Total size of the regions scanned so far
+    pub bytes_scanned: u64,
+}
+
+/// Called after each scanned region
+pub type ProgressCallback = Box<dyn Fn(ScanProgress) + Send>;
+
 /// Scan options for configuring how a scan is performed
-#[derive(Clone, Debug)]
 pub struct ScanOptions {
     pub value_type: ValueType,
     pub alignment: usize,
     pub writable_only: bool,
     pub readable_only: bool,
     pub executable_only: bool,
+    /// Restricts the scan to [start, end) if set
+    pub address_range: Option<(usize, usize)>,
+    pub region_types: RegionTypeFlags,
+    /// Only scan private read/write regions in the typical heap range
+    pub heap_only: bool,
+    /// Byte order of the values in the target's memory
+    pub endianness: Endianness,
+    pub progress_callback: Option<ProgressCallback>,
+    /// Set to true to stop the scan after the current region
+    pub cancellation_token: Arc<AtomicBool>,
+    /// Fail the scan if it is still running after this long
+    pub timeout: Option<Duration>,
+}
+
+impl fmt::Debug for ScanOptions {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ScanOptions")
+            .field("value_type", &self.value_type)
+            .field("alignment", &self.alignment)
+            .field("writable_only", &self.writable_only)
+            .field("readable_only", &self.readable_only)
+            .field("executable_only", &self.executable_only)
+            .field("address_range", &self.address_range)
+            .field("region_types", &self.region_types)
+            .field("heap_only", &self.heap_only)
+            .field("endianness", &self.endianness)
+            .field("progress_callback", &self.progress_callback.is_some())
+            .field("cancellation_token", &self.cancellation_token)
+            .field("timeout", &self.timeout)
+            .finish()
+    }
 }
 
 impl ScanOptions {
@@ -97,6 +302,35 @@ impl ScanOptions {
             writable_only: false,
             readable_only: true,
             executable_only: false,
+            address_range: None,
+            region_types: RegionTypeFlags::default(),
+            heap_only: false,
+            endianness: Endianness::Little,
+            progress_callback: None,
+            cancellation_token: Arc::new(AtomicBool::new(false)),
+            timeout: None,
+        }
+    }
+
+    /// Options for re-checking the results of a scan made with `prev`
+    ///
+    /// Copies every field but sets `writable_only`: values in read-only pages
+    /// never change, so next scans skip them. The progress callback is not
+    /// carried over since it cannot be cloned; the cancellation token is shared.
+    pub fn for_next_scan(prev: &ScanOptions) -> ScanOptions {
+        ScanOptions {
+            value_type: prev.value_type,
+            alignment: prev.alignment,
+            writable_only: true,
+            readable_only: prev.readable_only,
+            executable_only: prev.executable_only,
+            address_range: prev.address_range,
+            region_types: prev.region_types,
+            heap_only: prev.heap_only,
+            endianness: prev.endianness,
+            progress_callback: None,
+            cancellation_token: Arc::clone(&prev.cancellation_token),
+            timeout: prev.timeout,
         }
     }
 
@@ -109,4 +343,99 @@ impl ScanOptions {
         self.writable_only = true;
         self
     }
+
+    pub fn heap_only(mut self) -> Self {
+        self.heap_only = true;
+        self
+    }
+
+    pub fn with_address_range(mut self, start: usize, end: usize) -> Self {
+        self.address_range = Some((start, end));
+        self
+    }
+
+    pub fn with_region_types(mut self, region_types: RegionTypeFlags) -> Self {
+        self.region_types = region_types;
+        self
+    }
+
+    pub fn with_endianness(mut self, endianness: Endianness) -> Self {
+        self.endianness = endianness;
+        self
+    }
+
+    pub fn with_progress_callback(mut self, callback: ProgressCallback) -> Self {
+        self.progress_callback = Some(callback);
+        self
+    }
+
+    pub fn with_cancellation_token(mut self, token: Arc<AtomicBool>) -> Self {
+        self.cancellation_token = token;
+        self
+    }
+
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn results_at(addresses: &[(usize, i32)]) -> ScanResults {
+        let mut results = ScanResults::new(ValueType::I32);
+        for &(address, value) in addresses {
+            results.add_result(ScanResult::new(address, value.to_le_bytes().to_vec()));
+        }
+        results
+    }
+
+    fn addresses(results: &ScanResults) -> Vec<usize> {
+        results.results.iter().map(|r| r.address).collect()
+    }
+
+    #[test]
+    fn test_deduplicate_keeps_last_recorded_value() {
+        let mut results = results_at(&[(0x30, 1), (0x10, 2), (0x30, 3), (0x20, 4), (0x10, 5)]);
+        results.deduplicate();
+
+        assert_eq!(addresses(&results), vec![0x10, 0x20, 0x30]);
+        let values: Vec<Option<ScanValue>> =
+            results.results.iter().map(|r| r.parse_value(ValueType::I32)).collect();
+        assert_eq!(values, vec![Some(ScanValue::I32(5)), Some(ScanValue::I32(4)), Some(ScanValue::I32(3))]);
+    }
+
+    #[test]
+    fn test_filter_by_alignment() {
+        let mut results = results_at(&[(0x10, 0), (0x12, 0), (0x14, 0), (0x18, 0), (0x1F, 0)]);
+
+        assert_eq!(results.filter_by_alignment(1), 0);
+        assert_eq!(results.len(), 5);
+
+        assert_eq!(results.filter_by_alignment(4), 2);
+        assert_eq!(addresses(&results), vec![0x10, 0x14, 0x18]);
+
+        assert_eq!(results.filter_by_alignment(8), 1);
+        assert_eq!(addresses(&results), vec![0x10, 0x18]);
+    }
+
+    #[test]
+    fn test_to_csv_quotes_every_field() {
+        let mut results = ScanResults::new(ValueType::I32);
+        let mut result = ScanResult::new(0x1234, 100i32.to_le_bytes().to_vec());
+        result.label = Some("HP, \"max\"\nplayer 1".to_string());
+        results.add_result(result);
+        results.add_result(ScanResult::new(0x5678, (-5i32).to_le_bytes().to_vec()));
+
+        assert_eq!(
+            results.to_csv(),
+            concat!(
+                "\"address\",\"value\",\"previous_value\",\"label\"\r\n",
+                "\"0x1234\",\"100\",\"100\",\"HP, \"\"max\"\"\nplayer 1\"\r\n",
+                "\"0x5678\",\"-5\",\"-5\",\"\"\r\n",
+            )
+        );
+    }
 }
@@ -39,6 +39,11 @@ impl ValueType {
         }
     }
 
+    /// Returns true for the signed and unsigned integer types
+    pub fn is_integer(&self) -> bool {
+        !matches!(self, ValueType::F32 | ValueType::F64 | ValueType::ByteArray(_))
+    }
+
     /// Returns a human-readable name for this value type
     pub fn display_name(&self) -> &str {
         match self {
@@ -63,8 +68,65 @@ impl fmt::Display for ValueType {
     }
 }
 
+/// Character encoding of a string in the target's memory
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum StringEncoding {
+    #[default]
+    Utf8,
+    /// UTF-16LE, e.g. Windows wide strings and UE's TCHAR
+    Utf16,
+}
+
+impl StringEncoding {
+    /// Size of one code unit (and of the null terminator) in bytes
+    pub fn char_size(&self) -> usize {
+        match self {
+            StringEncoding::Utf8 => 1,
+            StringEncoding::Utf16 => 2,
+        }
+    }
+
+    /// Decodes raw bytes, replacing invalid sequences
+    pub fn decode(&self, bytes: &[u8]) -> String {
+        match self {
+            StringEncoding::Utf8 => String::from_utf8_lossy(bytes).into_owned(),
+            StringEncoding::Utf16 => {
+                let units: Vec<u16> = bytes
+                    .chunks_exact(2)
+                    .map(|c| u16::from_le_bytes([c[0], c[1]]))
+                    .collect();
+                String::from_utf16_lossy(&units)
+            }
+        }
+    }
+}
+
+/// Byte order of values in the target's memory
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Endianness {
+    #[default]
+    Little,
+    /// Big-endian, e.g. memory of emulated consoles (PowerPC)
+    Big,
+}
+
+impl Endianness {
+    pub fn display_name(&self) -> &str {
+        match self {
+            Endianness::Little => "Little Endian",
+            Endianness::Big => "Big Endian",
+        }
+    }
+}
+
+impl fmt::Display for Endianness {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.display_name())
+    }
+}
+
 /// Represents a value to scan for in memory
-#[derive(Clone, Debug, PartialEq)]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub enum ScanValue {
     I8(i8),
     I16(i16),
@@ -97,6 +159,27 @@ impl ScanValue {
         }
     }
 
+    /// Converts the value to a byte array in the given byte order
+    ///
+    /// Byte arrays are returned as-is.
+    pub fn to_bytes_endian(&self, endianness: Endianness) -> Vec<u8> {
+        match endianness {
+            Endianness::Little => self.to_bytes(),
+            Endianness::Big => self.as_bytes_be(),
+        }
+    }
+
+    /// Converts the value to a byte array (big-endian)
+    ///
+    /// Byte arrays are returned as-is.
+    pub fn as_bytes_be(&self) -> Vec<u8> {
+        let mut bytes = self.to_bytes();
+        if !matches!(self, ScanValue::ByteArray(_)) {
+            bytes.reverse();
+        }
+        bytes
+    }
+
     /// Creates a ScanValue from bytes (little-endian)
     pub fn from_bytes(bytes: &[u8], value_type: ValueType) -> Option<Self> {
         match value_type {
@@ -133,6 +216,69 @@ impl ScanValue {
         }
     }
 
+    /// Creates a ScanValue from bytes in the given byte order
+    pub fn from_bytes_endian(
+        bytes: &[u8],
+        value_type: ValueType,
+        endianness: Endianness,
+    ) -> Option<Self> {
+        match endianness {
+            Endianness::Little => Self::from_bytes(bytes, value_type),
+            Endianness::Big => Self::from_bytes_be(bytes, value_type),
+        }
+    }
+
+    /// Creates a ScanValue from bytes (big-endian)
+    pub fn from_bytes_be(bytes: &[u8], value_type: ValueType) -> Option<Self> {
+        if matches!(value_type, ValueType::ByteArray(_)) {
+            return Self::from_bytes(bytes, value_type);
+        }
+
+        let mut swapped = bytes.get(..value_type.size())?.to_vec();
+        swapped.reverse();
+        Self::from_bytes(&swapped, value_type)
+    }
+
+    /// Parses user input as a value of the given type
+    ///
+    /// Integers are decimal, or hex with a "0x"/"0X" prefix. Hex is the raw
+    /// bits of the type, so "0xFFFFFFFF" is -1 as an I32, the same form the
+    /// results view shows in Hex mode. A sign after the prefix ("0x-5") and
+    /// hex wider than the type are rejected.
+    pub fn parse(input: &str, value_type: ValueType) -> Result<Self, String> {
+        let input = input.trim();
+
+        if value_type.is_integer() {
+            if let Some(hex) = input.strip_prefix("0x").or_else(|| input.strip_prefix("0X")) {
+                // from_str_radix would accept a sign, but "0x-5" is not a hex literal
+                if hex.starts_with(['+', '-']) {
+                    return Err(format!("Invalid hex value: {}", input));
+                }
+                let bits = u64::from_str_radix(hex, 16).map_err(|e| format!("Invalid hex value: {}", e))?;
+                let size = value_type.size();
+                if size < 8 && bits >> (size * 8) != 0 {
+                    return Err(format!("{} is out of range for {}", input, value_type));
+                }
+                return Self::from_bytes(&bits.to_le_bytes(), value_type)
+                    .ok_or_else(|| format!("Invalid hex value: {}", input));
+            }
+        }
+
+        match value_type {
+            ValueType::I8 => input.parse().map(ScanValue::I8).map_err(|e| e.to_string()),
+            ValueType::I16 => input.parse().map(ScanValue::I16).map_err(|e| e.to_string()),
+            ValueType::I32 => input.parse().map(ScanValue::I32).map_err(|e| e.to_string()),
+            ValueType::I64 => input.parse().map(ScanValue::I64).map_err(|e| e.to_string()),
+            ValueType::U8 => input.parse().map(ScanValue::U8).map_err(|e| e.to_string()),
+            ValueType::U16 => input.parse().map(ScanValue::U16).map_err(|e| e.to_string()),
+            ValueType::U32 => input.parse().map(ScanValue::U32).map_err(|e| e.to_string()),
+            ValueType::U64 => input.parse().map(ScanValue::U64).map_err(|e| e.to_string()),
+            ValueType::F32 => input.parse().map(ScanValue::F32).map_err(|e| e.to_string()),
+            ValueType::F64 => input.parse().map(ScanValue::F64).map_err(|e| e.to_string()),
+            ValueType::ByteArray(_) => Err("Byte array input not yet implemented".to_string()),
+        }
+    }
+
     /// Returns the ValueType of this ScanValue
     pub fn value_type(&self) -> ValueType {
         match self {
@@ -223,6 +369,14 @@ pub enum ScanType {
     Decreased,
     Changed,
     Unchanged,
+    /// Increased by at least the given percentage (0.0-100.0)
+    IncreasedByPercentage(f64),
+    /// Decreased by at least the given percentage (0.0-100.0)
+    DecreasedByPercentage(f64),
+    /// Increased by exactly the given amount since the last scan
+    IncreasedByExactly(f64),
+    /// Decreased by exactly the given amount since the last scan
+    DecreasedByExactly(f64),
 }
 
 impl ScanType {
@@ -237,6 +391,10 @@ impl ScanType {
             ScanType::Decreased => "Decreased",
             ScanType::Changed => "Changed",
             ScanType::Unchanged => "Unchanged",
+            ScanType::IncreasedByPercentage(_) => "Increased by %",
+            ScanType::DecreasedByPercentage(_) => "Decreased by %",
+            ScanType::IncreasedByExactly(_) => "Increased by Value",
+            ScanType::DecreasedByExactly(_) => "Decreased by Value",
         }
     }
 
@@ -252,7 +410,14 @@ impl ScanType {
     pub fn is_next_scan_only(&self) -> bool {
         matches!(
             self,
-            ScanType::Increased | ScanType::Decreased | ScanType::Changed | ScanType::Unchanged
+            ScanType::Increased
+                | ScanType::Decreased
+                | ScanType::Changed
+                | ScanType::Unchanged
+                | ScanType::IncreasedByPercentage(_)
+                | ScanType::DecreasedByPercentage(_)
+                | ScanType::IncreasedByExactly(_)
+                | ScanType::DecreasedByExactly(_)
         )
     }
 }
@@ -262,3 +427,57 @@ impl fmt::Display for ScanType {
         write!(f, "{}", self.display_name())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_big_endian_bytes_roundtrip() {
+        let value = ScanValue::I32(0x01020304);
+        assert_eq!(value.as_bytes_be(), vec![0x01, 0x02, 0x03, 0x04]);
+        assert_eq!(ScanValue::from_bytes_be(&value.as_bytes_be(), ValueType::I32), Some(value));
+
+        let value = ScanValue::F64(-2.5);
+        assert_eq!(ScanValue::from_bytes_be(&value.as_bytes_be(), ValueType::F64), Some(value));
+    }
+
+    #[test]
+    fn test_parse_decimal() {
+        assert_eq!(ScanValue::parse("99", ValueType::I32), Ok(ScanValue::I32(99)));
+        assert_eq!(ScanValue::parse(" -5 ", ValueType::I32), Ok(ScanValue::I32(-5)));
+        assert_eq!(ScanValue::parse("18446744073709551615", ValueType::U64), Ok(ScanValue::U64(u64::MAX)));
+        assert_eq!(ScanValue::parse("1.5", ValueType::F32), Ok(ScanValue::F32(1.5)));
+    }
+
+    #[test]
+    fn test_parse_hex() {
+        assert_eq!(ScanValue::parse("0x63", ValueType::I32), Ok(ScanValue::I32(99)));
+        assert_eq!(ScanValue::parse("0X63", ValueType::I32), Ok(ScanValue::I32(99)));
+        assert_eq!(ScanValue::parse("0xff", ValueType::U8), Ok(ScanValue::U8(255)));
+        assert_eq!(ScanValue::parse("0xFF", ValueType::U8), Ok(ScanValue::U8(255)));
+        assert_eq!(ScanValue::parse("0xFFFFFFFFFFFFFFFF", ValueType::U64), Ok(ScanValue::U64(u64::MAX)));
+    }
+
+    #[test]
+    fn test_parse_hex_is_raw_bits_for_signed_types() {
+        assert_eq!(ScanValue::parse("0xFFFFFFFF", ValueType::I32), Ok(ScanValue::I32(-1)));
+        assert_eq!(ScanValue::parse("0x80", ValueType::I8), Ok(ScanValue::I8(i8::MIN)));
+        assert_eq!(ScanValue::parse("0xFFFFFFFFFFFFFFFF", ValueType::I64), Ok(ScanValue::I64(-1)));
+    }
+
+    #[test]
+    fn test_parse_rejects_sign_after_hex_prefix() {
+        assert!(ScanValue::parse("0x-5", ValueType::I32).is_err());
+        assert!(ScanValue::parse("0x+5", ValueType::I32).is_err());
+        assert!(ScanValue::parse("0x", ValueType::I32).is_err());
+    }
+
+    #[test]
+    fn test_parse_out_of_range() {
+        assert!(ScanValue::parse("256", ValueType::U8).is_err());
+        assert!(ScanValue::parse("0x100", ValueType::U8).is_err());
+        assert!(ScanValue::parse("0x1FF", ValueType::I8).is_err());
+        assert!(ScanValue::parse("-1", ValueType::U32).is_err());
+    }
+}